@@ -11,10 +11,27 @@
 //! transforming the whole slice to a vector of booleans. This should make it easy
 //! to see a byte slice as a bit stream, and parse code points of arbitrary bit length.
 
+use internal::{IResult,Needed};
+use internal::IResult::*;
+use util::ErrorKind;
+use nom::zigzag_i32;
+#[cfg(feature = "verbose-errors")]
+use std::cmp;
+#[cfg(feature = "verbose-errors")]
+use std::boxed::Box;
+#[cfg(feature = "verbose-errors")]
+use verbose_errors::Err;
+
 
 /// `bits!( parser ) => ( &[u8], (&[u8], usize) -> IResult<(&[u8], usize), T> ) -> IResult<&[u8], T>`
 /// transforms its byte slice input into a bit stream for the underlying parsers
 ///
+/// The `Needed` size of the bit parser is rounded up to bytes. The positions of the
+/// error chain point at the byte containing the failing bit, so the chain has the same
+/// nodes as for a byte parser. With `verbose-errors`, if the innermost position is inside
+/// a byte, it is followed by a single `BitOffset(n)` position, `n` being the offset of
+/// the bit in that byte.
+///
 /// ```
 /// # #[macro_use] extern crate nom;
 /// # use nom::IResult::Done;
@@ -46,18 +63,12 @@ macro_rules! bits_impl (
       let input = ($i, 0usize);
       match $submac!(input, $($args)*) {
         $crate::IResult::Error(e)                            => {
-          let err = match e {
-            $crate::Err::Code(k) | $crate::Err::Node(k, _) => $crate::Err::Code(k),
-            $crate::Err::Position(k, (i,b)) | $crate::Err::NodePosition(k, (i,b), _) => {
-              $crate::Err::Position(k, &i[b/8..])
-            }
-          };
-          $crate::IResult::Error(err)
+          $crate::IResult::Error($crate::bit_error_to_bytes(e))
         }
         $crate::IResult::Incomplete($crate::Needed::Unknown) => $crate::IResult::Incomplete($crate::Needed::Unknown),
         $crate::IResult::Incomplete($crate::Needed::Size(i)) => {
-          //println!("bits parser returned Needed::Size({})", i);
-          $crate::IResult::Incomplete($crate::Needed::Size(i / 8 + 1))
+          // the bit parser started on a byte boundary
+          $crate::IResult::Incomplete($crate::Needed::Size((i + 7) / 8))
         },
         $crate::IResult::Done((i, bit_index), o)             => {
           let byte_index = bit_index / 8 + if bit_index % 8 == 0 { 0 } else { 1 } ;
@@ -83,8 +94,8 @@ macro_rules! bits_impl (
         }
         $crate::IResult::Incomplete($crate::Needed::Unknown) => $crate::IResult::Incomplete($crate::Needed::Unknown),
         $crate::IResult::Incomplete($crate::Needed::Size(i)) => {
-          //println!("bits parser returned Needed::Size({})", i);
-          $crate::IResult::Incomplete($crate::Needed::Size(i / 8 + 1))
+          // the bit parser started on a byte boundary
+          $crate::IResult::Incomplete($crate::Needed::Size((i + 7) / 8))
         },
         $crate::IResult::Done((i, bit_index), o)             => {
           let byte_index = bit_index / 8 + if bit_index % 8 == 0 { 0 } else { 1 } ;
@@ -96,9 +107,94 @@ macro_rules! bits_impl (
   );
 );
 
+/// `bytes!( parser ) => ( (&[u8], usize), &[u8] -> IResult<&[u8], T> ) -> IResult<(&[u8], usize), T>`
+/// applies a byte parser inside a bit stream, which must be on a byte boundary
+///
+/// It returns an `Alignment` error if the bit offset is not a multiple of 8, see `byte_align!`.
+/// Errors positions of the byte parser are converted to bit positions, and the
+/// `Needed` size to a number of bits. Like for `take_bits!`, it counts the bits needed
+/// from the current position, so the whole bytes skipped by a bit offset of 8 or more
+/// are already counted by the parent parser.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::be_u16;
+/// # fn main() {
+///  // a 4 bits version, 4 bits of flags, then a big endian length and its payload
+///  named!( packet<(u8, u8, &[u8])>, bits!( do_parse!(
+///    version: take_bits!(u8, 4)                  >>
+///    flags:   take_bits!(u8, 4)                  >>
+///    payload: bytes!(length_bytes!(be_u16))      >>
+///    (version, flags, payload)
+///  ) ) );
+///
+///  assert_eq!(packet( &[0x12, 0x00, 0x02, 0xAA, 0xBB, 0xCC][..] ), Done(&[0xCC][..], (1, 2, &[0xAA, 0xBB][..])) );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! bytes (
+  ($i:expr, $submac:ident!( $($args:tt)* )) => (
+    bytes_impl!($i, $submac!($($args)*));
+  );
+  ($i:expr, $f:expr) => (
+    bytes_impl!($i, call!($f));
+  );
+);
+
+#[cfg(feature = "verbose-errors")]
+/// Internal parser, do not use directly
+#[doc(hidden)]
+#[macro_export]
+macro_rules! bytes_impl (
+  ($i:expr, $submac:ident!( $($args:tt)* )) => (
+    {
+      let (input, bit_offset): (&[u8], usize) = $i;
+      if bit_offset % 8 != 0 {
+        $crate::IResult::Error(error_position!($crate::ErrorKind::Alignment, (input, bit_offset)))
+      } else {
+        let bytes = &input[bit_offset / 8..];
+        match $submac!(bytes, $($args)*) {
+          $crate::IResult::Error(e)                            => {
+            $crate::IResult::Error(e.map_position(|p| (p, 0usize)))
+          },
+          $crate::IResult::Incomplete($crate::Needed::Unknown) => $crate::IResult::Incomplete($crate::Needed::Unknown),
+          $crate::IResult::Incomplete($crate::Needed::Size(n)) => $crate::IResult::Incomplete($crate::Needed::Size(n * 8)),
+          $crate::IResult::Done(i, o)                          => $crate::IResult::Done((i, 0usize), o),
+        }
+      }
+    }
+  );
+);
+
+#[cfg(not(feature = "verbose-errors"))]
+/// Internal parser, do not use directly
+#[doc(hidden)]
+#[macro_export]
+macro_rules! bytes_impl (
+  ($i:expr, $submac:ident!( $($args:tt)* )) => (
+    {
+      let (input, bit_offset): (&[u8], usize) = $i;
+      if bit_offset % 8 != 0 {
+        $crate::IResult::Error(error_position!($crate::ErrorKind::Alignment, (input, bit_offset)))
+      } else {
+        let bytes = &input[bit_offset / 8..];
+        match $submac!(bytes, $($args)*) {
+          $crate::IResult::Error(e)                            => $crate::IResult::Error(e),
+          $crate::IResult::Incomplete($crate::Needed::Unknown) => $crate::IResult::Incomplete($crate::Needed::Unknown),
+          $crate::IResult::Incomplete($crate::Needed::Size(n)) => $crate::IResult::Incomplete($crate::Needed::Size(n * 8)),
+          $crate::IResult::Done(i, o)                          => $crate::IResult::Done((i, 0usize), o),
+        }
+      }
+    }
+  );
+);
+
 /// `take_bits!(type, nb) => ( (&[T], usize), U, usize) -> IResult<(&[T], usize), U>`
 /// generates a parser consuming the specified number of bits.
 ///
+/// It returns an `Overflow` error if the number of bits does not fit in the type.
+///
 /// ```
 /// # #[macro_use] extern crate nom;
 /// # use nom::IResult::Done;
@@ -117,14 +213,18 @@ macro_rules! take_bits (
   ($i:expr, $t:ty, $count:expr) => (
     {
       use std::ops::Div;
-      //println!("taking {} bits from {:?}", $count, $i);
-      let (input, bit_offset) = $i;
-      let res : $crate::IResult<(&[u8],usize), $t> = if $count == 0 {
+      let start: (&[u8], usize) = $i;
+      let (input, bit_offset) = start;
+      // an offset of 8 bits or more skips whole bytes
+      let skipped = if bit_offset / 8 <= input.len() { bit_offset / 8 } else { input.len() };
+      let (input, bit_offset) = (&input[skipped..], bit_offset - skipped * 8);
+      let res : $crate::IResult<(&[u8],usize), $t> = if $count as usize > ::std::mem::size_of::<$t>() * 8 {
+        $crate::IResult::Error(error_position!($crate::ErrorKind::Overflow, start))
+      } else if $count == 0 {
         $crate::IResult::Done( (input, bit_offset), 0)
       } else {
         let cnt = ($count as usize + bit_offset).div(8);
         if input.len() * 8 < $count as usize + bit_offset {
-          //println!("returning incomplete: {}", $count as usize + bit_offset);
           $crate::IResult::Incomplete($crate::Needed::Size($count as usize))
         } else {
           let mut acc:$t            = 0;
@@ -160,6 +260,255 @@ macro_rules! take_bits (
   );
 );
 
+/// `take_bits_lsb!(type, nb) => ( (&[T], usize), U, usize) -> IResult<(&[T], usize), U>`
+/// generates a parser consuming the specified number of bits, least significant bit first
+///
+/// Bits are read from the least significant bit of each byte, and the first bit read
+/// is the least significant bit of the result, like in DEFLATE streams. The bit offset
+/// counts the bits already consumed from the low end of the first byte.
+/// It returns an `Overflow` error if the number of bits does not fit in the type.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!( take_pair<(u8, u8)>, bits!( pair!( take_bits_lsb!( u8, 3 ), take_bits_lsb!(u8, 7) ) ) );
+///
+///  let input = vec![0b10101011, 0b11110010];
+///  let sl    = &input[..];
+///
+///  // 0b011, then 0b10 followed by 0b10101
+///  assert_eq!(take_pair( sl ), Done(&sl[2..], (3, 0b1010101)) );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! take_bits_lsb (
+  ($i:expr, $t:ty, $count:expr) => (
+    {
+      let start: (&[u8], usize) = $i;
+      let (input, bit_offset) = start;
+      let skipped = if bit_offset / 8 <= input.len() { bit_offset / 8 } else { input.len() };
+      let (input, bit_offset) = (&input[skipped..], bit_offset - skipped * 8);
+      let count = $count as usize;
+      let res : $crate::IResult<(&[u8],usize), $t> = if count > ::std::mem::size_of::<$t>() * 8 {
+        $crate::IResult::Error(error_position!($crate::ErrorKind::Overflow, start))
+      } else if input.len() * 8 < count + bit_offset {
+        $crate::IResult::Incomplete($crate::Needed::Size(count))
+      } else {
+        let mut acc: $t          = 0;
+        let mut offset: usize    = bit_offset;
+        let mut index: usize     = 0;
+        let mut shift: usize     = 0;
+        let mut remaining: usize = count;
+
+        while remaining > 0 {
+          let available = 8 - offset;
+          let n = if remaining < available { remaining } else { available };
+          let val = (input[index] >> offset) & (((1u16 << n) - 1) as u8);
+          acc |= (val as $t) << shift;
+
+          shift     += n;
+          remaining -= n;
+          offset    += n;
+          if offset == 8 {
+            offset = 0;
+            index += 1;
+          }
+        }
+        $crate::IResult::Done( (&input[index..], offset), acc)
+      };
+      res
+    }
+  );
+);
+
+#[cfg(feature = "verbose-errors")]
+/// converts the positions of a bit parser error chain to the bytes containing the bits,
+/// adding a `BitOffset` position after the innermost position if it is inside a byte, for `bits!`
+#[doc(hidden)]
+pub fn bit_error_to_bytes<'a, E>(e: Err<(&'a [u8], usize), E>) -> Err<&'a [u8], E> {
+  fn byte_position<'a>((i, b): (&'a [u8], usize)) -> &'a [u8] {
+    &i[cmp::min(b / 8, i.len())..]
+  }
+
+  match e {
+    Err::Code(k)                  => Err::Code(k),
+    Err::Node(k, next)            => Err::Node(k, Box::new(bit_error_to_bytes(*next))),
+    Err::Position(k, p)           => {
+      let offset = p.1 % 8;
+      let p = byte_position(p);
+      if offset == 0 {
+        Err::Position(k, p)
+      } else {
+        Err::NodePosition(k, p, Box::new(Err::Position(ErrorKind::BitOffset(offset), p)))
+      }
+    },
+    Err::NodePosition(k, p, next) => Err::NodePosition(k, byte_position(p), Box::new(bit_error_to_bytes(*next))),
+  }
+}
+
+/// returns the index of the bit at a bit stream position, counted from the beginning of `base`
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::bit_index;
+/// # fn main() {
+///  named!( header<(&[u8], usize), (u8, u16)>, pair!( take_bits!(u8, 3), take_bits!(u16, 8) ) );
+///
+///  let input = &[0b10101010, 0b11110000][..];
+///  if let Done(remaining, _) = header((input, 0)) {
+///    assert_eq!(bit_index(input, remaining), 11);
+///  }
+/// # }
+/// ```
+#[inline]
+pub fn bit_index(base: &[u8], position: (&[u8], usize)) -> usize {
+  (position.0.as_ptr() as usize - base.as_ptr() as usize) * 8 + position.1
+}
+
+#[doc(hidden)]
+/// sign extends the `count` low bits of a value, for `take_signed_bits!`
+#[inline]
+pub fn sign_extend_bits(value: u64, count: usize) -> i64 {
+  if count == 0 || count >= 64 {
+    value as i64
+  } else if value >> (count - 1) & 1 == 1 {
+    (value | (!0u64 << count)) as i64
+  } else {
+    value as i64
+  }
+}
+
+/// `take_signed_bits!(type, nb) => ( (&[T], usize), U, usize) -> IResult<(&[T], usize), U>`
+/// generates a parser consuming the specified number of bits as a two's complement signed integer
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!( take_pair<(i8, i8)>, bits!( pair!( take_signed_bits!( i8, 3 ), take_signed_bits!(i8, 5) ) ) );
+///
+///  let input = vec![0b11001110];
+///  let sl    = &input[..];
+///
+///  assert_eq!(take_pair( sl ), Done(&sl[1..], (-2, 14)) );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! take_signed_bits (
+  ($i:expr, $t:ty, $count:expr) => (
+    {
+      let count = $count as usize;
+      let res : $crate::IResult<(&[u8],usize), $t> = if count > ::std::mem::size_of::<$t>() * 8 {
+        $crate::IResult::Error(error_position!($crate::ErrorKind::Overflow, $i))
+      } else { match take_bits!($i, u64, count) {
+        $crate::IResult::Done(i, o)    => $crate::IResult::Done(i, $crate::sign_extend_bits(o, count) as $t),
+        $crate::IResult::Error(e)      => $crate::IResult::Error(e),
+        $crate::IResult::Incomplete(n) => $crate::IResult::Incomplete(n),
+      } };
+      res
+    }
+  );
+);
+
+/// `take_signed_bits_lsb!(type, nb) => ( (&[T], usize), U, usize) -> IResult<(&[T], usize), U>`
+/// generates a parser consuming the specified number of bits, least significant bit first,
+/// as a two's complement signed integer
+#[macro_export]
+macro_rules! take_signed_bits_lsb (
+  ($i:expr, $t:ty, $count:expr) => (
+    {
+      let count = $count as usize;
+      let res : $crate::IResult<(&[u8],usize), $t> = if count > ::std::mem::size_of::<$t>() * 8 {
+        $crate::IResult::Error(error_position!($crate::ErrorKind::Overflow, $i))
+      } else { match take_bits_lsb!($i, u64, count) {
+        $crate::IResult::Done(i, o)    => $crate::IResult::Done(i, $crate::sign_extend_bits(o, count) as $t),
+        $crate::IResult::Error(e)      => $crate::IResult::Error(e),
+        $crate::IResult::Incomplete(n) => $crate::IResult::Incomplete(n),
+      } };
+      res
+    }
+  );
+);
+
+/// `take_bit!() => (&[T], usize) -> IResult<(&[T], usize), bool>`
+/// consumes one bit and returns true if it is set
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!( flags<(bool, bool, u8)>, bits!( tuple!( take_bit!(), take_bit!(), take_bits!(u8, 6) ) ) );
+///
+///  assert_eq!(flags( &[0b10000001][..] ), Done(&b""[..], (true, false, 1)) );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! take_bit (
+  ($i:expr,) => (
+    take_bit!($i)
+  );
+  ($i:expr) => (
+    map!($i, take_bits!(u8, 1), |b: u8| b == 1)
+  );
+);
+
+/// `take_bit_lsb!() => (&[T], usize) -> IResult<(&[T], usize), bool>`
+/// consumes one bit, least significant bit first, and returns true if it is set
+#[macro_export]
+macro_rules! take_bit_lsb (
+  ($i:expr,) => (
+    take_bit_lsb!($i)
+  );
+  ($i:expr) => (
+    map!($i, take_bits_lsb!(u8, 1), |b: u8| b == 1)
+  );
+);
+
+/// `byte_align!() => (&[T], usize) -> IResult<(&[T], usize), ()>`
+/// skips the remaining bits of the current byte, to continue at the next byte boundary
+///
+/// It consumes nothing if the input is already on a byte boundary. Like `take_bits!`,
+/// it treats an offset of 8 bits or more as skipped whole bytes, and returns `Incomplete`
+/// with the number of bits to skip if the input ends before the next byte boundary
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!( header<(u8, u8)>, bits!( do_parse!(
+///    a: take_bits!(u8, 3) >>
+///       byte_align!()     >>
+///    b: take_bits!(u8, 8) >>
+///    (a, b)
+///  ) ) );
+///
+///  assert_eq!(header( &[0b10111111, 0x42][..] ), Done(&b""[..], (5, 0x42)) );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! byte_align (
+  ($i:expr,) => (
+    byte_align!($i)
+  );
+  ($i:expr) => (
+    {
+      let (input, bit_offset): (&[u8], usize) = $i;
+      // an offset of 8 bits or more skips whole bytes
+      let skipped = if bit_offset / 8 <= input.len() { bit_offset / 8 } else { input.len() };
+      let (input, bit_offset) = (&input[skipped..], bit_offset - skipped * 8);
+      let aligned = (bit_offset + 7) / 8;
+      let res : $crate::IResult<(&[u8],usize), ()> = if aligned > input.len() {
+        $crate::IResult::Incomplete($crate::Needed::Size(aligned * 8 - bit_offset))
+      } else {
+        $crate::IResult::Done((&input[aligned..], 0), ())
+      };
+      res
+    }
+  );
+);
+
 /// matches an integer pattern to a bitstream. The number of bits of the input to compare must be specified
 #[macro_export]
 macro_rules! tag_bits (
@@ -183,6 +532,176 @@ macro_rules! tag_bits (
   )
 );
 
+/// matches an integer pattern to a bitstream read least significant bit first, like `tag_bits!`
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  // a DEFLATE block header: the final block bit, then the block type 1 (fixed Huffman codes)
+///  named!( fixed_block<(bool, u8)>, bits!( pair!( take_bit_lsb!(), tag_bits_lsb!(u8, 2, 0b01) ) ) );
+///
+///  assert_eq!(fixed_block( &[0b00000011][..] ), Done(&b""[..], (true, 1)) );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! tag_bits_lsb (
+  ($i:expr, $t:ty, $count:expr, $p: pat) => (
+    {
+      match take_bits_lsb!($i, $t, $count) {
+        $crate::IResult::Incomplete(i) => $crate::IResult::Incomplete(i),
+        $crate::IResult::Done(i, o)    => {
+          if let $p = o {
+            let res: $crate::IResult<(&[u8],usize),$t> = $crate::IResult::Done(i, o);
+            res
+          } else {
+            $crate::IResult::Error(error_position!($crate::ErrorKind::TagBits, $i))
+          }
+        },
+        _                              => {
+          $crate::IResult::Error(error_position!($crate::ErrorKind::TagBits, $i))
+        }
+      }
+    }
+  )
+);
+
+/// counts the bits equal to `!stop` before a `stop` bit, and consumes them all
+fn count_bits(start: (&[u8], usize), stop: u8) -> IResult<(&[u8], usize), u32> {
+  let (mut input, mut offset) = start;
+  let skipped = ::std::cmp::min(offset / 8, input.len());
+  input = &input[skipped..];
+  offset -= skipped * 8;
+
+  let mut count: u32 = 0;
+  loop {
+    if input.is_empty() {
+      return Incomplete(Needed::Size(count as usize + 1));
+    }
+    let bit = (input[0] >> (7 - offset)) & 1;
+    offset += 1;
+    if offset == 8 {
+      input = &input[1..];
+      offset = 0;
+    }
+
+    if bit == stop {
+      return Done((input, offset), count);
+    }
+    count = match count.checked_add(1) {
+      Some(c) => c,
+      None    => return Error(error_position!(ErrorKind::Overflow, start)),
+    };
+  }
+}
+
+/// reads `count` bits after a prefix of `consumed` bits, adding the prefix to the `Needed` size
+fn take_bits_after(input: (&[u8], usize), count: usize, consumed: usize) -> IResult<(&[u8], usize), u32> {
+  match take_bits!(input, u32, count) {
+    Incomplete(Needed::Size(n)) => Incomplete(Needed::Size(consumed + n)),
+    res                         => res,
+  }
+}
+
+/// Recognizes a unary code: the number of 0 bits before a 1 bit, which is consumed
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::unary;
+/// let input = &[0b00010100][..];
+/// assert_eq!(unary((input, 0)), Done((input, 4), 3));
+/// assert_eq!(unary((input, 4)), Done((input, 6), 1));
+/// ```
+pub fn unary(input: (&[u8], usize)) -> IResult<(&[u8], usize), u32> {
+  count_bits(input, 1)
+}
+
+/// Recognizes a unary code written with 1 bits: the number of 1 bits before a 0 bit, which is consumed
+pub fn unary_ones(input: (&[u8], usize)) -> IResult<(&[u8], usize), u32> {
+  count_bits(input, 0)
+}
+
+/// Recognizes a Golomb-Rice code of parameter `k`: a unary quotient followed by a `k` bits remainder
+///
+/// Returns an `Overflow` error if the value does not fit in a u32
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::rice;
+/// # fn main() {
+///  // quotient 2, remainder 0b11: 2 * 4 + 3
+///  named!(residual<u32>, bits!(call!(rice, 2)));
+///  assert_eq!(residual(&[0b00111000][..]), Done(&b""[..], 11));
+/// # }
+/// ```
+pub fn rice(input: (&[u8], usize), k: usize) -> IResult<(&[u8], usize), u32> {
+  match unary(input) {
+    Done(i, q) => {
+      if k >= 32 || (k > 0 && q >> (32 - k) != 0) {
+        return Error(error_position!(ErrorKind::Overflow, input));
+      }
+      take_bits_after(i, k, q as usize + 1).map(|r| (q << k) | r)
+    },
+    Error(e)      => Error(e),
+    Incomplete(n) => Incomplete(n),
+  }
+}
+
+/// Recognizes a signed Golomb-Rice code of parameter `k`, as in FLAC residuals: the code of
+/// 0, -1, 1, -2, 2... is the unsigned Rice code of 0, 1, 2, 3, 4...
+pub fn rice_signed(input: (&[u8], usize), k: usize) -> IResult<(&[u8], usize), i32> {
+  rice(input, k).map(zigzag_i32)
+}
+
+/// Recognizes an unsigned Exp-Golomb code, like the `ue(v)` syntax elements of H.264:
+/// `n` 0 bits, a 1 bit, then `n` bits
+///
+/// Returns an `Overflow` error if the value does not fit in a u32
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::exp_golomb;
+/// let input = &[0b10100110, 0b01000000][..];
+/// assert_eq!(exp_golomb((input, 0)), Done((input, 1), 0));
+/// assert_eq!(exp_golomb((input, 1)), Done((input, 4), 1));
+/// assert_eq!(exp_golomb((input, 4)), Done((input, 7), 2));
+/// assert_eq!(exp_golomb((input, 7)), Done((&input[1..], 4), 3));
+/// ```
+pub fn exp_golomb(input: (&[u8], usize)) -> IResult<(&[u8], usize), u32> {
+  match unary(input) {
+    Done(i, n) => {
+      if n > 31 {
+        return Error(error_position!(ErrorKind::Overflow, input));
+      }
+      // at most 2^31 - 1 + 2^31 - 1
+      take_bits_after(i, n as usize, n as usize + 1).map(|bits| ((1u32 << n) - 1) + bits)
+    },
+    Error(e)      => Error(e),
+    Incomplete(n) => Incomplete(n),
+  }
+}
+
+/// Recognizes a signed Exp-Golomb code, like the `se(v)` syntax elements of H.264:
+/// the code of 0, 1, -1, 2, -2... is the unsigned Exp-Golomb code of 0, 1, 2, 3, 4...
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::exp_golomb_signed;
+/// let input = &[0b01001100, 0b10000000][..];
+/// assert_eq!(exp_golomb_signed((input, 0)), Done((input, 3), 1));
+/// assert_eq!(exp_golomb_signed((input, 3)), Done((input, 6), -1));
+/// ```
+pub fn exp_golomb_signed(input: (&[u8], usize)) -> IResult<(&[u8], usize), i32> {
+  exp_golomb(input).map(|k| {
+    if k & 1 == 1 {
+      ((k >> 1) + 1) as i32
+    } else {
+      -((k >> 1) as i64) as i32
+    }
+  })
+}
+
 #[cfg(test)]
 mod tests {
   use internal::{IResult,Needed};
@@ -210,6 +729,129 @@ mod tests {
     assert_eq!(take_bits!( (sl, 4), u32, 22 ), IResult::Incomplete(Needed::Size(22)));
   }
 
+  #[test]
+  fn take_bits_overflow() {
+    let input = vec![0b10101010, 0b11110000, 0b00110011];
+    let sl    = &input[..];
+
+    assert_eq!(take_bits!( (sl, 0), u8,  9 ), IResult::Error(error_position!(ErrorKind::Overflow, (sl, 0))));
+    assert_eq!(take_bits!( (sl, 0), u16, 17 ), IResult::Error(error_position!(ErrorKind::Overflow, (sl, 0))));
+    assert_eq!(take_bits_lsb!( (sl, 0), u8,  9 ), IResult::Error(error_position!(ErrorKind::Overflow, (sl, 0))));
+    assert_eq!(take_signed_bits!( (sl, 0), i8,  9 ), IResult::Error(error_position!(ErrorKind::Overflow, (sl, 0))));
+    assert_eq!(take_bits!( (sl, 0), u64, 20 ), IResult::Done((&sl[2..], 4), 700163));
+
+    // an offset of 8 bits is the beginning of the next byte
+    assert_eq!(take_bits!( (sl, 8), u8, 4 ), IResult::Done((&sl[1..], 4), 15));
+    assert_eq!(take_bits!( (&sl[..1], 8), u8, 4 ), IResult::Incomplete(Needed::Size(4)));
+    assert_eq!(take_bits_lsb!( (sl, 12), u8, 4 ), IResult::Done((&sl[2..], 0), 15));
+  }
+
+  #[test]
+  fn bit_precise_errors() {
+    let input = vec![0b10101010, 0b11110000];
+    let sl    = &input[..];
+
+    named!(header<(&[u8],usize),(u8,u8)>, pair!(take_bits!(u8, 7), tag_bits!(u8, 3, 0b111)));
+    match header((sl, 0)) {
+      IResult::Error(e) => {
+        let position = error_position!(ErrorKind::TagBits, (sl, 7));
+        assert_eq!(e, position);
+        #[cfg(feature = "verbose-errors")]
+        {
+          if let ::Err::Position(_, p) = e {
+            assert_eq!(::bit_index(sl, p), 7);
+          }
+        }
+      },
+      other => panic!("unexpected result {:?}", other),
+    }
+
+    // Needed is rounded up to whole bytes
+    named!(twelve<u16>, bits!(take_bits!(u16, 12)));
+    named!(sixteen<u16>, bits!(take_bits!(u16, 16)));
+    assert_eq!(twelve(&sl[..1]), IResult::Incomplete(Needed::Size(2)));
+    assert_eq!(sixteen(&sl[..1]), IResult::Incomplete(Needed::Size(2)));
+  }
+
+  #[cfg(feature = "verbose-errors")]
+  #[test]
+  fn bits_error_chain() {
+    use verbose_errors::Err;
+
+    let input = vec![0b10101010, 0b11110000];
+    let sl    = &input[..];
+
+    // the tag starts at bit 2 of the second byte
+    named!(header<u8>, bits!(add_error!(ErrorKind::Custom(42), preceded!(take_bits!(u16, 10), tag_bits!(u8, 3, 0b000)))));
+    assert_eq!(header(sl), IResult::Error(Err::NodePosition(ErrorKind::Custom(42), sl,
+      Box::new(Err::NodePosition(ErrorKind::TagBits, &sl[1..],
+        Box::new(Err::Position(ErrorKind::BitOffset(2), &sl[1..])))))));
+
+    // the offset is only added after the innermost position
+    named!(inner<u8>, bits!(preceded!(take_bits!(u8, 3), add_error!(ErrorKind::Custom(1), tag_bits!(u8, 2, 0b11)))));
+    assert_eq!(inner(sl), IResult::Error(Err::NodePosition(ErrorKind::Custom(1), sl,
+      Box::new(Err::NodePosition(ErrorKind::TagBits, sl,
+        Box::new(Err::Position(ErrorKind::BitOffset(3), sl)))))));
+
+    // positions on a byte boundary keep the chain of a byte parser
+    named!(aligned<u8>, bits!(preceded!(take_bits!(u8, 8), add_error!(ErrorKind::Custom(1), tag_bits!(u8, 2, 0b00)))));
+    assert_eq!(aligned(sl), IResult::Error(Err::NodePosition(ErrorKind::Custom(1), &sl[1..],
+      Box::new(Err::Position(ErrorKind::TagBits, &sl[1..])))));
+  }
+
+  #[test]
+  fn unary_codes() {
+    let input = vec![0b00000000, 0b01110000];
+    let sl    = &input[..];
+
+    assert_eq!(::unary((sl, 0)), IResult::Done((&sl[1..], 2), 9));
+    assert_eq!(::unary_ones((&sl[1..], 1)), IResult::Done((&sl[1..], 5), 3));
+    assert_eq!(::unary((&sl[1..], 5)), IResult::Incomplete(Needed::Size(4)));
+    assert_eq!(::unary((&sl[..1], 0)), IResult::Incomplete(Needed::Size(9)));
+    assert_eq!(::unary((&sl[2..], 0)), IResult::Incomplete(Needed::Size(1)));
+  }
+
+  #[test]
+  fn rice_codes() {
+    // quotient 1 and remainder 0b101, quotient 0 and remainder 0b011
+    let input = vec![0b01101101, 0b10000000];
+    let sl    = &input[..];
+
+    assert_eq!(::rice((sl, 0), 3), IResult::Done((sl, 5), 13));
+    assert_eq!(::rice((sl, 5), 3), IResult::Done((&sl[1..], 1), 3));
+    assert_eq!(::rice((sl, 0), 0), IResult::Done((sl, 2), 1));
+    // the remainder runs past the end of the input
+    assert_eq!(::rice((sl, 5), 13), IResult::Incomplete(Needed::Size(14)));
+    assert_eq!(::rice((sl, 0), 32), IResult::Error(error_position!(ErrorKind::Overflow, (sl, 0))));
+
+    assert_eq!(::rice_signed((sl, 0), 3), IResult::Done((sl, 5), -7));
+    assert_eq!(::rice_signed((sl, 5), 3), IResult::Done((&sl[1..], 1), -2));
+    assert_eq!(::rice_signed((sl, 0), 0), IResult::Done((sl, 2), -1));
+  }
+
+  #[test]
+  fn exp_golomb_codes() {
+    // 00111 is 6, 00100 is 3, 1 is 0, 0001000 is 7
+    let input = vec![0b00111001, 0b00100010, 0b00000000];
+    let sl    = &input[..];
+
+    assert_eq!(::exp_golomb((sl, 0)), IResult::Done((sl, 5), 6));
+    assert_eq!(::exp_golomb((sl, 5)), IResult::Done((&sl[1..], 2), 3));
+    assert_eq!(::exp_golomb((&sl[1..], 2)), IResult::Done((&sl[1..], 3), 0));
+    assert_eq!(::exp_golomb((&sl[1..], 3)), IResult::Done((&sl[2..], 2), 7));
+    assert_eq!(::exp_golomb((&sl[2..], 0)), IResult::Incomplete(Needed::Size(9)));
+
+    assert_eq!(::exp_golomb_signed((sl, 0)), IResult::Done((sl, 5), -3));
+    assert_eq!(::exp_golomb_signed((sl, 5)), IResult::Done((&sl[1..], 2), 2));
+    assert_eq!(::exp_golomb_signed((&sl[1..], 3)), IResult::Done((&sl[2..], 2), 4));
+
+    // 31 leading zeros and 31 one bits is the largest u32
+    let max = vec![0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFE];
+    assert_eq!(::exp_golomb((&max[..], 0)), IResult::Done((&max[7..], 7), ::std::u32::MAX - 1));
+    let too_big = vec![0, 0, 0, 0, 0x80, 0, 0, 0, 0];
+    assert_eq!(::exp_golomb((&too_big[..], 0)), IResult::Error(error_position!(ErrorKind::Overflow, (&too_big[..], 0))));
+  }
+
   #[test]
   fn tag_bits() {
     let input = vec![0b10101010, 0b11110000, 0b00110011];
@@ -219,6 +861,134 @@ mod tests {
     assert_eq!(tag_bits!( (sl, 0), u8,   4, 0b1010), IResult::Done((&sl[0..], 4), 10));
   }
 
+  #[test]
+  fn take_bits_lsb() {
+    let input = vec![0b10101010, 0b11110000, 0b00110011];
+    let sl    = &input[..];
+
+    assert_eq!(take_bits_lsb!( (sl, 0), u8,   0 ), IResult::Done((sl, 0), 0));
+    assert_eq!(take_bits_lsb!( (sl, 0), u8,   8 ), IResult::Done((&sl[1..], 0), 170));
+    assert_eq!(take_bits_lsb!( (sl, 0), u8,   3 ), IResult::Done((sl, 3), 0b010));
+    assert_eq!(take_bits_lsb!( (sl, 3), u8,   5 ), IResult::Done((&sl[1..], 0), 0b10101));
+    assert_eq!(take_bits_lsb!( (sl, 6), u8,   4 ), IResult::Done((&sl[1..], 2), 0b0010));
+    assert_eq!(take_bits_lsb!( (sl, 4), u16, 12 ), IResult::Done((&sl[2..], 0), 0b111100001010));
+    assert_eq!(take_bits_lsb!( (sl, 4), u32, 20 ), IResult::Done((&sl[3..], 0), 0b00110011111100001010));
+    assert_eq!(take_bits_lsb!( (sl, 4), u32, 21 ), IResult::Incomplete(Needed::Size(21)));
+  }
+
+  #[test]
+  fn signed_bits() {
+    let input = vec![0b10101010, 0b11110000];
+    let sl    = &input[..];
+
+    assert_eq!(take_signed_bits!( (sl, 0), i8,  3 ), IResult::Done((sl, 3), -3));
+    assert_eq!(take_signed_bits!( (sl, 1), i8,  3 ), IResult::Done((sl, 4), 2));
+    assert_eq!(take_signed_bits!( (sl, 0), i8,  8 ), IResult::Done((&sl[1..], 0), -86));
+    assert_eq!(take_signed_bits!( (sl, 4), i16, 12 ), IResult::Done((&sl[2..], 0), -1296));
+    assert_eq!(take_signed_bits!( (sl, 4), i16, 13 ), IResult::Incomplete(Needed::Size(13)));
+    assert_eq!(take_signed_bits!( (sl, 0), i8,  0 ), IResult::Done((sl, 0), 0));
+
+    assert_eq!(take_signed_bits_lsb!( (sl, 0), i8,  3 ), IResult::Done((sl, 3), 2));
+    assert_eq!(take_signed_bits_lsb!( (sl, 1), i8,  3 ), IResult::Done((sl, 4), -3));
+    assert_eq!(take_signed_bits_lsb!( (sl, 4), i16, 12 ), IResult::Done((&sl[2..], 0), -246));
+  }
+
+  #[test]
+  fn bool_bits() {
+    let input = vec![0b10000001];
+    let sl    = &input[..];
+
+    assert_eq!(take_bit!( (sl, 0) ), IResult::Done((sl, 1), true));
+    assert_eq!(take_bit!( (sl, 1) ), IResult::Done((sl, 2), false));
+    assert_eq!(take_bit!( (sl, 7) ), IResult::Done((&sl[1..], 0), true));
+    assert_eq!(take_bit_lsb!( (sl, 0) ), IResult::Done((sl, 1), true));
+    assert_eq!(take_bit_lsb!( (sl, 6) ), IResult::Done((sl, 7), false));
+    assert_eq!(take_bit!( (&sl[1..], 0) ), IResult::Incomplete(Needed::Size(1)));
+  }
+
+  #[test]
+  fn byte_align() {
+    let input = vec![0b10101010, 0b11110000];
+    let sl    = &input[..];
+
+    assert_eq!(byte_align!( (sl, 0) ), IResult::Done((sl, 0), ()));
+    assert_eq!(byte_align!( (sl, 3) ), IResult::Done((&sl[1..], 0), ()));
+    assert_eq!(byte_align!( (&sl[2..], 0) ), IResult::Done((&sl[2..], 0), ()));
+    assert_eq!(byte_align!( (sl, 8) ), IResult::Done((&sl[1..], 0), ()));
+    assert_eq!(byte_align!( (sl, 12) ), IResult::Done((&sl[2..], 0), ()));
+    assert_eq!(byte_align!( (sl, 16) ), IResult::Done((&sl[2..], 0), ()));
+    assert_eq!(byte_align!( (&sl[..0], 0) ), IResult::Done((&sl[..0], 0), ()));
+    assert_eq!(byte_align!( (&sl[..0], 3) ), IResult::Incomplete(Needed::Size(5)));
+    assert_eq!(byte_align!( (sl, 19) ), IResult::Incomplete(Needed::Size(5)));
+
+    named!(aligned<(&[u8],usize),(u8,u8)>, do_parse!(
+      a: take_bits_lsb!(u8, 1) >>
+         byte_align!()         >>
+      b: take_bits_lsb!(u8, 4) >>
+      (a, b)
+    ));
+    assert_eq!(aligned((sl, 0)), IResult::Done((&sl[1..], 4), (0, 0)));
+    assert_eq!(aligned((sl, 7)), IResult::Done((&sl[1..], 4), (1, 0)));
+  }
+
+  #[test]
+  fn tag_bits_lsb() {
+    let input = vec![0b10101010, 0b11110000, 0b00110011];
+    let sl    = &input[..];
+
+    assert_eq!(tag_bits_lsb!( (sl, 0), u8, 3, 0b010), IResult::Done((sl, 3), 2));
+    assert_eq!(tag_bits_lsb!( (sl, 4), u16, 12, 0b111100001010), IResult::Done((&sl[2..], 0), 3850));
+    assert_eq!(tag_bits_lsb!( (sl, 0), u8, 3, 0b101), IResult::Error(error_position!(ErrorKind::TagBits, (sl, 0))));
+    assert_eq!(tag_bits_lsb!( (&sl[3..], 0), u8, 3, 0b101), IResult::Incomplete(Needed::Size(3)));
+  }
+
+  #[test]
+  fn verify_bits() {
+    let input = vec![0b10101010, 0b11110000, 0b00110011];
+    let sl    = &input[..];
+
+    named!(nibble<(&[u8],usize),u8>, verify!(take_bits!(u8, 4), |v: &u8| *v < 12));
+    assert_eq!(nibble((sl, 0)), IResult::Done((sl, 4), 10));
+    assert_eq!(nibble((&sl[1..], 0)), IResult::Error(error_position!(ErrorKind::Verify, (&sl[1..], 0))));
+    assert_eq!(nibble((&sl[..1], 6)), IResult::Incomplete(Needed::Size(4)));
+  }
+
+  #[test]
+  fn bytes_in_bits() {
+    let input = vec![0b10100000, 0x61, 0x62, 0x63];
+    let sl    = &input[..];
+
+    named!(abc<(&[u8],usize),(u8,&[u8])>, do_parse!(
+      a: take_bits!(u8, 3)    >>
+         byte_align!()        >>
+      b: bytes!(tag!("ab"))   >>
+      (a, b)
+    ));
+    assert_eq!(abc((sl, 0)), IResult::Done((&sl[3..], 0), (5, &b"ab"[..])));
+    // 8 bits before the tag, and 16 bits for the tag
+    assert_eq!(abc((&sl[..2], 0)), IResult::Incomplete(Needed::Size(24)));
+
+    named!(unaligned<(&[u8],usize),(u8,&[u8])>, pair!(take_bits!(u8, 3), bytes!(tag!("ab"))));
+    assert_eq!(unaligned((sl, 0)), IResult::Error(error_position!(ErrorKind::Alignment, (sl, 3))));
+
+    // the byte parser errors are converted to bit positions
+    named!(wrong_tag<(&[u8],usize),&[u8]>, bytes!(tag!("bc")));
+    assert_eq!(wrong_tag((&sl[1..], 0)), IResult::Error(error_position!(ErrorKind::Tag, (&sl[1..], 0))));
+    named!(bc<(&[u8],usize),&[u8]>, bytes!(tag!("bc")));
+    assert_eq!(bc((&sl[1..], 8)), IResult::Done((&sl[4..], 0), &b"bc"[..]));
+    assert_eq!(bc((&sl[1..2], 8)), IResult::Incomplete(Needed::Size(16)));
+
+    // a byte skipped with a bit offset of 8, then the 16 bits of the tag
+    fn skip_byte(i: (&[u8], usize)) -> IResult<(&[u8], usize), ()> {
+      IResult::Done((i.0, i.1 + 8), ())
+    }
+    named!(skipped<(&[u8],usize),&[u8]>, do_parse!(call!(skip_byte) >> b: bytes!(tag!("ab")) >> (b)));
+    assert_eq!(skipped((&sl[..2], 0)), IResult::Incomplete(Needed::Size(24)));
+    assert_eq!(skipped((&sl[..3], 0)), IResult::Done((&sl[3..3], 0), &b"ab"[..]));
+    named!(skipped_bytes<&[u8]>, bits!(skipped));
+    assert_eq!(skipped_bytes(&sl[..2]), IResult::Incomplete(Needed::Size(3)));
+  }
+
   named!(ch<(&[u8],usize),(u8,u8)>,
     chain!(
       tag_bits!(u8, 3, 0b101) ~
@@ -283,29 +283,35 @@ macro_rules! alt_complete (
 ///  # }
 /// ```
 ///
-/// Due to limitations in Rust macros, it is not possible to have simple functions on the right hand
-/// side of pattern, like this:
+/// The error of the selected parser is kept as the next node of the `ErrorKind::Switch` error.
 ///
-/// ```ignore
-///  named!(sw,
-///    switch!(take!(4),
-///      b"abcd" => tag!("XYZ") |
-///      b"efgh" => tag!("123")
-///    )
-///  );
-/// ```
+/// Patterns can be ranges and have guards, and a parser can be called directly
+/// by its name. If the last pattern is `_`, its parser is applied on values
+/// matched by no other pattern, instead of returning an `ErrorKind::Switch` error:
 ///
-/// If you want to pass your own functions instead, you can use the `call!` combinator as follows:
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{self,Done};
+/// # use nom::be_u8;
+/// # fn main() {
+///  fn unknown(input: &[u8]) -> IResult<&[u8], &[u8]> {
+///    Done(input, &input[..0])
+///  }
 ///
-/// ```ignore
-///  named!(xyz, tag!("XYZ"));
-///  named!(num, tag!("123"));
 ///  named!(sw,
-///    switch!(take!(4),
-///      b"abcd" => call!(xyz) |
-///      b"efgh" => call!(num)
+///    switch!(be_u8,
+///      0x00...0x0f => take!(1)          |
+///      n if n < 0x80 => take!(2)        |
+///      0xff => tag!("end")              |
+///      _ => unknown
 ///    )
 ///  );
+///
+///  assert_eq!(sw(&b"\x01abc"[..]), Done(&b"bc"[..], &b"a"[..]));
+///  assert_eq!(sw(&b"\x41abc"[..]), Done(&b"c"[..], &b"ab"[..]));
+///  assert_eq!(sw(&b"\xffend"[..]), Done(&b""[..], &b"end"[..]));
+///  assert_eq!(sw(&b"\x90abc"[..]), Done(&b"abc"[..], &b""[..]));
+/// # }
 /// ```
 ///
 #[macro_export]
@@ -326,7 +332,7 @@ macro_rules! switch (
 #[doc(hidden)]
 #[macro_export]
 macro_rules! switch_impl (
-  ($i:expr, $submac:ident!( $($args:tt)* ), $($p:pat => $subrule:ident!( $($args2:tt)* ))|* ) => (
+  ($i:expr, $submac:ident!( $($args:tt)* ), $($rest:tt)*) => (
     {
       match $submac!($i, $($args)*) {
         $crate::IResult::Error(e)      => $crate::IResult::Error(error_node_position!(
@@ -334,45 +340,158 @@ macro_rules! switch_impl (
         )),
         $crate::IResult::Incomplete(i) => $crate::IResult::Incomplete(i),
         $crate::IResult::Done(i, o)    => {
-          match o {
-            $($p => match $subrule!(i, $($args2)*) {
-              $crate::IResult::Error(e) => $crate::IResult::Error(error_node_position!(
-                  $crate::ErrorKind::Switch, $i, e
-              )),
-              a => a,
-            }),*,
-            _    => $crate::IResult::Error(error_position!($crate::ErrorKind::Switch,$i))
-          }
+          switch_arms!($i, i, o, [], $($rest)*)
         }
       }
     }
   );
 );
 
+/// Internal parser, do not use directly
+///
+/// accumulates the match arms, then generates the match on the selector's result
+#[doc(hidden)]
+#[macro_export]
+macro_rules! switch_arms (
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], _ => $subrule:ident!( $($args:tt)* )) => (
+    match $o {
+      $($arms)*
+      _ => switch_branch!($i, $input, $subrule!($($args)*))
+    }
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], _ => $e:ident) => (
+    switch_arms!($i, $input, $o, [$($arms)*], _ => call!($e))
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], $p:pat if $cond:expr => $subrule:ident!( $($args:tt)* ) | $($rest:tt)*) => (
+    switch_arms!($i, $input, $o, [$($arms)* $p if $cond => switch_branch!($i, $input, $subrule!($($args)*)),], $($rest)*)
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], $p:pat if $cond:expr => $e:ident | $($rest:tt)*) => (
+    switch_arms!($i, $input, $o, [$($arms)*], $p if $cond => call!($e) | $($rest)*)
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], $p:pat if $cond:expr => $subrule:ident!( $($args:tt)* )) => (
+    switch_arms!($i, $input, $o, [$($arms)* $p if $cond => switch_branch!($i, $input, $subrule!($($args)*)),],)
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], $p:pat if $cond:expr => $e:ident) => (
+    switch_arms!($i, $input, $o, [$($arms)*], $p if $cond => call!($e))
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], $p:pat => $subrule:ident!( $($args:tt)* ) | $($rest:tt)*) => (
+    switch_arms!($i, $input, $o, [$($arms)* $p => switch_branch!($i, $input, $subrule!($($args)*)),], $($rest)*)
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], $p:pat => $e:ident | $($rest:tt)*) => (
+    switch_arms!($i, $input, $o, [$($arms)*], $p => call!($e) | $($rest)*)
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], $p:pat => $subrule:ident!( $($args:tt)* )) => (
+    switch_arms!($i, $input, $o, [$($arms)* $p => switch_branch!($i, $input, $subrule!($($args)*)),],)
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*], $p:pat => $e:ident) => (
+    switch_arms!($i, $input, $o, [$($arms)*], $p => call!($e))
+  );
+  ($i:expr, $input:ident, $o:ident, [$($arms:tt)*],) => (
+    match $o {
+      $($arms)*
+      _ => $crate::IResult::Error(error_position!($crate::ErrorKind::Switch,$i))
+    }
+  );
+);
+
+/// Internal parser, do not use directly
+#[doc(hidden)]
+#[macro_export]
+macro_rules! switch_branch (
+  ($i:expr, $input:ident, $subrule:ident!( $($args:tt)* )) => (
+    match $subrule!($input, $($args)*) {
+      $crate::IResult::Error(e) => $crate::IResult::Error(error_node_position!(
+          $crate::ErrorKind::Switch, $i, e
+      )),
+      a => a,
+    }
+  );
+);
+
+/// `permutation!(I -> IResult<I,A>, I -> IResult<I,B>, ... I -> IResult<I,X> ) => I -> IResult<I, (A,B,...X)>`
+/// applies its sub parsers in a sequence, but independent from their order
+/// this parser will only succeed if all of its required sub parsers succeed
+///
+/// A sub parser followed by `?` is optional: its result is wrapped in an `Option`,
+/// and it is `None` if that parser never succeeded. Any number of sub parsers can be used.
+///
+/// If none of the remaining parsers can advance, the error of the first required
+/// sub parser is wrapped in an `ErrorKind::Permutation` error.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{Done,Error};
+/// # #[cfg(feature = "verbose-errors")]
+/// # use nom::Err::{Position,NodePosition};
+/// # use nom::ErrorKind;
+/// # fn main() {
+///  named!(perm<(&[u8], Option<&[u8]>, &[u8])>,
+///    permutation!(tag!("abcd"), tag!("efg")?, tag!("hi"))
+///  );
+///
+///  let a = &b"abcdefghijk"[..];
+///  assert_eq!(perm(a), Done(&b"jk"[..], (&b"abcd"[..], Some(&b"efg"[..]), &b"hi"[..])));
+///  let b = &b"hiabcdjk"[..];
+///  assert_eq!(perm(b), Done(&b"jk"[..], (&b"abcd"[..], None, &b"hi"[..])));
+///
+///  let c = &b"efgxyz"[..];
+///  assert_eq!(perm(c), Error(error_node_position!(ErrorKind::Permutation, &b"xyz"[..],
+///    error_position!(ErrorKind::Tag, &b"xyz"[..]))));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! permutation (
   ($i:expr, $($rest:tt)*) => (
+    permutation_items!(($i, []), $($rest)*)
+  );
+);
+
+/// Internal parser, do not use directly
+///
+/// rewrites the sub parsers to `[req submac!(...)]` or `[opt submac!(...)]`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! permutation_items (
+  (($i:expr, [$($items:tt)*]), $e:ident?, $($rest:tt)*) => (
+    permutation_items!(($i, [$($items)* [opt call!($e)]]), $($rest)*)
+  );
+  (($i:expr, [$($items:tt)*]), $e:ident, $($rest:tt)*) => (
+    permutation_items!(($i, [$($items)* [req call!($e)]]), $($rest)*)
+  );
+  (($i:expr, [$($items:tt)*]), $submac:ident!( $($args:tt)* )?, $($rest:tt)*) => (
+    permutation_items!(($i, [$($items)* [opt $submac!($($args)*)]]), $($rest)*)
+  );
+  (($i:expr, [$($items:tt)*]), $submac:ident!( $($args:tt)* ), $($rest:tt)*) => (
+    permutation_items!(($i, [$($items)* [req $submac!($($args)*)]]), $($rest)*)
+  );
+  (($i:expr, [$($items:tt)*]), $e:ident?) => (
+    permutation_items!(($i, [$($items)* [opt call!($e)]]),)
+  );
+  (($i:expr, [$($items:tt)*]), $e:ident) => (
+    permutation_items!(($i, [$($items)* [req call!($e)]]),)
+  );
+  (($i:expr, [$($items:tt)*]), $submac:ident!( $($args:tt)* )?) => (
+    permutation_items!(($i, [$($items)* [opt $submac!($($args)*)]]),)
+  );
+  (($i:expr, [$($items:tt)*]), $submac:ident!( $($args:tt)* )) => (
+    permutation_items!(($i, [$($items)* [req $submac!($($args)*)]]),)
+  );
+  (($i:expr, [$($items:tt)*]),) => (
     {
-      use util::HexDisplay;
-      let mut res    = permutation_init!((), $($rest)*);
+      let mut res    = permutation_init!($($items)*);
       let mut input  = $i;
-      let mut error  = None;
-      let mut needed = None;
+      let mut error;
+      let mut needed = ::std::option::Option::None;
 
       loop {
-        let mut all_done = true;
-        println!("res = {:?}, input:\n{}", res, input.to_hex(16));
-        permutation_iterator!(0, input, all_done, needed, res, $($rest)*);
-
-        //if we reach that part, it means none of the parsers were able to read anything
-        if !all_done {
-          //FIXME: should wrap the error returned by the child parser
-          error = Some(error_position!($crate::ErrorKind::Permutation, input));
-        }
+        error = ::std::option::Option::None;
+        permutation_iterator!(input, error, needed, res, $($items)*);
+
+        // if we reach that part, none of the remaining parsers were able to advance
         break;
       }
 
-      if let Some(need) = needed {
+      if let ::std::option::Option::Some(need) = needed {
         if let $crate::Needed::Size(sz) = need {
           $crate::IResult::Incomplete(
             $crate::Needed::Size(
@@ -384,130 +503,79 @@ macro_rules! permutation (
         } else {
           $crate::IResult::Incomplete($crate::Needed::Unknown)
         }
-      } else if let Some(e) = error {
-        $crate::IResult::Error(e)
+      } else if let ::std::option::Option::Some(e) = error {
+        $crate::IResult::Error(error_node_position!($crate::ErrorKind::Permutation, input, e))
       } else {
-        let unwrapped_res = permutation_unwrap!(0, (), res, $($rest)*);
-        $crate::IResult::Done(input, unwrapped_res)
+        $crate::IResult::Done(input, permutation_unwrap!(res, (), $($items)*))
       }
     }
   );
 );
 
-
+/// Internal parser, do not use directly
+///
+/// the results are stored in nested tuples, `(Option<A>, (Option<B>, ()))`,
+/// to support any number of sub parsers
 #[doc(hidden)]
 #[macro_export]
 macro_rules! permutation_init (
-  ((), $e:ident, $($rest:tt)*) => (
-    permutation_init!((None), $($rest)*)
-  );
-  ((), $submac:ident!( $($args:tt)* ), $($rest:tt)*) => (
-    permutation_init!((None), $($rest)*)
-  );
-  (($($parsed:expr),*), $e:ident, $($rest:tt)*) => (
-    permutation_init!(($($parsed),* , None), $($rest)*);
-  );
-  (($($parsed:expr),*), $submac:ident!( $($args:tt)* ), $($rest:tt)*) => (
-    permutation_init!(($($parsed),* , None), $($rest)*);
-  );
-  (($($parsed:expr),*), $e:ident) => (
-    ($($parsed),* , None)
-  );
-  (($($parsed:expr),*), $submac:ident!( $($args:tt)* )) => (
-    ($($parsed),* , None)
+  ([$($item:tt)*] $($rest:tt)*) => (
+    (::std::option::Option::None, permutation_init!($($rest)*))
   );
-  (($($parsed:expr),*),) => (
-    ($($parsed),*)
+  () => (
+    ()
   );
 );
 
-#[doc(hidden)]
-#[macro_export]
-macro_rules! succ (
-  (0, $submac:ident ! ($($rest:tt)*)) => ($submac!(1, $($rest)*));
-  (1, $submac:ident ! ($($rest:tt)*)) => ($submac!(2, $($rest)*));
-  (2, $submac:ident ! ($($rest:tt)*)) => ($submac!(3, $($rest)*));
-  (3, $submac:ident ! ($($rest:tt)*)) => ($submac!(4, $($rest)*));
-);
-
-// HACK: for some reason, Rust 1.11 does not accept $res.$it in
-// permutation_unwrap. This is a bit ugly, but it will have None
-// impact on the generated code
-#[doc(hidden)]
-#[macro_export]
-macro_rules! acc (
-  (0, $tup:expr) => ($tup.0);
-  (1, $tup:expr) => ($tup.1);
-  (2, $tup:expr) => ($tup.2);
-  (3, $tup:expr) => ($tup.3);
-);
-
 #[doc(hidden)]
 #[macro_export]
 macro_rules! permutation_unwrap (
-  ($it:tt,  (), $res:ident, $submac:ident!( $($args:tt)* ), $($rest:tt)*) => (
-    succ!($it, permutation_unwrap!((acc!($it, $res).unwrap()), $res, $($rest)*));
-  );
-  ($it:tt, ($($parsed:expr),*), $res:ident, $e:ident, $($rest:tt)*) => (
-    succ!($it, permutation_unwrap!(($($parsed),* , acc!($it, $res).unwrap()), $res, $($rest)*));
+  ($res:expr, ($($parsed:expr),*), [req $($item:tt)*] $($rest:tt)*) => (
+    permutation_unwrap!($res.1, ($($parsed,)* $res.0.unwrap()), $($rest)*)
   );
-  ($it:tt, ($($parsed:expr),*), $res:ident, $submac:ident!( $($args:tt)* ), $($rest:tt)*) => (
-    succ!($it, permutation_unwrap!(($($parsed),* , acc!($it, $res).unwrap()), $res, $($rest)*));
+  ($res:expr, ($($parsed:expr),*), [opt $($item:tt)*] $($rest:tt)*) => (
+    permutation_unwrap!($res.1, ($($parsed,)* $res.0), $($rest)*)
   );
-  ($it:tt, ($($parsed:expr),*), $res:ident, $e:ident) => (
-    ($($parsed),* , { acc!($it, $res).unwrap() })
-  );
-  ($it:tt, ($($parsed:expr),*), $res:ident, $submac:ident!( $($args:tt)* )) => (
-    ($($parsed),* , acc!($it, $res).unwrap() )
+  ($res:expr, ($($parsed:expr),*),) => (
+    ($($parsed),*)
   );
 );
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! permutation_iterator (
-  ($it:tt,$i:expr, $all_done:expr, $needed:expr, $res:expr, $e:ident, $($rest:tt)*) => (
-    permutation_iterator!($it, $i, $all_done, $needed, $res, call!($e), $($rest)*);
-  );
-  ($it:tt, $i:expr, $all_done:expr, $needed:expr, $res:expr, $submac:ident!( $($args:tt)* ), $($rest:tt)*) => {
-    if acc!($it, $res) == None {
+  ($i:ident, $error:ident, $needed:ident, $res:expr, [$kind:ident $submac:ident!( $($args:tt)* )] $($rest:tt)*) => {
+    if $res.0.is_none() {
       match $submac!($i, $($args)*) {
         $crate::IResult::Done(i,o)     => {
           $i = i;
-          acc!($it, $res) = Some(o);
+          $res.0 = ::std::option::Option::Some(o);
           continue;
         },
-        $crate::IResult::Error(_) => {
-          $all_done = false;
+        $crate::IResult::Error(e)      => {
+          permutation_error!($kind, $error, e);
         },
         $crate::IResult::Incomplete(i) => {
-          $needed = Some(i);
+          $needed = ::std::option::Option::Some(i);
           break;
         }
       };
     }
-    succ!($it, permutation_iterator!($i, $all_done, $needed, $res, $($rest)*));
+    permutation_iterator!($i, $error, $needed, $res.1, $($rest)*);
   };
-  ($it:tt,$i:expr, $all_done:expr, $needed:expr, $res:expr, $e:ident) => (
-    permutation_iterator!($it, $i, $all_done, $res, call!($e));
-  );
-  ($it:tt, $i:expr, $all_done:expr, $needed:expr, $res:expr, $submac:ident!( $($args:tt)* )) => {
-    if acc!($it, $res) == None {
-      match $submac!($i, $($args)*) {
-        $crate::IResult::Done(i,o)     => {
-          $i = i;
-          acc!($it, $res) = Some(o);
-          continue;
-        },
-        $crate::IResult::Error(_) => {
-          $all_done = false;
-        },
-        $crate::IResult::Incomplete(i) => {
-          $needed = Some(i);
-          break;
-        }
-      };
+  ($i:ident, $error:ident, $needed:ident, $res:expr,) => {};
+);
+
+/// keeps the error of the first required parser that failed
+#[doc(hidden)]
+#[macro_export]
+macro_rules! permutation_error (
+  (req, $error:ident, $e:expr) => (
+    if $error.is_none() {
+      $error = ::std::option::Option::Some($e);
     }
-  };
+  );
+  (opt, $error:ident, $e:expr) => ();
 );
 
 #[cfg(test)]
@@ -515,6 +583,7 @@ mod tests {
   use internal::{Needed,IResult};
   use internal::IResult::*;
   use util::ErrorKind;
+  use nom::be_u8;
 
   // reproduce the tag and take macros, because of module import order
   macro_rules! tag (
@@ -664,6 +733,36 @@ mod tests {
     assert_eq!(sw(c), Error(error_position!(ErrorKind::Switch, &b"afghijkl"[..])));
   }
 
+  #[test]
+  fn switch_ranges_guards_fallback() {
+    named!(unknown<&[u8], &[u8]>, take!(1));
+    named!(sw<&[u8], &[u8]>,
+      switch!(take!(1),
+        b"a" => tag!("bc")                  |
+        t if t[0] >= b'0' && t[0] <= b'9' => take!(2) |
+        _ => unknown
+      )
+    );
+    named!(sw_range<&[u8], &[u8]>,
+      switch!(be_u8,
+        b'a'...b'f' => take!(1) |
+        b'x'        => tag!("yz")
+      )
+    );
+
+    assert_eq!(sw(&b"abcd"[..]), Done(&b"d"[..], &b"bc"[..]));
+    assert_eq!(sw(&b"5xyz"[..]), Done(&b"z"[..], &b"xy"[..]));
+    assert_eq!(sw(&b"-xyz"[..]), Done(&b"yz"[..], &b"x"[..]));
+    assert_eq!(sw(&b"axyz"[..]), Error(error_node_position!(ErrorKind::Switch, &b"axyz"[..],
+      error_position!(ErrorKind::Tag, &b"xyz"[..]))));
+
+    assert_eq!(sw_range(&b"cde"[..]), Done(&b"e"[..], &b"d"[..]));
+    assert_eq!(sw_range(&b"xyz"[..]), Done(&b""[..], &b"yz"[..]));
+    assert_eq!(sw_range(&b"xab"[..]), Error(error_node_position!(ErrorKind::Switch, &b"xab"[..],
+      error_position!(ErrorKind::Tag, &b"ab"[..]))));
+    assert_eq!(sw_range(&b"zab"[..]), Error(error_position!(ErrorKind::Switch, &b"zab"[..])));
+  }
+
   #[test]
   fn permutation() {
     //trace_macros!(true);
@@ -682,9 +781,40 @@ mod tests {
     assert_eq!(perm(c), Done(&b"jk"[..], expected));
 
     let d = &b"efgxyzabcdefghi"[..];
-    assert_eq!(perm(d), Error(error_position!(ErrorKind::Permutation, &b"xyzabcdefghi"[..])));
+    assert_eq!(perm(d), Error(error_node_position!(ErrorKind::Permutation, &b"xyzabcdefghi"[..],
+      error_position!(ErrorKind::Tag, &b"xyzabcdefghi"[..]))));
 
     let e = &b"efgabc"[..];
     assert_eq!(perm(e), Incomplete(Needed::Size(7)));
   }
+
+  #[test]
+  fn permutation_optional() {
+    named!(abcd, tag!("abcd"));
+    named!(perm<(Option<&[u8]>, &[u8], Option<&[u8]>)>,
+      permutation!(abcd?, tag!("efg"), tag!("hi")?)
+    );
+
+    let a = &b"hiefgabcdjk"[..];
+    assert_eq!(perm(a), Done(&b"jk"[..], (Some(&b"abcd"[..]), &b"efg"[..], Some(&b"hi"[..]))));
+    let b = &b"efgjk"[..];
+    assert_eq!(perm(b), Done(&b"jk"[..], (None, &b"efg"[..], None)));
+    let c = &b"hijk"[..];
+    assert_eq!(perm(c), Error(error_node_position!(ErrorKind::Permutation, &b"jk"[..],
+      error_position!(ErrorKind::Tag, &b"jk"[..]))));
+    let d = &b"hiab"[..];
+    assert_eq!(perm(d), Incomplete(Needed::Size(6)));
+  }
+
+  #[test]
+  fn permutation_many() {
+    named!(perm<(&[u8], &[u8], &[u8], &[u8], &[u8], &[u8], &[u8])>,
+      permutation!(tag!("a"), tag!("b"), tag!("c"), tag!("d"), tag!("e"), tag!("f"), tag!("g"),)
+    );
+
+    let expected = (&b"a"[..], &b"b"[..], &b"c"[..], &b"d"[..], &b"e"[..], &b"f"[..], &b"g"[..]);
+    assert_eq!(perm(&b"gfedcbaz"[..]), Done(&b"z"[..], expected));
+    assert_eq!(perm(&b"abcdefgz"[..]), Done(&b"z"[..], expected));
+    assert_eq!(perm(&b"dacbgfez"[..]), Done(&b"z"[..], expected));
+  }
 }
@@ -35,6 +35,90 @@ macro_rules! recognize (
   );
 );
 
+/// `consumed!(I -> IResult<I, O>) => I -> IResult<I, (I, O)>`
+/// if the child parser was successful, return the consumed input along with the produced value
+///
+/// Unlike `recognize!`, it keeps the child parser's output, and works on `&[u8]` and `&str`
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::digit;
+/// # fn main() {
+///  named!(x<&[u8], (&[u8], &[u8])>, consumed!(delimited!(tag!("("), digit, tag!(")"))));
+///  assert_eq!(x(&b"(123) aaa"[..]), Done(&b" aaa"[..], (&b"(123)"[..], &b"123"[..])));
+///
+///  named!(y<&str, (&str, &str)>, consumed!(preceded!(tag_s!("#"), take_s!(2))));
+///  assert_eq!(y("#été"), Done("é", ("#ét", "ét")));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! consumed (
+  ($i:expr, $submac:ident!( $($args:tt)* )) => (
+    {
+      let input = $i;
+      use $crate::Offset;
+      match $submac!(input, $($args)*) {
+        $crate::IResult::Done(i,o)     => {
+          let index = input.offset(i);
+          $crate::IResult::Done(i, (&input[..index], o))
+        },
+        $crate::IResult::Error(e)      => $crate::IResult::Error(e),
+        $crate::IResult::Incomplete(i) => $crate::IResult::Incomplete(i)
+      }
+    }
+  );
+  ($i:expr, $f:expr) => (
+    consumed!($i, call!($f))
+  );
+);
+
+/// `with_span!(I, I -> IResult<I, O>) => I -> IResult<I, (I, O, (usize, usize))>`
+/// if the child parser was successful, return the consumed input, its output, and the
+/// `(start, end)` offsets of the consumed input relative to a base input, like `consumed!`
+///
+/// The base input must be the complete input from which the current input was sliced,
+/// like the buffer passed to the top level parser. It works on `&[u8]` and `&str`,
+/// and the offsets are in bytes.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{self,Done};
+/// # use nom::alpha;
+/// # fn main() {
+///  fn idents<'a>(input: &'a [u8], base: &'a [u8]) -> IResult<&'a [u8], Vec<(&'a [u8], (usize, usize))>> {
+///    many0!(input, map!(
+///      terminated!(with_span!(base, alpha), opt!(tag!(" "))),
+///      |(consumed, _, span)| (consumed, span)
+///    ))
+///  }
+///
+///  let source = &b"let abc;"[..];
+///  assert_eq!(idents(source, source), Done(&b";"[..], vec![(&b"let"[..], (0, 3)), (&b"abc"[..], (4, 7))]));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! with_span (
+  ($i:expr, $base:expr, $submac:ident!( $($args:tt)* )) => (
+    {
+      let input = $i;
+      use $crate::Offset;
+      match $submac!(input, $($args)*) {
+        $crate::IResult::Done(i,o)     => {
+          let index = input.offset(i);
+          let start = ($base).offset(input);
+          $crate::IResult::Done(i, (&input[..index], o, (start, start + index)))
+        },
+        $crate::IResult::Error(e)      => $crate::IResult::Error(e),
+        $crate::IResult::Incomplete(i) => $crate::IResult::Incomplete(i)
+      }
+    }
+  );
+  ($i:expr, $base:expr, $f:expr) => (
+    with_span!($i, $base, call!($f))
+  );
+);
+
 /// `tag!(&[T]: nom::AsBytes) => &[T] -> IResult<&[T], &[T]>`
 /// declares a byte array as a suite to recognize
 ///
@@ -91,6 +175,96 @@ macro_rules! tag_bytes (
   );
 );
 
+/// `tag_nocase!(&[T]: nom::AsBytes) => &[T] -> IResult<&[T], &[T]>`
+/// declares a byte array as a suite to recognize, ignoring ASCII case
+///
+/// consumes the recognized characters, and returns them as they appear in the input
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(content_length, tag_nocase!("Content-Length"));
+///  assert_eq!(content_length(&b"content-LENGTH: 1"[..]), Done(&b": 1"[..], &b"content-LENGTH"[..]));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! tag_nocase (
+  ($i:expr, $inp: expr) => (
+    {
+      let input: &[u8] = $i;
+
+      #[inline(always)]
+      fn as_bytes<T: $crate::AsBytes>(b: &T) -> &[u8] {
+        b.as_bytes()
+      }
+
+      let expected = $inp;
+      let bytes    = as_bytes(&expected);
+
+      tag_nocase_bytes!(input,bytes)
+    }
+  );
+);
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tag_nocase_bytes (
+  ($i:expr, $bytes: expr) => (
+    {
+      let len = $i.len();
+      let blen = $bytes.len();
+      let m   = if len < blen { len } else { blen };
+      let reduced = &$i[..m];
+      let b       = &$bytes[..m];
+
+      let res: $crate::IResult<_,_> = if !reduced.eq_ignore_ascii_case(b) {
+        $crate::IResult::Error(error_position!($crate::ErrorKind::Tag, $i))
+      } else if m < blen {
+        $crate::IResult::Incomplete($crate::Needed::Size(blen))
+      } else {
+        $crate::IResult::Done(&$i[blen..], reduced)
+      };
+      res
+    }
+  );
+);
+
+/// `tag_nocase_unicode!(&str) => &[u8] -> IResult<&[u8], &[u8]>`
+/// declares a string as a suite to recognize in UTF-8 input, ignoring case for all scripts
+///
+/// characters are compared with their full Unicode case folding, so the recognized input
+/// can have a different length than the tag. Invalid UTF-8 input does not match.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(street, tag_nocase_unicode!("straße"));
+///  assert_eq!(street(&b"STRA\xc3\x9fE 1"[..]), Done(&b" 1"[..], &b"STRA\xc3\x9fE"[..]));
+///  assert_eq!(street("Straße 1".as_bytes()), Done(&b" 1"[..], "Straße".as_bytes()));
+///  assert_eq!(street(&b"STRASSE 1"[..]), Done(&b" 1"[..], &b"STRASSE"[..]));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! tag_nocase_unicode (
+  ($i:expr, $tag: expr) => (
+    {
+      let input: &[u8] = $i;
+      let tag: &str    = $tag;
+
+      let res: $crate::IResult<_,_> = match $crate::tag_nocase_len_bytes(input, tag) {
+        $crate::NocaseMatch::Match(n)   => $crate::IResult::Done(&input[n..], &input[..n]),
+        $crate::NocaseMatch::Incomplete => {
+          $crate::IResult::Incomplete($crate::Needed::Size(if input.len() < tag.len() { tag.len() } else { input.len() + 1 }))
+        },
+        $crate::NocaseMatch::Mismatch   => $crate::IResult::Error(error_position!($crate::ErrorKind::Tag, input)),
+      };
+      res
+    }
+  );
+);
+
 /// `is_not!(&[T:AsBytes]) => &[T] -> IResult<&[T], &[T]>`
 /// returns the longest list of bytes that do not appear in the provided array
 ///
@@ -210,6 +384,43 @@ macro_rules! is_a_bytes (
   );
 );
 
+/// `is_a_nocase!(&[T]) => &[T] -> IResult<&[T], &[T]>`
+/// returns the longest list of bytes that appear in the provided array, ignoring ASCII case
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(hex, is_a_nocase!("0123456789abcdef"));
+///  assert_eq!(hex(&b"12aBcD;"[..]), Done(&b";"[..], &b"12aBcD"[..]));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! is_a_nocase (
+  ($input:expr, $arr:expr) => (
+    {
+      let input: &[u8] = $input;
+
+      #[inline(always)]
+      fn as_bytes<T: $crate::AsBytes>(b: &T) -> &[u8] {
+        b.as_bytes()
+      }
+
+      let expected  = $arr;
+      let bytes     = as_bytes(&expected);
+
+      let res: $crate::IResult<_,_> = match input.iter().position(|c| {
+        !bytes.iter().any(|i| c.eq_ignore_ascii_case(i))
+      }) {
+        Some(0) => $crate::IResult::Error(error_position!($crate::ErrorKind::IsA,input)),
+        Some(n) => $crate::IResult::Done(&input[n..], &input[..n]),
+        None    => $crate::IResult::Done(&input[input.len()..], input)
+      };
+      res
+    }
+  );
+);
+
 /// `escaped!(&[T] -> IResult<&[T], &[T]>, T, &[T] -> IResult<&[T], &[T]>) => &[T] -> IResult<&[T], &[T]>`
 /// matches a byte string with escaped characters.
 ///
@@ -309,14 +520,17 @@ macro_rules! escaped_impl (
   );
 );
 
-/// `escaped_transform!(&[T] -> IResult<&[T], &[T]>, T, &[T] -> IResult<&[T], &[T]>) => &[T] -> IResult<&[T], Vec<T>>`
+/// `escaped_transform!(&[T] -> IResult<&[T], &[T]>, T, &[T] -> IResult<&[T], &[T]>) => &[T] -> IResult<&[T], Cow<[u8]>>`
 /// matches a byte string with escaped characters.
 ///
 /// The first argument matches the normal characters (it must not match the control character), the second argument is the control character (like `\` in most languages),
-/// the third argument matches the escaped characters and trnasforms them.
+/// the third argument matches the escaped characters and transforms them.
 ///
 /// As an example, the chain `abc\tdef` could be `abc    def` (it also consumes the control character)
 ///
+/// The output borrows the input if it contains no escaped characters, and is only
+/// allocated at the first escaped sequence
+///
 /// WARNING: if you do not use the `verbose-errors` feature, this combinator will currently fail to build
 /// because of a type inference error
 ///
@@ -324,24 +538,19 @@ macro_rules! escaped_impl (
 /// # #[macro_use] extern crate nom;
 /// # use nom::IResult::Done;
 /// # use nom::alpha;
-/// # use std::str::from_utf8;
+/// # use std::borrow::Cow;
 /// # fn main() {
-/// fn to_s(i:Vec<u8>) -> String {
-///   String::from_utf8_lossy(&i).into_owned()
-/// }
-
-///  named!(transform < String >,
-///    map!(
-///      escaped_transform!(call!(alpha), '\\',
-///        alt!(
-///            tag!("\\")       => { |_| &b"\\"[..] }
-///          | tag!("\"")       => { |_| &b"\""[..] }
-///          | tag!("n")        => { |_| &b"\n"[..] }
-///        )
-///      ), to_s
+///  named!(transform<Cow<[u8]> >,
+///    escaped_transform!(call!(alpha), '\\',
+///      alt!(
+///          tag!("\\")       => { |_| &b"\\"[..] }
+///        | tag!("\"")       => { |_| &b"\""[..] }
+///        | tag!("n")        => { |_| &b"\n"[..] }
+///      )
 ///    )
 ///  );
-///  assert_eq!(transform(&b"ab\\\"cd"[..]), Done(&b""[..], String::from("ab\"cd")));
+///  assert_eq!(transform(&b"ab\\\"cd"[..]), Done(&b""[..], Cow::Owned(b"ab\"cd".to_vec())));
+///  assert_eq!(transform(&b"abcd"[..]), Done(&b""[..], Cow::Borrowed(&b"abcd"[..])));
 /// # }
 /// ```
 #[macro_export]
@@ -381,12 +590,17 @@ macro_rules! escaped_transform_impl (
       use $crate::InputLength;
       let cl = || {
         use $crate::Offset;
+        use ::std::borrow::Cow;
         let mut index  = 0;
-        let mut res = Vec::new();
+        // borrows the input until the first escaped sequence
+        let mut res: Cow<[u8]> = Cow::Borrowed(&$i[..0]);
 
         while index < $i.len() {
           if let $crate::IResult::Done(i,o) = $normal!(&$i[index..], $($args)*) {
-            res.extend(o.iter().cloned());
+            match res {
+              Cow::Owned(ref mut v) => v.extend(o.iter().cloned()),
+              Cow::Borrowed(_)      => res = Cow::Borrowed(&$i[..$i.offset(i)]),
+            }
             if i.is_empty() {
               return $crate::IResult::Done(&$i[$i.input_len()..], res);
             } else {
@@ -398,7 +612,7 @@ macro_rules! escaped_transform_impl (
             } else {
               match $transform!(&$i[index+1..], $($args2)*) {
                 $crate::IResult::Done(i,o) => {
-                  res.extend(o.iter().cloned());
+                  res.to_mut().extend(o.iter().cloned());
                   if i.is_empty() {
                     return $crate::IResult::Done(&$i[$i.input_len()..], res)
                   } else {
@@ -566,6 +780,107 @@ macro_rules! take_str (
   );
 );
 
+/// `take_utf8!(nb) => &[u8] -> IResult<&[u8], &str>`
+/// same as take_str! but reports invalid UTF-8 as an `ErrorKind::Utf8` error
+/// at the position of the invalid sequence
+///
+/// If the input is shorter than `nb` bytes, it returns `Incomplete`, unless the
+/// available bytes are already invalid. A character cut by the end of the `nb` bytes is invalid.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{Done,Error};
+/// # use nom::ErrorKind;
+/// # fn main() {
+///  named!(take5<&str>, take_utf8!(5));
+///
+///  assert_eq!(take5("été!".as_bytes()), Done(&b"!"[..], "été"));
+///  let invalid = &b"ab\xffcd"[..];
+///  assert_eq!(take5(invalid), Error(error_position!(ErrorKind::Utf8, &invalid[2..])));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! take_utf8 (
+  ( $i:expr, $size:expr ) => (
+    {
+      let input: &[u8] = $i;
+
+      let cnt = $size as usize;
+      let available = if input.len() < cnt { input.len() } else { cnt };
+      let res: $crate::IResult<_,_> = match ::std::str::from_utf8(&input[..available]) {
+        Ok(s) => if available < cnt {
+          $crate::IResult::Incomplete($crate::Needed::Size(cnt))
+        } else {
+          $crate::IResult::Done(&input[cnt..], s)
+        },
+        Err(e) => if available < cnt && e.error_len().is_none() {
+          $crate::IResult::Incomplete($crate::Needed::Size(cnt))
+        } else {
+          $crate::IResult::Error(error_position!($crate::ErrorKind::Utf8, &input[e.valid_up_to()..]))
+        },
+      };
+      res
+    }
+  );
+);
+
+/// `take_utf8_chars!(nb) => &[u8] -> IResult<&[u8], &str>`
+/// decodes `nb` UTF-8 characters and returns them as a &str
+///
+/// A sequence truncated at the end of the input returns `Incomplete` with the size
+/// of the complete sequence, and an invalid sequence returns an `ErrorKind::Utf8` error
+/// at its position
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{Done,Incomplete};
+/// # use nom::Needed;
+/// # fn main() {
+///  named!(two_chars<&str>, take_utf8_chars!(2));
+///
+///  assert_eq!(two_chars("€€€".as_bytes()), Done("€".as_bytes(), "€€"));
+///  // the second euro sign needs 3 bytes
+///  assert_eq!(two_chars(&"€€".as_bytes()[..4]), Incomplete(Needed::Size(6)));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! take_utf8_chars (
+  ( $i:expr, $count:expr ) => (
+    {
+      let input: &[u8] = $i;
+
+      let cnt = $count as usize;
+      let mut index = 0;
+      let mut failure = None;
+      for decoded in 0..cnt {
+        match $crate::decode_utf8(&input[index..]) {
+          $crate::DecodedChar::Char(_, len) => index += len,
+          $crate::DecodedChar::Incomplete($crate::Needed::Size(width)) => {
+            // the next characters need at least one byte each
+            failure = Some($crate::IResult::Incomplete($crate::Needed::Size(index + width + cnt - decoded - 1)));
+            break;
+          },
+          $crate::DecodedChar::Incomplete($crate::Needed::Unknown) => {
+            failure = Some($crate::IResult::Incomplete($crate::Needed::Unknown));
+            break;
+          },
+          $crate::DecodedChar::Invalid => {
+            failure = Some($crate::IResult::Error(error_position!($crate::ErrorKind::Utf8, &input[index..])));
+            break;
+          },
+        }
+      }
+
+      let res: $crate::IResult<_,_> = match failure {
+        Some(f) => f,
+        // the first index bytes were validated by decode_utf8
+        None    => $crate::IResult::Done(&input[index..], unsafe { ::std::str::from_utf8_unchecked(&input[..index]) }),
+      };
+      res
+    }
+  );
+);
+
 /// `take_until_and_consume!(tag) => &[T] -> IResult<&[T], &[T]>`
 /// generates a parser consuming bytes until the specified byte sequence is found, and consumes it
 #[macro_export]
@@ -640,6 +955,44 @@ macro_rules! take_until(
   );
 );
 
+/// `take_until_nocase!(tag) => &[T] -> IResult<&[T], &[T]>`
+/// consumes data until it finds the specified tag, ignoring ASCII case
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(body, take_until_nocase!("</body>"));
+///  assert_eq!(body(&b"hello</BODY>"[..]), Done(&b"</BODY>"[..], &b"hello"[..]));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! take_until_nocase(
+  ($i:expr, $inp:expr) => (
+    {
+      let input: &[u8] = $i;
+
+      #[inline(always)]
+      fn as_bytes<T: $crate::AsBytes>(b: &T) -> &[u8] {
+        b.as_bytes()
+      }
+
+      let expected   = $inp;
+      let bytes      = as_bytes(&expected);
+
+      let res: $crate::IResult<_,_> = if bytes.len() > input.len() {
+        $crate::IResult::Incomplete($crate::Needed::Size(bytes.len()))
+      } else {
+        match input.windows(bytes.len()).position(|w| w.eq_ignore_ascii_case(bytes)) {
+          Some(index) => $crate::IResult::Done(&input[index..], &input[0..index]),
+          None        => $crate::IResult::Error(error_position!($crate::ErrorKind::TakeUntil,input))
+        }
+      };
+      res
+    }
+  );
+);
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! take_until_bytes(
@@ -821,6 +1174,7 @@ mod tests {
   use internal::Needed;
   use internal::IResult::*;
   use util::ErrorKind;
+  use std::borrow::Cow;
   use nom::{alpha, digit, hex_digit, oct_digit, alphanumeric, space, multispace};
 
   #[test]
@@ -877,7 +1231,7 @@ mod tests {
       error_position!(ErrorKind::IsA, &b"A"[..]))));
   }
 
-  fn to_s(i:Vec<u8>) -> String {
+  fn to_s(i:Cow<[u8]>) -> String {
     String::from_utf8_lossy(&i).into_owned()
   }
 
@@ -916,6 +1270,35 @@ mod tests {
     assert_eq!(esc2(&b"ab&egrave;D&agrave;EF"[..]), Done(&b""[..], String::from("abèDàEF")));
   }
 
+  #[cfg(feature = "verbose-errors")]
+  #[test]
+  fn escape_transform_borrows() {
+    named!(esc<Cow<[u8]> >, escaped_transform!(alpha, '\\',
+      alt!(
+          tag!("\\")       => { |_| &b"\\"[..] }
+        | tag!("n")        => { |_| &b"\n"[..] }
+      ))
+    );
+
+    let input = &b"abcd;"[..];
+    match esc(input) {
+      Done(i, Cow::Borrowed(o)) => {
+        assert_eq!(i, &b";"[..]);
+        assert_eq!(o.as_ptr(), input.as_ptr());
+        assert_eq!(o, &b"abcd"[..]);
+      },
+      other => panic!("expected borrowed output, got {:?}", other),
+    }
+
+    match esc(&b"ab\\ncd;"[..]) {
+      Done(i, Cow::Owned(o)) => {
+        assert_eq!(i, &b";"[..]);
+        assert_eq!(o, b"ab\ncd".to_vec());
+      },
+      other => panic!("expected owned output, got {:?}", other),
+    }
+  }
+
   #[test]
   fn issue_84() {
     let r0 = is_a!(&b"aaaaefgh"[..], "abcd");
@@ -934,6 +1317,37 @@ mod tests {
     assert_eq!(take_str!(&a[..], 9), Incomplete(Needed::Size(9)));
   }
 
+  #[test]
+  fn take_utf8() {
+    named!(take5<&str>, take_utf8!(5));
+
+    assert_eq!(take5("groß!".as_bytes()), Done(&b"!"[..], "groß"));
+    assert_eq!(take5(&b"gr"[..]), Incomplete(Needed::Size(5)));
+    // truncated at the end of the input
+    assert_eq!(take5(&b"gr\xc3"[..]), Incomplete(Needed::Size(5)));
+    // invalid before the end of the input
+    let invalid = &b"gr\xc3("[..];
+    assert_eq!(take5(invalid), Error(error_position!(ErrorKind::Utf8, &invalid[2..])));
+    // cut by the end of the 5 bytes
+    let cut = "größe".as_bytes();
+    assert_eq!(take5(cut), Error(error_position!(ErrorKind::Utf8, &cut[4..])));
+  }
+
+  #[test]
+  fn take_utf8_chars() {
+    named!(take3<&str>, take_utf8_chars!(3));
+
+    assert_eq!(take3("aé字b".as_bytes()), Done(&b"b"[..], "aé字"));
+    assert_eq!(take3("aé字".as_bytes()), Done(&b""[..], "aé字"));
+    assert_eq!(take3(&b""[..]), Incomplete(Needed::Size(3)));
+    assert_eq!(take3("aé".as_bytes()), Incomplete(Needed::Size(4)));
+    assert_eq!(take3(&"aé字".as_bytes()[..4]), Incomplete(Needed::Size(6)));
+    assert_eq!(take3(&b"a\xf0\x9f"[..]), Incomplete(Needed::Size(6)));
+
+    let invalid = &b"a\xed\xa0\x80b"[..];
+    assert_eq!(take3(invalid), Error(error_position!(ErrorKind::Utf8, &invalid[1..])));
+  }
+
   #[test]
   fn take_until_test() {
     named!(x, take_until_and_consume!("efgh"));
@@ -977,6 +1391,77 @@ mod tests {
     );
   }
 
+  #[test]
+  fn consumed() {
+    named!(x<&[u8], (&[u8], &[u8])>, consumed!(delimited!(tag!("<!--"), take!(5), tag!("-->"))));
+    assert_eq!(x(&b"<!-- abc --> aaa"[..]), Done(&b" aaa"[..], (&b"<!-- abc -->"[..], &b" abc "[..])));
+    assert_eq!(x(&b"<!-- abc"[..]), Incomplete(Needed::Size(9)));
+    assert_eq!(x(&b"<!-- abc ->"[..]), Error(error_position!(ErrorKind::Tag, &b"->"[..])));
+
+    named!(y<&[u8], (&[u8], &[u8])>, consumed!(alpha));
+    assert_eq!(y(&b"abc123"[..]), Done(&b"123"[..], (&b"abc"[..], &b"abc"[..])));
+  }
+
+  #[test]
+  fn with_span() {
+    let base = &b"abc 1234;"[..];
+    fn word<'a>(input: &'a [u8], base: &'a [u8]) -> ::IResult<&'a [u8], (&'a [u8], &'a [u8], (usize, usize))> {
+      preceded!(input, opt!(tag!(" ")), with_span!(base, alt!(alpha | digit)))
+    }
+
+    assert_eq!(word(base, base), Done(&b" 1234;"[..], (&b"abc"[..], &b"abc"[..], (0, 3))));
+    assert_eq!(word(&base[3..], base), Done(&b";"[..], (&b"1234"[..], &b"1234"[..], (4, 8))));
+    assert_eq!(word(&base[8..], base), Error(error_position!(ErrorKind::Alt, &b";"[..])));
+
+    fn empty<'a>(input: &'a [u8], base: &'a [u8]) -> ::IResult<&'a [u8], (&'a [u8], (), (usize, usize))> {
+      with_span!(input, base, value!(()))
+    }
+    assert_eq!(empty(&base[5..], base), Done(&b"234;"[..], (&b""[..], (), (5, 5))));
+  }
+
+  #[test]
+  fn tag_nocase_test() {
+    named!(test, tag_nocase!("ABcd"));
+    assert_eq!(test(&b"aBCdefgh"[..]), Done(&b"efgh"[..], &b"aBCd"[..]));
+    assert_eq!(test(&b"abcdefgh"[..]), Done(&b"efgh"[..], &b"abcd"[..]));
+    assert_eq!(test(&b"ABCDefgh"[..]), Done(&b"efgh"[..], &b"ABCD"[..]));
+    assert_eq!(test(&b"ab"[..]), Incomplete(Needed::Size(4)));
+    assert_eq!(test(&b"Hello"[..]), Error(error_position!(ErrorKind::Tag, &b"Hello"[..])));
+    assert_eq!(test(&b"ABCe"[..]), Error(error_position!(ErrorKind::Tag, &b"ABCe"[..])));
+  }
+
+  #[test]
+  fn tag_nocase_unicode_test() {
+    named!(test, tag_nocase_unicode!("ÉTÉ"));
+    assert_eq!(test("été!".as_bytes()), Done(&b"!"[..], "été".as_bytes()));
+    assert_eq!(test("ÉtÉ!".as_bytes()), Done(&b"!"[..], "ÉtÉ".as_bytes()));
+    assert_eq!(test("ete!".as_bytes()), Error(error_position!(ErrorKind::Tag, &b"ete!"[..])));
+    assert_eq!(test(&"été".as_bytes()[..3]), Incomplete(Needed::Size(5)));
+    assert_eq!(test(&b"\xc3\xa9t\xc3"[..]), Incomplete(Needed::Size(5)));
+    assert_eq!(test("étéé".as_bytes()), Done("é".as_bytes(), "été".as_bytes()));
+    assert_eq!(test(&b"\xc3\x89T\xc3\xa9\xff"[..]), Done(&b"\xff"[..], &b"\xc3\x89T\xc3\xa9"[..]));
+    assert_eq!(test(&b"\xc3\xa9t\xff\xff\xff"[..]), Error(error_position!(ErrorKind::Tag, &b"\xc3\xa9t\xff\xff\xff"[..])));
+
+    // ß folds to ss, but only matches a whole expansion
+    named!(sharp_s, tag_nocase_unicode!("STRASSE"));
+    assert_eq!(sharp_s("straße!".as_bytes()), Done(&b"!"[..], "straße".as_bytes()));
+    named!(half, tag_nocase_unicode!("stras"));
+    assert_eq!(half("straße".as_bytes()), Error(error_position!(ErrorKind::Tag, "straße".as_bytes())));
+  }
+
+  #[test]
+  fn take_until_is_a_nocase() {
+    named!(until, take_until_nocase!("Content-Type"));
+    assert_eq!(until(&b"Host: a\r\ncontent-type: text"[..]), Done(&b"content-type: text"[..], &b"Host: a\r\n"[..]));
+    assert_eq!(until(&b"Host: example.org\r\n"[..]), Error(error_position!(ErrorKind::TakeUntil, &b"Host: example.org\r\n"[..])));
+    assert_eq!(until(&b"Host"[..]), Incomplete(Needed::Size(12)));
+
+    named!(hex, is_a_nocase!("0123456789abcdef"));
+    assert_eq!(hex(&b"fF0Ag"[..]), Done(&b"g"[..], &b"fF0A"[..]));
+    assert_eq!(hex(&b"g"[..]), Error(error_position!(ErrorKind::IsA, &b"g"[..])));
+    assert_eq!(hex(&b"aB"[..]), Done(&b""[..], &b"aB"[..]));
+  }
+
   #[test]
   fn recognize() {
     named!(x, recognize!(delimited!(tag!("<!--"), take!(5), tag!("-->"))));
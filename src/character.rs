@@ -4,22 +4,117 @@ use internal::{IResult,Needed};
 use util::ErrorKind;
 
 /// matches one of the provided characters
+///
+/// works on `&[u8]` and `&str`. The set can be a string or a slice of `char`, matched
+/// against the input decoded as UTF-8, or a byte slice, matched against the first byte
+/// of a `&[u8]` input
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(sign<char>, one_of!("+-±"));
+///  assert_eq!(sign("±1".as_bytes()), Done(&b"1"[..], '±'));
+///
+///  fn quote(input: &str) -> nom::IResult<&str, char> {
+///    one_of!(input, &['«', '"'][..])
+///  }
+///  assert_eq!(quote("«a»"), Done("a»", '«'));
+///
+///  // a set of bytes is compared with the first byte
+///  named!(marker<char>, one_of!(&b"\x80\xe9"[..]));
+///  assert_eq!(marker(&b"\xe9\x01"[..]), Done(&b"\x01"[..], '\u{e9}'));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! one_of (
   ($i:expr, $inp: expr) => (
     {
-      if $i.is_empty() {
-        $crate::IResult::Incomplete::<_, _>($crate::Needed::Size(1))
+      let expected = $inp;
+      let input = $i;
+      let decoded = if $crate::CharSet::is_byte_set(&expected) {
+        $crate::FirstChar::first_byte_char(&input)
       } else {
-        #[inline(always)]
-        fn as_bytes<T: $crate::AsBytes>(b: &T) -> &[u8] {
-          b.as_bytes()
-        }
+        $crate::FirstChar::first_char(&input)
+      };
+      satisfy_kind!(__decoded input, decoded, $crate::ErrorKind::OneOf, $crate::ErrorKind::OneOf,
+        |c| $crate::CharSet::contains_char(&expected, c))
+    }
+  );
+);
 
-        let expected = $inp;
-        let bytes = as_bytes(&expected);
-        one_of_bytes!($i, bytes)
-      }
+#[doc(hidden)]
+#[macro_export]
+macro_rules! satisfy_kind (
+  ($i:expr, $kind:expr, $predicate:expr) => (
+    {
+      let input = $i;
+      let decoded = $crate::FirstChar::first_char(&input);
+      satisfy_kind!(__decoded input, decoded, $kind, $crate::ErrorKind::Utf8, $predicate)
+    }
+  );
+  (__decoded $input:ident, $decoded:expr, $kind:expr, $invalid:expr, $predicate:expr) => (
+    {
+      let res: $crate::IResult<_, char> = match $decoded {
+        $crate::DecodedChar::Char(c, len) => {
+          if $predicate(c) {
+            $crate::IResult::Done(&$input[len..], c)
+          } else {
+            $crate::IResult::Error(error_position!($kind, $input))
+          }
+        },
+        $crate::DecodedChar::Incomplete(needed) => $crate::IResult::Incomplete(needed),
+        $crate::DecodedChar::Invalid            => $crate::IResult::Error(error_position!($invalid, $input)),
+      };
+      res
+    }
+  );
+);
+
+/// matches one character for which the predicate returns true
+///
+/// works on `&[u8]` (decoded as UTF-8) and `&str`. The predicate takes a `char`
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{Done,Error};
+/// # use nom::ErrorKind;
+/// # fn main() {
+///  fn alpha(input: &str) -> nom::IResult<&str, char> {
+///    satisfy!(input, char::is_alphabetic)
+///  }
+///  assert_eq!(alpha("λx"), Done("x", 'λ'));
+///  assert_eq!(alpha("1"), Error(error_position!(ErrorKind::Satisfy, "1")));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! satisfy (
+  ($i:expr, $predicate:expr) => (
+    satisfy_kind!($i, $crate::ErrorKind::Satisfy, $predicate)
+  );
+);
+
+/// matches one character in an inclusive range: `char_range!('a', 'z')`
+///
+/// works on `&[u8]` (decoded as UTF-8) and `&str`
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{Done,Error};
+/// # use nom::ErrorKind;
+/// # fn main() {
+///  named!(greek<char>, char_range!('α', 'ω'));
+///  assert_eq!(greek("βx".as_bytes()), Done(&b"x"[..], 'β'));
+///  assert_eq!(greek(&b"b"[..]), Error(error_position!(ErrorKind::CharRange, &b"b"[..])));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! char_range (
+  ($i:expr, $low:expr, $high:expr) => (
+    {
+      let low: char = $low;
+      let high: char = $high;
+      satisfy_kind!($i, $crate::ErrorKind::CharRange, |c| c >= low && c <= high)
     }
   );
 );
@@ -52,23 +147,73 @@ macro_rules! one_of_bytes (
   );
 );
 
+/// matches one of the provided characters, ignoring case
+///
+/// returns the character as it appears in the input. Like `one_of!`, a string or a
+/// slice of `char` is matched against the decoded input, comparing the full Unicode case
+/// folding of characters, and a byte slice against the first byte, ignoring ASCII case
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(exponent<char>, one_of_nocase!("e"));
+///  assert_eq!(exponent(&b"E10"[..]), Done(&b"10"[..], 'E'));
+///
+///  fn vowel(input: &str) -> nom::IResult<&str, char> {
+///    one_of_nocase!(input, "aeiouéè")
+///  }
+///  assert_eq!(vowel("Ét"), Done("t", 'É'));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! one_of_nocase (
+  ($i:expr, $inp: expr) => (
+    {
+      let expected = $inp;
+      let input = $i;
+      let decoded = if $crate::CharSet::is_byte_set(&expected) {
+        $crate::FirstChar::first_byte_char(&input)
+      } else {
+        $crate::FirstChar::first_char(&input)
+      };
+      satisfy_kind!(__decoded input, decoded, $crate::ErrorKind::OneOf, $crate::ErrorKind::OneOf,
+        |c| $crate::CharSet::contains_char_nocase(&expected, c))
+    }
+  );
+);
+
 /// matches anything but the provided characters
+///
+/// works on `&[u8]` and `&str`, with the same sets as `one_of!`. On `&[u8]` input,
+/// a byte which does not start a valid UTF-8 sequence is not in a set of characters,
+/// and is returned as the character with the same code point
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(not_quote<char>, none_of!("\"'"));
+///  assert_eq!(not_quote("éa".as_bytes()), Done(&b"a"[..], 'é'));
+///  assert_eq!(not_quote(&b"\xffa"[..]), Done(&b"a"[..], '\u{ff}'));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! none_of (
   ($i:expr, $inp: expr) => (
     {
-      if $i.is_empty() {
-        $crate::IResult::Incomplete::<_, _>($crate::Needed::Size(1))
+      let expected = $inp;
+      let input = $i;
+      let decoded = if $crate::CharSet::is_byte_set(&expected) {
+        $crate::FirstChar::first_byte_char(&input)
       } else {
-        #[inline(always)]
-        fn as_bytes<T: $crate::AsBytes>(b: &T) -> &[u8] {
-          b.as_bytes()
+        match $crate::FirstChar::first_char(&input) {
+          $crate::DecodedChar::Invalid => $crate::FirstChar::first_byte_char(&input),
+          decoded                      => decoded,
         }
-
-        let expected = $inp;
-        let bytes = as_bytes(&expected);
-        none_of_bytes!($i, bytes)
-      }
+      };
+      satisfy_kind!(__decoded input, decoded, $crate::ErrorKind::NoneOf, $crate::ErrorKind::NoneOf,
+        |c| !$crate::CharSet::contains_char(&expected, c))
     }
   );
 );
@@ -100,21 +245,40 @@ macro_rules! none_of_bytes (
   );
 );
 
-/// matches one character: `char!(char) => &[u8] -> IResult<&[u8], char>
+/// matches one character: `char!(char) => &[u8] -> IResult<&[u8], char>`
+///
+/// works on `&[u8]` and `&str`. A `char` is matched against the input decoded as UTF-8,
+/// and a `u8` against the first byte of a `&[u8]` input
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(euro<char>, char!('€'));
+///  assert_eq!(euro("€5".as_bytes()), Done(&b"5"[..], '€'));
+///
+///  fn comma(input: &str) -> nom::IResult<&str, char> {
+///    char!(input, ',')
+///  }
+///  assert_eq!(comma(",a"), Done("a", ','));
+///
+///  named!(escape<char>, char!(b'\x1b'));
+///  assert_eq!(escape(&b"\x1b["[..]), Done(&b"["[..], '\x1b'));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! char (
   ($i:expr, $c: expr) => (
     {
-      if $i.is_empty() {
-        let res: $crate::IResult<&[u8], char> = $crate::IResult::Incomplete($crate::Needed::Size(1));
-        res
+      let expected = $c;
+      let input = $i;
+      let decoded = if $crate::ExpectedChar::is_byte(&expected) {
+        $crate::FirstChar::first_byte_char(&input)
       } else {
-        if $i[0] == $c as u8 {
-          $crate::IResult::Done(&$i[1..], $i[0] as char)
-        } else {
-          $crate::IResult::Error(error_position!($crate::ErrorKind::Char, $i))
-        }
-      }
+        $crate::FirstChar::first_char(&input)
+      };
+      satisfy_kind!(__decoded input, decoded, $crate::ErrorKind::Char, $crate::ErrorKind::Char,
+        |c| c == $crate::ExpectedChar::as_char(&expected))
     }
   );
 );
@@ -136,6 +300,7 @@ pub fn crlf(input:&[u8]) -> IResult<&[u8], char> {
 named!(pub eol<char>, alt!(crlf | newline));
 named!(pub tab<char>, char!('\t'));
 
+/// matches any byte, returned as the character with the same code point
 pub fn anychar(input:&[u8]) -> IResult<&[u8], char> {
   if input.is_empty() {
     IResult::Incomplete(Needed::Size(1))
@@ -144,9 +309,21 @@ pub fn anychar(input:&[u8]) -> IResult<&[u8], char> {
   }
 }
 
+/// matches any character, decoding UTF-8
+pub fn anychar_utf8(input:&[u8]) -> IResult<&[u8], char> {
+  satisfy_kind!(input, ErrorKind::Utf8, |_| true)
+}
+
+/// matches any character
+pub fn anychar_s(input:&str) -> IResult<&str, char> {
+  satisfy_kind!(input, ErrorKind::Utf8, |_| true)
+}
+
 #[cfg(test)]
 mod tests {
+  use super::*;
   use internal::IResult::*;
+  use internal::{IResult,Needed};
   use util::ErrorKind;
 
   #[test]
@@ -160,6 +337,34 @@ mod tests {
     assert_eq!(f(b), Error(error_position!(ErrorKind::OneOf, b)));
   }
 
+  #[test]
+  fn one_of_nocase() {
+    named!(f<char>, one_of_nocase!("aB"));
+
+    assert_eq!(f(&b"Abcd"[..]), Done(&b"bcd"[..], 'A'));
+    assert_eq!(f(&b"bcd"[..]), Done(&b"cd"[..], 'b'));
+
+    let c = &b"cde"[..];
+    assert_eq!(f(c), Error(error_position!(ErrorKind::OneOf, c)));
+
+    named!(g<char>, one_of_nocase!("é"));
+    assert_eq!(g("É!".as_bytes()), Done(&b"!"[..], 'É'));
+    assert_eq!(g("é!".as_bytes()), Done(&b"!"[..], 'é'));
+    let a = "à".as_bytes();
+    assert_eq!(g(a), Error(error_position!(ErrorKind::OneOf, a)));
+
+    named!(h<char>, one_of_nocase!(&b"x"[..]));
+    assert_eq!(h(&b"X\xff"[..]), Done(&b"\xff"[..], 'X'));
+
+    fn sigma(input: &str) -> IResult<&str, char> {
+      one_of_nocase!(input, &['σ', 'ß'][..])
+    }
+    assert_eq!(sigma("Σa"), Done("a", 'Σ'));
+    assert_eq!(sigma("ςa"), Done("a", 'ς'));
+    assert_eq!(sigma("sa"), Error(error_position!(ErrorKind::OneOf, "sa")));
+    assert_eq!(sigma("\u{1e9e}a"), Done("a", '\u{1e9e}'));
+  }
+
   #[test]
   fn none_of() {
     named!(f<char>, none_of!("ab"));
@@ -181,4 +386,106 @@ mod tests {
     let b = &b"cde"[..];
     assert_eq!(f(b), Done(&b"de"[..], 'c'));
   }
+
+  #[test]
+  fn char_utf8() {
+    named!(f<char>, char!('é'));
+
+    assert_eq!(f("été".as_bytes()), Done("té".as_bytes(), 'é'));
+    assert_eq!(f(&b"\xc3"[..]), Incomplete(Needed::Size(2)));
+    assert_eq!(f(&b""[..]), Incomplete(Needed::Size(1)));
+    let e = &b"e"[..];
+    assert_eq!(f(e), Error(error_position!(ErrorKind::Char, e)));
+    // 'é' encoded in Latin-1 is not valid UTF-8
+    let latin1 = &b"\xe9t"[..];
+    assert_eq!(f(latin1), Error(error_position!(ErrorKind::Char, latin1)));
+
+    fn g(input: &str) -> IResult<&str, char> {
+      char!(input, 'é')
+    }
+    assert_eq!(g("été"), Done("té", 'é'));
+    assert_eq!(g("e"), Error(error_position!(ErrorKind::Char, "e")));
+    assert_eq!(g(""), Incomplete(Needed::Size(1)));
+  }
+
+  #[test]
+  fn one_of_none_of_utf8() {
+    named!(f<char>, one_of!("αβ"));
+    assert_eq!(f("βα".as_bytes()), Done("α".as_bytes(), 'β'));
+    let gamma = "γ".as_bytes();
+    assert_eq!(f(gamma), Error(error_position!(ErrorKind::OneOf, gamma)));
+
+    fn g(input: &str) -> IResult<&str, char> {
+      none_of!(input, &['\'', '"'][..])
+    }
+    assert_eq!(g("ça"), Done("a", 'ç'));
+    assert_eq!(g("\"a"), Error(error_position!(ErrorKind::NoneOf, "\"a")));
+
+    // invalid UTF-8 is not in a set of characters
+    named!(i<char>, none_of!("abc"));
+    assert_eq!(i(&b"\xff"[..]), Done(&b""[..], '\u{ff}'));
+    assert_eq!(i(&b"\xc3"[..]), Incomplete(Needed::Size(2)));
+    named!(j<char>, one_of!("abc"));
+    let invalid = &b"\xff"[..];
+    assert_eq!(j(invalid), Error(error_position!(ErrorKind::OneOf, invalid)));
+  }
+
+  #[test]
+  fn byte_sets() {
+    // a byte slice is a set of bytes, compared with the first byte
+    named!(f<char>, one_of!(&b"\x80\xe9"[..]));
+    assert_eq!(f(&b"\xe9"[..]), Done(&b""[..], '\u{e9}'));
+    let utf8 = "é".as_bytes();
+    assert_eq!(f(utf8), Error(error_position!(ErrorKind::OneOf, utf8)));
+
+    named!(g<char>, none_of!(b"\x00\xff"));
+    assert_eq!(g(&b"\xc3\xa9"[..]), Done(&b"\xa9"[..], '\u{c3}'));
+    let ff = &b"\xff"[..];
+    assert_eq!(g(ff), Error(error_position!(ErrorKind::NoneOf, ff)));
+
+    named!(h<char>, char!(b'\xff'));
+    assert_eq!(h(&b"\xff\x00"[..]), Done(&b"\x00"[..], '\u{ff}'));
+    assert_eq!(h(&b""[..]), Incomplete(Needed::Size(1)));
+    named!(a<char>, char!(b'a'));
+    assert_eq!(a(&b"ab"[..]), Done(&b"b"[..], 'a'));
+  }
+
+  #[test]
+  fn satisfy() {
+    named!(f<char>, satisfy!(|c: char| c.is_numeric()));
+    assert_eq!(f("٣1".as_bytes()), Done(&b"1"[..], '٣'));
+    let a = &b"a"[..];
+    assert_eq!(f(a), Error(error_position!(ErrorKind::Satisfy, a)));
+
+    fn g(input: &str) -> IResult<&str, char> {
+      satisfy!(input, char::is_uppercase)
+    }
+    assert_eq!(g("Éa"), Done("a", 'É'));
+    assert_eq!(g("é"), Error(error_position!(ErrorKind::Satisfy, "é")));
+  }
+
+  #[test]
+  fn char_range() {
+    named!(f<char>, char_range!('a', 'f'));
+    assert_eq!(f(&b"f0"[..]), Done(&b"0"[..], 'f'));
+    let g = &b"g"[..];
+    assert_eq!(f(g), Error(error_position!(ErrorKind::CharRange, g)));
+
+    fn cjk(input: &str) -> IResult<&str, char> {
+      char_range!(input, '\u{4e00}', '\u{9fff}')
+    }
+    assert_eq!(cjk("字x"), Done("x", '字'));
+    assert_eq!(cjk("x"), Error(error_position!(ErrorKind::CharRange, "x")));
+  }
+
+  #[test]
+  fn anychar_utf8() {
+    assert_eq!(anychar(&b"\xe2\x82"[..]), Done(&b"\x82"[..], '\u{e2}'));
+    assert_eq!(super::anychar_utf8("€1".as_bytes()), Done(&b"1"[..], '€'));
+    assert_eq!(super::anychar_utf8(&b"\xe2\x82"[..]), Incomplete(Needed::Size(3)));
+    let invalid = &b"\xff"[..];
+    assert_eq!(super::anychar_utf8(invalid), Error(error_position!(ErrorKind::Utf8, invalid)));
+    assert_eq!(anychar_s("€1"), Done("1", '€'));
+    assert_eq!(anychar_s(""), Incomplete(Needed::Size(1)));
+  }
 }
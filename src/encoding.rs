@@ -0,0 +1,308 @@
+//! UTF-16 and Latin-1 strings
+//!
+//! Binary formats often store text as UTF-16, in little or big endian order and
+//! sometimes starting with a byte order mark, or as Latin-1 (ISO 8859-1).
+//! These parsers take a length in bytes or a null terminator, and decode the
+//! text to a `String`.
+//!
+//! Decoding UTF-16 fails with `ErrorKind::OddLength` on a length which is not a
+//! multiple of two, and with `ErrorKind::UnpairedSurrogate` at the position of a
+//! surrogate code unit which is not part of a pair.
+
+use std::char;
+
+use internal::IResult;
+use internal::IResult::*;
+use internal::Needed;
+use util::ErrorKind;
+
+#[inline]
+fn read_unit(input: &[u8], index: usize, big_endian: bool) -> u16 {
+  if big_endian {
+    ((input[index] as u16) << 8) | input[index + 1] as u16
+  } else {
+    ((input[index + 1] as u16) << 8) | input[index] as u16
+  }
+}
+
+/// decodes the whole input, which has an even length, or returns the offset of an unpaired surrogate
+fn decode_utf16(input: &[u8], big_endian: bool) -> Result<String, usize> {
+  let mut res = String::with_capacity(input.len() / 2);
+  let mut index = 0;
+
+  while index < input.len() {
+    let unit = read_unit(input, index, big_endian);
+    match unit {
+      0xD800...0xDBFF => {
+        let low = if index + 4 <= input.len() { read_unit(input, index + 2, big_endian) } else { 0 };
+        if low < 0xDC00 || low > 0xDFFF {
+          return Err(index);
+        }
+        let value = 0x10000 + (((unit as u32) - 0xD800) << 10) + ((low as u32) - 0xDC00);
+        res.push(char::from_u32(value).unwrap());
+        index += 4;
+      },
+      0xDC00...0xDFFF => return Err(index),
+      _ => {
+        res.push(char::from_u32(unit as u32).unwrap());
+        index += 2;
+      },
+    }
+  }
+
+  Ok(res)
+}
+
+/// returns the byte order given by a byte order mark, and the length of that mark
+#[inline]
+fn detect_bom(input: &[u8]) -> (bool, usize) {
+  if input.starts_with(&[0xFE, 0xFF]) {
+    (true, 2)
+  } else if input.starts_with(&[0xFF, 0xFE]) {
+    (false, 2)
+  } else {
+    // RFC 2781: text without byte order mark is big endian
+    (true, 0)
+  }
+}
+
+fn take_utf16(input: &[u8], len: usize, order: Option<bool>) -> IResult<&[u8], String> {
+  if len % 2 != 0 {
+    return Error(error_position!(ErrorKind::OddLength, input));
+  }
+  if input.len() < len {
+    return Incomplete(Needed::Size(len));
+  }
+
+  let (big_endian, start) = match order {
+    Some(big_endian) => (big_endian, 0),
+    None             => detect_bom(&input[..len]),
+  };
+
+  match decode_utf16(&input[start..len], big_endian) {
+    Ok(s)       => Done(&input[len..], s),
+    Err(offset) => Error(error_position!(ErrorKind::UnpairedSurrogate, &input[start + offset..])),
+  }
+}
+
+fn utf16_until_nul(input: &[u8], order: Option<bool>) -> IResult<&[u8], String> {
+  let (big_endian, start) = match order {
+    Some(big_endian) => (big_endian, 0),
+    None if input.len() < 2 => return Incomplete(Needed::Size(2)),
+    None             => detect_bom(input),
+  };
+
+  let mut index = start;
+  while index + 2 <= input.len() {
+    if input[index] == 0 && input[index + 1] == 0 {
+      return match decode_utf16(&input[start..index], big_endian) {
+        Ok(s)       => Done(&input[index + 2..], s),
+        Err(offset) => Error(error_position!(ErrorKind::UnpairedSurrogate, &input[start + offset..])),
+      };
+    }
+    index += 2;
+  }
+
+  Incomplete(Needed::Size(index + 2))
+}
+
+/// decodes `len` bytes of UTF-16 in little endian order
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::utf16_le;
+/// assert_eq!(utf16_le(b"h\x00\xe9\x00=\xd8\x00\xde!", 8), Done(&b"!"[..], String::from("hé\u{1f600}")));
+/// ```
+pub fn utf16_le(input: &[u8], len: usize) -> IResult<&[u8], String> {
+  take_utf16(input, len, Some(false))
+}
+
+/// decodes `len` bytes of UTF-16 in big endian order
+pub fn utf16_be(input: &[u8], len: usize) -> IResult<&[u8], String> {
+  take_utf16(input, len, Some(true))
+}
+
+/// decodes `len` bytes of UTF-16, in the order given by a byte order mark
+///
+/// The byte order mark is counted in `len` but not returned. Text without byte
+/// order mark is decoded as big endian.
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::utf16;
+/// assert_eq!(utf16(b"\xff\xfeo\x00k\x00", 6), Done(&b""[..], String::from("ok")));
+/// assert_eq!(utf16(b"\xfe\xff\x00o\x00k", 6), Done(&b""[..], String::from("ok")));
+/// assert_eq!(utf16(b"\x00o\x00k", 4), Done(&b""[..], String::from("ok")));
+/// ```
+pub fn utf16(input: &[u8], len: usize) -> IResult<&[u8], String> {
+  take_utf16(input, len, None)
+}
+
+/// decodes UTF-16 in little endian order until a null code unit, and consumes it
+///
+/// ```
+/// # use nom::IResult::{Done,Incomplete};
+/// # use nom::{utf16_le_nul,Needed};
+/// assert_eq!(utf16_le_nul(b"a\x00b\x00\x00\x00rest"), Done(&b"rest"[..], String::from("ab")));
+/// assert_eq!(utf16_le_nul(b"a\x00b\x00"), Incomplete(Needed::Size(6)));
+/// ```
+pub fn utf16_le_nul(input: &[u8]) -> IResult<&[u8], String> {
+  utf16_until_nul(input, Some(false))
+}
+
+/// decodes UTF-16 in big endian order until a null code unit, and consumes it
+pub fn utf16_be_nul(input: &[u8]) -> IResult<&[u8], String> {
+  utf16_until_nul(input, Some(true))
+}
+
+/// decodes UTF-16 in the order given by a byte order mark until a null code unit, and consumes it
+pub fn utf16_nul(input: &[u8]) -> IResult<&[u8], String> {
+  utf16_until_nul(input, None)
+}
+
+/// decodes `len` bytes of Latin-1 (ISO 8859-1)
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::latin1;
+/// assert_eq!(latin1(b"caf\xe9!", 4), Done(&b"!"[..], String::from("café")));
+/// ```
+pub fn latin1(input: &[u8], len: usize) -> IResult<&[u8], String> {
+  if input.len() < len {
+    Incomplete(Needed::Size(len))
+  } else {
+    Done(&input[len..], input[..len].iter().map(|&b| b as char).collect())
+  }
+}
+
+/// decodes Latin-1 (ISO 8859-1) until a null byte, and consumes it
+pub fn latin1_nul(input: &[u8]) -> IResult<&[u8], String> {
+  match input.iter().position(|&b| b == 0) {
+    Some(index) => Done(&input[index + 1..], input[..index].iter().map(|&b| b as char).collect()),
+    None        => Incomplete(Needed::Size(input.len() + 1)),
+  }
+}
+
+/// `take_utf16_le!(nb) => &[u8] -> IResult<&[u8], String>`
+/// decodes `nb` bytes of UTF-16 in little endian order
+#[macro_export]
+macro_rules! take_utf16_le (
+  ($i:expr, $size:expr) => (
+    $crate::utf16_le($i, $size as usize)
+  );
+);
+
+/// `take_utf16_be!(nb) => &[u8] -> IResult<&[u8], String>`
+/// decodes `nb` bytes of UTF-16 in big endian order
+#[macro_export]
+macro_rules! take_utf16_be (
+  ($i:expr, $size:expr) => (
+    $crate::utf16_be($i, $size as usize)
+  );
+);
+
+/// `take_utf16!(nb) => &[u8] -> IResult<&[u8], String>`
+/// decodes `nb` bytes of UTF-16 in the order given by a byte order mark, big endian by default
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::be_u8;
+/// # fn main() {
+///  // a length prefixed string
+///  named!(title<String>, do_parse!(len: be_u8 >> s: take_utf16!(len) >> (s)));
+///
+///  assert_eq!(title(b"\x06\xff\xfeo\x00k\x00"), Done(&b""[..], String::from("ok")));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! take_utf16 (
+  ($i:expr, $size:expr) => (
+    $crate::utf16($i, $size as usize)
+  );
+);
+
+/// `take_latin1!(nb) => &[u8] -> IResult<&[u8], String>`
+/// decodes `nb` bytes of Latin-1 (ISO 8859-1)
+#[macro_export]
+macro_rules! take_latin1 (
+  ($i:expr, $size:expr) => (
+    $crate::latin1($i, $size as usize)
+  );
+);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use internal::IResult::*;
+  use internal::Needed;
+  use util::ErrorKind;
+
+  #[test]
+  fn utf16_fixed_length() {
+    let le = b"\x3d\xd8\x00\xde-\x00";
+    assert_eq!(utf16_le(le, 6), Done(&b""[..], String::from("\u{1f600}-")));
+    assert_eq!(utf16_le(le, 4), Done(&b"-\x00"[..], String::from("\u{1f600}")));
+    assert_eq!(utf16_le(le, 8), Incomplete(Needed::Size(8)));
+    assert_eq!(utf16_le(le, 3), Error(error_position!(ErrorKind::OddLength, &le[..])));
+
+    let be = b"\xd8\x3d\xde\x00\x00-";
+    assert_eq!(utf16_be(be, 6), Done(&b""[..], String::from("\u{1f600}-")));
+    assert_eq!(utf16_be(&be[..0], 0), Done(&b""[..], String::new()));
+  }
+
+  #[test]
+  fn utf16_surrogates() {
+    // high surrogate at the end of the string
+    let truncated = b"a\x00\x3d\xd8";
+    assert_eq!(utf16_le(truncated, 4), Error(error_position!(ErrorKind::UnpairedSurrogate, &truncated[2..])));
+    // high surrogate followed by a character
+    let unpaired = b"\x3d\xd8a\x00";
+    assert_eq!(utf16_le(unpaired, 4), Error(error_position!(ErrorKind::UnpairedSurrogate, &unpaired[..])));
+    // low surrogate first
+    let low = b"\x00a\xde\x00\xd8\x3d";
+    assert_eq!(utf16_be(low, 6), Error(error_position!(ErrorKind::UnpairedSurrogate, &low[2..])));
+  }
+
+  #[test]
+  fn utf16_bom() {
+    assert_eq!(utf16(b"\xff\xfe\xe9\x00x", 4), Done(&b"x"[..], String::from("é")));
+    assert_eq!(utf16(b"\xfe\xff\x00\xe9x", 4), Done(&b"x"[..], String::from("é")));
+    assert_eq!(utf16(b"\x00\xe9x", 2), Done(&b"x"[..], String::from("é")));
+    assert_eq!(utf16(b"\xff\xfe", 2), Done(&b""[..], String::new()));
+    let odd = b"\xff\xfe\xe9";
+    assert_eq!(utf16(odd, 3), Error(error_position!(ErrorKind::OddLength, &odd[..])));
+  }
+
+  #[test]
+  fn utf16_terminated() {
+    assert_eq!(utf16_be_nul(b"\x00a\x00\x00b"), Done(&b"b"[..], String::from("a")));
+    // the terminator is aligned on code units
+    assert_eq!(utf16_le_nul(b"\x00\x01\x00\x00"), Done(&b""[..], String::from("\u{100}")));
+    assert_eq!(utf16_le_nul(b"a\x00\x00"), Incomplete(Needed::Size(4)));
+    assert_eq!(utf16_nul(b"\xff\xfea\x00\x00\x00"), Done(&b""[..], String::from("a")));
+    assert_eq!(utf16_nul(b"\xfe\xff\x00a\x00\x00"), Done(&b""[..], String::from("a")));
+    assert_eq!(utf16_nul(b"\xff"), Incomplete(Needed::Size(2)));
+
+    let unpaired = b"\x00\xdc\x00\x00";
+    assert_eq!(utf16_le_nul(unpaired), Error(error_position!(ErrorKind::UnpairedSurrogate, &unpaired[..])));
+  }
+
+  #[test]
+  fn latin1_strings() {
+    assert_eq!(latin1(b"\xa9 2016", 6), Done(&b""[..], String::from("© 2016")));
+    assert_eq!(latin1(b"ab", 3), Incomplete(Needed::Size(3)));
+    assert_eq!(latin1_nul(b"\xdcber\x00\x01"), Done(&b"\x01"[..], String::from("Über")));
+    assert_eq!(latin1_nul(b"abc"), Incomplete(Needed::Size(4)));
+  }
+
+  #[test]
+  fn macros() {
+    named!(le<String>, take_utf16_le!(2));
+    named!(be<String>, take_utf16_be!(2));
+    named!(lat<String>, take_latin1!(1));
+    assert_eq!(le(b"a\x00"), Done(&b""[..], String::from("a")));
+    assert_eq!(be(b"\x00a"), Done(&b""[..], String::from("a")));
+    assert_eq!(lat(b"\xff"), Done(&b""[..], String::from("ÿ")));
+  }
+}
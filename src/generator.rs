@@ -0,0 +1,531 @@
+//! Generators: serialization counterparts of the parsers
+//!
+//! A generator writes data at a position of an output and returns the output and the
+//! position after the written data, so generators can be chained like parsers with
+//! `do_gen!`. The output is a `[u8]`, which returns an error if it is too small,
+//! or a `Vec<u8>`, which grows as needed.
+//!
+//! The generators mirror the parsers: `gen_be_u32` writes what `be_u32` parses,
+//! `gen_tag` what `tag!` recognizes, and `gen_length_value` writes a placeholder
+//! length field before its body, then back-patches it, like `length_bytes!` parses it.
+//!
+//! ```
+//! # #[macro_use] extern crate nom;
+//! # use nom::IResult::Done;
+//! # use nom::*;
+//! # fn main() {
+//!  // a message: a magic tag, a version, then a length prefixed name
+//!  named!(message<(u8, &[u8])>, do_parse!(
+//!    tag!("MSG") >>
+//!    version: be_u8 >>
+//!    name: length_bytes!(be_u16) >>
+//!    (version, name)
+//!  ));
+//!
+//!  let mut out = Vec::new();
+//!  let res = do_gen!((&mut out, 0),
+//!    gen_tag("MSG") >>
+//!    gen_be_u8(2) >>
+//!    gen_length_value(gen_be_u16, |s| gen_tag(s, "nom"))
+//!  );
+//!
+//!  assert_eq!(res.map(|(_, pos)| pos), Ok(9));
+//!  assert_eq!(message(&out[..]), Done(&b""[..], (2, &b"nom"[..])));
+//! # }
+//! ```
+
+use util::AsBytes;
+
+/// Error returned by generators
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum GenError {
+  /// the output is too small, it needs at least this length
+  BufferTooSmall(usize),
+  /// a value or a length does not fit in its field
+  Overflow,
+}
+
+/// Outputs of generators
+pub trait GenOutput {
+  /// writes the data at a position of the output
+  fn write_at(&mut self, pos: usize, data: &[u8]) -> Result<(), GenError>;
+}
+
+impl GenOutput for [u8] {
+  #[inline]
+  fn write_at(&mut self, pos: usize, data: &[u8]) -> Result<(), GenError> {
+    let end = pos + data.len();
+    if end > self.len() {
+      Err(GenError::BufferTooSmall(end))
+    } else {
+      self[pos..end].copy_from_slice(data);
+      Ok(())
+    }
+  }
+}
+
+impl GenOutput for Vec<u8> {
+  /// overwrites the existing data and extends the vector, filling any gap with zeros
+  #[inline]
+  fn write_at(&mut self, pos: usize, data: &[u8]) -> Result<(), GenError> {
+    if pos > self.len() {
+      self.resize(pos, 0);
+    }
+    let overlap = ::std::cmp::min(self.len() - pos, data.len());
+    self[pos..pos + overlap].copy_from_slice(&data[..overlap]);
+    self.extend_from_slice(&data[overlap..]);
+    Ok(())
+  }
+}
+
+/// result of a generator: the output and the position after the written data
+pub type GenResult<'a, O> = Result<(&'a mut O, usize), GenError>;
+
+/// Types of the length fields written by `gen_length_value`
+pub trait GenLength: Sized {
+  /// converts a length, or returns `None` if it does not fit in the type
+  fn from_length(len: usize) -> Option<Self>;
+}
+
+macro_rules! gen_length_impl (
+  ($($t:ty)*) => ($(
+    impl GenLength for $t {
+      #[inline]
+      fn from_length(len: usize) -> Option<$t> {
+        if len as u64 <= <$t>::max_value() as u64 {
+          Some(len as $t)
+        } else {
+          None
+        }
+      }
+    }
+  )*);
+);
+
+gen_length_impl!(u8 u16 u32 u64 usize);
+
+/// `do_gen!(state, gen_a(args) >> gen_b!(args) >> ...) => GenResult`
+/// applies generators in sequence, stopping at the first error
+///
+/// The state is `(&mut output, position)`. Generators are functions taking the
+/// state as first argument, or macros taking it as first argument.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::{gen_be_u16,gen_le_u16,GenError};
+/// # fn main() {
+///  let mut buf = [0u8; 4];
+///  assert_eq!(do_gen!((&mut buf[..], 0), gen_be_u16(1) >> gen_le_u16(2)).map(|(_, pos)| pos), Ok(4));
+///  assert_eq!(buf, [0, 1, 2, 0]);
+///
+///  let mut small = [0u8; 3];
+///  assert_eq!(do_gen!((&mut small[..], 0), gen_be_u16(1) >> gen_le_u16(2)).map(|(_, pos)| pos), Err(GenError::BufferTooSmall(4)));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! do_gen (
+  ($s:expr, $submac:ident!( $($args:tt)* ) >> $($rest:tt)+) => (
+    match $submac!($s, $($args)*) {
+      Ok(s)  => do_gen!(s, $($rest)+),
+      Err(e) => Err(e),
+    }
+  );
+  ($s:expr, $f:ident( $($args:tt)* ) >> $($rest:tt)+) => (
+    match $f($s, $($args)*) {
+      Ok(s)  => do_gen!(s, $($rest)+),
+      Err(e) => Err(e),
+    }
+  );
+  ($s:expr, $submac:ident!( $($args:tt)* )) => (
+    $submac!($s, $($args)*)
+  );
+  ($s:expr, $f:ident( $($args:tt)* )) => (
+    $f($s, $($args)*)
+  );
+);
+
+#[inline]
+fn write<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), data: &[u8]) -> GenResult<'a, O> {
+  let (out, pos) = s;
+  match out.write_at(pos, data) {
+    Ok(())  => Ok((out, pos + data.len())),
+    Err(e)  => Err(e),
+  }
+}
+
+/// writes bytes or a string, the counterpart of `tag!`
+pub fn gen_tag<'a, O: GenOutput + ?Sized, T: AsBytes + ?Sized>(s: (&'a mut O, usize), tag: &T) -> GenResult<'a, O> {
+  write(s, tag.as_bytes())
+}
+
+/// writes `count` zero bytes
+pub fn gen_zeros<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), count: usize) -> GenResult<'a, O> {
+  let mut s = s;
+  let zeros = [0u8; 64];
+  let mut remaining = count;
+  while remaining > 0 {
+    let n = ::std::cmp::min(remaining, zeros.len());
+    s = match write(s, &zeros[..n]) {
+      Ok(s)  => s,
+      Err(e) => return Err(e),
+    };
+    remaining -= n;
+  }
+  Ok(s)
+}
+
+macro_rules! gen_int_impl (
+  ($name:ident, $t:ty, $size:expr, big) => (
+    /// writes a big endian integer, the counterpart of the parser of the same name
+    #[inline]
+    pub fn $name<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), value: $t) -> GenResult<'a, O> {
+      let mut bytes = [0u8; $size];
+      for i in 0..$size {
+        bytes[i] = ((value as u64) >> (8 * ($size - 1 - i))) as u8;
+      }
+      write(s, &bytes)
+    }
+  );
+  ($name:ident, $t:ty, $size:expr, little) => (
+    /// writes a little endian integer, the counterpart of the parser of the same name
+    #[inline]
+    pub fn $name<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), value: $t) -> GenResult<'a, O> {
+      let mut bytes = [0u8; $size];
+      for i in 0..$size {
+        bytes[i] = ((value as u64) >> (8 * i)) as u8;
+      }
+      write(s, &bytes)
+    }
+  );
+);
+
+gen_int_impl!(gen_be_u8,  u8,  1, big);
+gen_int_impl!(gen_be_u16, u16, 2, big);
+gen_int_impl!(gen_be_u32, u32, 4, big);
+gen_int_impl!(gen_be_u64, u64, 8, big);
+gen_int_impl!(gen_be_i8,  i8,  1, big);
+gen_int_impl!(gen_be_i16, i16, 2, big);
+gen_int_impl!(gen_be_i32, i32, 4, big);
+gen_int_impl!(gen_be_i64, i64, 8, big);
+gen_int_impl!(gen_le_u8,  u8,  1, little);
+gen_int_impl!(gen_le_u16, u16, 2, little);
+gen_int_impl!(gen_le_u32, u32, 4, little);
+gen_int_impl!(gen_le_u64, u64, 8, little);
+gen_int_impl!(gen_le_i8,  i8,  1, little);
+gen_int_impl!(gen_le_i16, i16, 2, little);
+gen_int_impl!(gen_le_i32, i32, 4, little);
+gen_int_impl!(gen_le_i64, i64, 8, little);
+
+/// writes a big endian 3 bytes integer, returning an `Overflow` error if it does not fit
+pub fn gen_be_u24<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), value: u32) -> GenResult<'a, O> {
+  if value >> 24 != 0 {
+    return Err(GenError::Overflow);
+  }
+  write(s, &[(value >> 16) as u8, (value >> 8) as u8, value as u8])
+}
+
+/// writes a little endian 3 bytes integer, returning an `Overflow` error if it does not fit
+pub fn gen_le_u24<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), value: u32) -> GenResult<'a, O> {
+  if value >> 24 != 0 {
+    return Err(GenError::Overflow);
+  }
+  write(s, &[value as u8, (value >> 8) as u8, (value >> 16) as u8])
+}
+
+/// writes a big endian 4 bytes floating point number
+pub fn gen_be_f32<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), value: f32) -> GenResult<'a, O> {
+  gen_be_u32(s, value.to_bits())
+}
+
+/// writes a big endian 8 bytes floating point number
+pub fn gen_be_f64<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), value: f64) -> GenResult<'a, O> {
+  gen_be_u64(s, value.to_bits())
+}
+
+/// writes a little endian 4 bytes floating point number
+pub fn gen_le_f32<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), value: f32) -> GenResult<'a, O> {
+  gen_le_u32(s, value.to_bits())
+}
+
+/// writes a little endian 8 bytes floating point number
+pub fn gen_le_f64<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), value: f64) -> GenResult<'a, O> {
+  gen_le_u64(s, value.to_bits())
+}
+
+/// applies a generator at an earlier position, and returns the current position
+///
+/// This back-patches a field once the data it depends on is written
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::{gen_at,gen_be_u8,gen_tag};
+/// # fn main() {
+///  let mut out = Vec::new();
+///  let res = do_gen!((&mut out, 0), gen_be_u8(0) >> gen_tag("abc") >> gen_at(0, |s| gen_be_u8(s, 3)));
+///  assert_eq!(res.map(|(_, pos)| pos), Ok(4));
+///  assert_eq!(&out[..], &b"\x03abc"[..]);
+/// # }
+/// ```
+pub fn gen_at<'a, O, F>(s: (&'a mut O, usize), pos: usize, generator: F) -> GenResult<'a, O>
+  where O: GenOutput + ?Sized,
+        F: FnOnce((&'a mut O, usize)) -> GenResult<'a, O> {
+  let (out, current) = s;
+  match generator((out, pos)) {
+    Ok((out, _)) => Ok((out, current)),
+    Err(e)       => Err(e),
+  }
+}
+
+/// writes a length field with `length`, then the body, and back-patches the length
+/// with the number of bytes written by the body: the counterpart of `length_bytes!`
+///
+/// The length generator is applied twice, so it must always write the same number
+/// of bytes. Returns an `Overflow` error if the length does not fit in its type.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::{gen_length_value,gen_le_u32,gen_be_u8,gen_tag};
+/// # fn main() {
+///  let mut out = Vec::new();
+///  let res = do_gen!((&mut out, 0),
+///    gen_length_value(gen_le_u32, |s| do_gen!(s, gen_tag("ab") >> gen_be_u8(0)))
+///  );
+///  assert_eq!(res.map(|(_, pos)| pos), Ok(7));
+///  assert_eq!(&out[..], &[3, 0, 0, 0, b'a', b'b', 0][..]);
+/// # }
+/// ```
+pub fn gen_length_value<'a, O, L, F, G>(s: (&'a mut O, usize), length: F, body: G) -> GenResult<'a, O>
+  where O: GenOutput + ?Sized,
+        L: GenLength,
+        F: Fn((&'a mut O, usize), L) -> GenResult<'a, O>,
+        G: FnOnce((&'a mut O, usize)) -> GenResult<'a, O> {
+  let start = s.1;
+  let placeholder = match L::from_length(0) {
+    Some(l) => l,
+    None    => return Err(GenError::Overflow),
+  };
+  let (out, body_start) = match length(s, placeholder) {
+    Ok(s)  => s,
+    Err(e) => return Err(e),
+  };
+  let (out, end) = match body((out, body_start)) {
+    Ok(s)  => s,
+    Err(e) => return Err(e),
+  };
+  let len = match L::from_length(end - body_start) {
+    Some(l) => l,
+    None    => return Err(GenError::Overflow),
+  };
+  match length((out, start), len) {
+    Ok((out, _)) => Ok((out, end)),
+    Err(e)       => Err(e),
+  }
+}
+
+/// applies a generator on each item, the counterpart of `many0!`
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::{gen_many,gen_be_u16};
+/// # fn main() {
+///  let mut out = Vec::new();
+///  let res = do_gen!((&mut out, 0), gen_many(&[1u16, 2], |s, v| gen_be_u16(s, *v)));
+///  assert_eq!(res.map(|(_, pos)| pos), Ok(4));
+///  assert_eq!(&out[..], &[0, 1, 0, 2][..]);
+/// # }
+/// ```
+pub fn gen_many<'a, O, I, F>(s: (&'a mut O, usize), items: I, mut generator: F) -> GenResult<'a, O>
+  where O: GenOutput + ?Sized,
+        I: IntoIterator,
+        F: FnMut((&'a mut O, usize), I::Item) -> GenResult<'a, O> {
+  let mut s = s;
+  for item in items {
+    s = match generator(s, item) {
+      Ok(s)  => s,
+      Err(e) => return Err(e),
+    };
+  }
+  Ok(s)
+}
+
+/// applies a generator on each item, with a separator between items, the counterpart of `separated_list!`
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::{gen_separated,gen_tag};
+/// # fn main() {
+///  let mut out = Vec::new();
+///  let res = do_gen!((&mut out, 0), gen_separated(&["a", "b", "c"], |s| gen_tag(s, ", "), |s, v| gen_tag(s, *v)));
+///  assert_eq!(res.map(|(_, pos)| pos), Ok(7));
+///  assert_eq!(&out[..], &b"a, b, c"[..]);
+/// # }
+/// ```
+pub fn gen_separated<'a, O, I, S, F>(s: (&'a mut O, usize), items: I, mut separator: S, mut generator: F) -> GenResult<'a, O>
+  where O: GenOutput + ?Sized,
+        I: IntoIterator,
+        S: FnMut((&'a mut O, usize)) -> GenResult<'a, O>,
+        F: FnMut((&'a mut O, usize), I::Item) -> GenResult<'a, O> {
+  let mut s = s;
+  for (idx, item) in items.into_iter().enumerate() {
+    if idx > 0 {
+      s = match separator(s) {
+        Ok(s)  => s,
+        Err(e) => return Err(e),
+      };
+    }
+    s = match generator(s, item) {
+      Ok(s)  => s,
+      Err(e) => return Err(e),
+    };
+  }
+  Ok(s)
+}
+
+fn pack_bits<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), fields: &[(u64, usize)], lsb_first: bool) -> GenResult<'a, O> {
+  let total: usize = fields.iter().map(|&(_, count)| count).sum();
+  let mut bytes = vec![0u8; (total + 7) / 8];
+  let mut index = 0;
+
+  for &(value, count) in fields {
+    if count > 64 || (count < 64 && value >> count != 0) {
+      return Err(GenError::Overflow);
+    }
+    for i in 0..count {
+      let (byte, bit) = (index / 8, index % 8);
+      if lsb_first {
+        // the least significant bit of the value first, like take_bits_lsb!
+        bytes[byte] |= (((value >> i) & 1) as u8) << bit;
+      } else {
+        bytes[byte] |= (((value >> (count - 1 - i)) & 1) as u8) << (7 - bit);
+      }
+      index += 1;
+    }
+  }
+
+  write(s, &bytes)
+}
+
+/// writes `(value, number of bits)` fields most significant bit first, the counterpart of `take_bits!`
+///
+/// The last byte is padded with zero bits. Returns an `Overflow` error if a value does not fit in its bits
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::gen_bits;
+/// # fn main() {
+///  let mut out = Vec::new();
+///  let res = do_gen!((&mut out, 0), gen_bits(&[(0b101, 3), (0b01111, 5), (1, 1)]));
+///  assert_eq!(res.map(|(_, pos)| pos), Ok(2));
+///  assert_eq!(&out[..], &[0b10101111, 0b10000000][..]);
+/// # }
+/// ```
+pub fn gen_bits<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), fields: &[(u64, usize)]) -> GenResult<'a, O> {
+  pack_bits(s, fields, false)
+}
+
+/// writes `(value, number of bits)` fields least significant bit first, the counterpart of `take_bits_lsb!`
+pub fn gen_bits_lsb<'a, O: GenOutput + ?Sized>(s: (&'a mut O, usize), fields: &[(u64, usize)]) -> GenResult<'a, O> {
+  pack_bits(s, fields, true)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use internal::IResult::*;
+  use nom::{be_u16, be_u32, le_u24, le_f64, be_i16};
+
+  #[test]
+  fn integers() {
+    let mut out = Vec::new();
+    let res = do_gen!((&mut out, 0),
+      gen_be_u32(0x01020304) >> gen_le_u32(0x01020304) >> gen_be_i16(-2) >> gen_le_i8(-1) >> gen_be_u64(1)
+    );
+    assert_eq!(res.map(|(_, pos)| pos), Ok(19));
+    assert_eq!(&out[..], &[1, 2, 3, 4, 4, 3, 2, 1, 0xFF, 0xFE, 0xFF, 0, 0, 0, 0, 0, 0, 0, 1][..]);
+
+    assert_eq!(be_u32(&out[..]), Done(&out[4..], 0x01020304));
+    assert_eq!(be_i16(&out[8..]), Done(&out[10..], -2));
+  }
+
+  #[test]
+  fn odd_widths_and_floats() {
+    let mut buf = [0u8; 11];
+    let res = do_gen!((&mut buf[..], 0), gen_le_u24(0x123456) >> gen_le_f64(-1.5));
+    assert_eq!(res.map(|(_, pos)| pos), Ok(11));
+    assert_eq!(le_u24(&buf[..]), Done(&buf[3..], 0x123456));
+    assert_eq!(le_f64(&buf[3..]), Done(&b""[..], -1.5));
+
+    let mut out = Vec::new();
+    assert_eq!(gen_be_u24((&mut out, 0), 0x1000000).map(|(_, pos)| pos), Err(GenError::Overflow));
+    assert_eq!(gen_be_f32((&mut out, 0), 1.0).map(|(_, pos)| pos), Ok(4));
+    assert_eq!(&out[..], &[0x3f, 0x80, 0, 0][..]);
+  }
+
+  #[test]
+  fn slices() {
+    let mut buf = [0xAAu8; 6];
+    assert_eq!(do_gen!((&mut buf[..], 1), gen_tag(&b"ab"[..]) >> gen_zeros(2)).map(|(_, pos)| pos), Ok(5));
+    assert_eq!(buf, [0xAA, b'a', b'b', 0, 0, 0xAA]);
+    assert_eq!(gen_zeros((&mut buf[..], 4), 3).map(|(_, pos)| pos), Err(GenError::BufferTooSmall(7)));
+
+    // vectors grow, and fill gaps with zeros
+    let mut out = vec![1, 2, 3];
+    assert_eq!(gen_tag((&mut out, 2), "xy").map(|(_, pos)| pos), Ok(4));
+    assert_eq!(gen_tag((&mut out, 6), "z").map(|(_, pos)| pos), Ok(7));
+    assert_eq!(&out[..], &[1, 2, b'x', b'y', 0, 0, b'z'][..]);
+  }
+
+  #[test]
+  fn length_value() {
+    named!(record<(&[u8], u32)>, pair!(length_bytes!(be_u16), be_u32));
+
+    let mut out = Vec::new();
+    let res = do_gen!((&mut out, 0),
+      gen_length_value(gen_be_u16, |s| gen_many(s, &["ab", "cd"], |s, v| gen_tag(s, *v))) >>
+      gen_be_u32(7)
+    );
+    assert_eq!(res.map(|(_, pos)| pos), Ok(10));
+    assert_eq!(record(&out[..]), Done(&b""[..], (&b"abcd"[..], 7)));
+
+    // the length does not fit in a u8
+    assert_eq!(u8::from_length(255), Some(255));
+    assert_eq!(u8::from_length(256), None);
+    let mut out = Vec::new();
+    let res = gen_length_value((&mut out, 0), gen_be_u8, |s| gen_zeros(s, 256));
+    assert_eq!(res.map(|(_, pos)| pos), Err(GenError::Overflow));
+
+    // the body does not fit in the buffer
+    let mut buf = [0u8; 3];
+    let res = gen_length_value((&mut buf[..], 0), gen_be_u16, |s| gen_tag(s, "ab"));
+    assert_eq!(res.map(|(_, pos)| pos), Err(GenError::BufferTooSmall(4)));
+  }
+
+  #[test]
+  fn separated() {
+    let mut out = Vec::new();
+    let empty: &[&str] = &[];
+    assert_eq!(gen_separated((&mut out, 0), empty, |s| gen_tag(s, ","), |s, v| gen_tag(s, *v)).map(|(_, pos)| pos), Ok(0));
+    assert_eq!(gen_separated((&mut out, 0), &["x"], |s| gen_tag(s, ","), |s, v| gen_tag(s, *v)).map(|(_, pos)| pos), Ok(1));
+
+    named!(list<Vec<&[u8]> >, separated_list!(tag!(","), tag!("ab")));
+    let mut out = Vec::new();
+    assert_eq!(gen_separated((&mut out, 0), 0..3, |s| gen_tag(s, ","), |s, _| gen_tag(s, "ab")).map(|(_, pos)| pos), Ok(8));
+    assert_eq!(list(&out[..]), Done(&b""[..], vec![&b"ab"[..], &b"ab"[..], &b"ab"[..]]));
+  }
+
+  #[test]
+  fn bits() {
+    named!(fields<(u8, u16, u8)>, bits!(tuple!(take_bits!(u8, 3), take_bits!(u16, 10), take_bits!(u8, 3))));
+    let mut out = Vec::new();
+    assert_eq!(gen_bits((&mut out, 0), &[(5, 3), (700, 10), (2, 3)]).map(|(_, pos)| pos), Ok(2));
+    assert_eq!(fields(&out[..]), Done(&b""[..], (5, 700, 2)));
+
+    named!(fields_lsb<(u8, u16)>, bits!(pair!(take_bits_lsb!(u8, 3), take_bits_lsb!(u16, 10))));
+    let mut out = Vec::new();
+    assert_eq!(gen_bits_lsb((&mut out, 0), &[(5, 3), (700, 10)]).map(|(_, pos)| pos), Ok(2));
+    assert_eq!(fields_lsb(&out[..]), Done(&b""[..], (5, 700)));
+
+    assert_eq!(gen_bits((&mut out, 0), &[(8, 3)]).map(|(_, pos)| pos), Err(GenError::Overflow));
+    assert_eq!(gen_bits((&mut out, 0), &[(::std::u64::MAX, 64)]).map(|(_, pos)| pos), Ok(8));
+  }
+}
@@ -0,0 +1,400 @@
+//! Prefix code decoding for bit streams
+//!
+//! A `PrefixCode` maps variable length codes to values, like the Huffman codes of
+//! DEFLATE or JPEG. It is built from the code lengths of a canonical Huffman code,
+//! or from explicit codes, and decodes one value from a bit stream input
+//! `(&[u8], usize)`, reading the bits most or least significant bit first.
+//!
+//! In both orders, the first bit read is the most significant bit of the code,
+//! as in DEFLATE, where the bytes are read least significant bit first.
+
+use internal::IResult;
+use internal::IResult::*;
+use internal::Needed;
+use util::ErrorKind;
+
+/// The longest code supported by `PrefixCode`
+pub const MAX_CODE_LENGTH: u8 = 32;
+
+/// number of bits indexing the decoding table, longer codes are decoded bit by bit
+const TABLE_BITS: u8 = 9;
+
+/// Error returned when building a `PrefixCode`
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum PrefixCodeError {
+  /// the code at this index is longer than `MAX_CODE_LENGTH`, or empty
+  InvalidLength(usize),
+  /// the code lengths describe more codes than there are bit patterns
+  OverSubscribed,
+  /// the codes at these indexes are equal, or one is a prefix of the other
+  Ambiguous(usize, usize),
+}
+
+/// Decoding table for a prefix code
+///
+/// Codes up to 9 bits long are decoded with one lookup in a table indexed by the next
+/// bits of the input, longer codes are then completed bit by bit.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::PrefixCode;
+/// # fn main() {
+///  // canonical code for the lengths of A, B, C and D: B is 0, A is 10, C is 110, D is 111
+///  let code = PrefixCode::from_lengths(&[2, 1, 3, 3]).unwrap();
+///
+///  let input = &[0b01011011, 0b10000000][..];
+///  assert_eq!(code.decode((input, 0)), Done((input, 1), 1));
+///  assert_eq!(code.decode((input, 1)), Done((input, 3), 0));
+///  assert_eq!(code.decode((input, 3)), Done((input, 6), 2));
+///  assert_eq!(code.decode((input, 6)), Done((&input[1..], 1), 3));
+/// # }
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct PrefixCode<T> {
+  /// for each length, the codes of that length and their values, sorted by code
+  by_length: Vec<Vec<(u32, T)>>,
+  /// for each pattern of the first `table_bits` bits, the length and value of the
+  /// code it starts with, if that code is not longer than `table_bits`
+  table: Vec<Option<(u8, T)>>,
+  table_bits: u8,
+}
+
+impl PrefixCode<usize> {
+  /// builds the canonical Huffman code for a list of code lengths, as in DEFLATE
+  ///
+  /// The values are the indexes in the list. A length of 0 means the value is not
+  /// used. The code may be incomplete, but not over-subscribed.
+  pub fn from_lengths(lengths: &[u8]) -> Result<PrefixCode<usize>, PrefixCodeError> {
+    let max = lengths.iter().cloned().max().unwrap_or(0);
+    if max > MAX_CODE_LENGTH {
+      return Err(PrefixCodeError::InvalidLength(lengths.iter().position(|&l| l == max).unwrap()));
+    }
+
+    let mut count = vec![0u64; max as usize + 1];
+    for &len in lengths.iter().filter(|&&l| l > 0) {
+      count[len as usize] += 1;
+    }
+
+    // first code of each length
+    let mut next = vec![0u64; max as usize + 1];
+    let mut code = 0u64;
+    for len in 1..(max as usize + 1) {
+      code = (code + count[len - 1]) << 1;
+      next[len] = code;
+    }
+
+    let mut by_length: Vec<Vec<(u32, usize)>> = (0..max).map(|_| Vec::new()).collect();
+    for (value, &len) in lengths.iter().enumerate().filter(|&(_, &l)| l > 0) {
+      let code = next[len as usize];
+      if code >> len != 0 {
+        return Err(PrefixCodeError::OverSubscribed);
+      }
+      next[len as usize] += 1;
+      by_length[len as usize - 1].push((code as u32, value));
+    }
+
+    Ok(PrefixCode::with_codes(by_length))
+  }
+}
+
+impl<T: Clone> PrefixCode<T> {
+  /// builds a prefix code from a list of `(code, length, value)`
+  ///
+  /// The code is in the `length` low bits of `code`. No code can be the prefix of another one.
+  ///
+  /// ```
+  /// # use nom::IResult::Done;
+  /// # use nom::{PrefixCode,PrefixCodeError};
+  /// let code = PrefixCode::from_codes(&[(0b0, 1, 'e'), (0b10, 2, 't'), (0b11, 2, 'a')]).unwrap();
+  /// assert_eq!(code.decode((&[0b10110000][..], 0)), Done((&[0b10110000][..], 2), 't'));
+  ///
+  /// assert_eq!(PrefixCode::from_codes(&[(0b0, 1, 'e'), (0b01, 2, 't')]), Err(PrefixCodeError::Ambiguous(0, 1)));
+  /// ```
+  pub fn from_codes(codes: &[(u32, u8, T)]) -> Result<PrefixCode<T>, PrefixCodeError> {
+    let mut max = 0;
+    for (idx, &(code, len, _)) in codes.iter().enumerate() {
+      if len == 0 || len > MAX_CODE_LENGTH || (len < 32 && code >> len != 0) {
+        return Err(PrefixCodeError::InvalidLength(idx));
+      }
+      if len > max {
+        max = len;
+      }
+    }
+
+    // a code is a prefix of another one if their ranges of left aligned bit patterns overlap
+    let range = |idx: usize| {
+      let (code, len, _) = codes[idx];
+      let start = (code as u64) << (32 - len);
+      (start, start + (1u64 << (32 - len)))
+    };
+    let mut sorted: Vec<usize> = (0..codes.len()).collect();
+    sorted.sort_by_key(|&idx| range(idx));
+    for w in sorted.windows(2) {
+      if range(w[0]).1 > range(w[1]).0 {
+        let (a, b) = if w[0] < w[1] { (w[0], w[1]) } else { (w[1], w[0]) };
+        return Err(PrefixCodeError::Ambiguous(a, b));
+      }
+    }
+
+    let mut by_length: Vec<Vec<(u32, T)>> = (0..max).map(|_| Vec::new()).collect();
+    for &(code, len, ref value) in codes {
+      by_length[len as usize - 1].push((code, value.clone()));
+    }
+    for codes in by_length.iter_mut() {
+      codes.sort_by_key(|&(code, _)| code);
+    }
+
+    Ok(PrefixCode::with_codes(by_length))
+  }
+
+  fn with_codes(by_length: Vec<Vec<(u32, T)>>) -> PrefixCode<T> {
+    let table_bits = ::std::cmp::min(by_length.len() as u8, TABLE_BITS);
+    let mut table = vec![None; 1 << table_bits];
+    for (idx, codes) in by_length.iter().take(table_bits as usize).enumerate() {
+      let len = idx as u8 + 1;
+      let fill = table_bits - len;
+      for &(code, ref value) in codes {
+        let start = (code as usize) << fill;
+        for entry in &mut table[start..start + (1 << fill)] {
+          *entry = Some((len, value.clone()));
+        }
+      }
+    }
+
+    PrefixCode { by_length: by_length, table: table, table_bits: table_bits }
+  }
+
+  /// length of the longest code
+  pub fn max_length(&self) -> u8 {
+    self.by_length.len() as u8
+  }
+
+  fn decode_bits<'a>(&self, start: (&'a [u8], usize), lsb_first: bool) -> IResult<(&'a [u8], usize), T> {
+    let (input, offset) = start;
+    let input = &input[::std::cmp::min(offset / 8, input.len())..];
+    let offset = offset % 8;
+
+    // peek at most `table_bits` bits, the first bit read being the most significant of the code
+    let available = (input.len() * 8).saturating_sub(offset);
+    let peeked = ::std::cmp::min(self.table_bits as usize, available);
+    let mut window = 0u32;
+    for (i, &byte) in input.iter().take(3).enumerate() {
+      window |= if lsb_first { (byte as u32) << (8 * i) } else { (byte as u32) << (24 - 8 * i) };
+    }
+    let mut code = 0u32;
+    if lsb_first {
+      window >>= offset;
+      for _ in 0..peeked {
+        code = (code << 1) | (window & 1);
+        window >>= 1;
+      }
+    } else if peeked > 0 {
+      code = (window << offset) >> (32 - peeked);
+    }
+
+    let index = (code as usize) << (self.table_bits as usize - peeked);
+    if let Some((len, ref value)) = self.table[index] {
+      if len as usize <= peeked {
+        let position = offset + len as usize;
+        return Done((&input[position / 8..], position % 8), value.clone());
+      }
+    }
+    if peeked < self.table_bits as usize {
+      return Incomplete(Needed::Size(peeked + 1));
+    }
+
+    // slow path for the codes longer than the table
+    let position = offset + peeked;
+    let (mut input, mut offset) = (&input[position / 8..], position % 8);
+    for (idx, codes) in self.by_length.iter().enumerate().skip(peeked) {
+      if input.is_empty() {
+        return Incomplete(Needed::Size(idx + 1));
+      }
+
+      let bit = if lsb_first {
+        (input[0] >> offset) & 1
+      } else {
+        (input[0] >> (7 - offset)) & 1
+      };
+      code = (code << 1) | bit as u32;
+      offset += 1;
+      if offset == 8 {
+        input = &input[1..];
+        offset = 0;
+      }
+
+      if let Ok(pos) = codes.binary_search_by_key(&code, |&(c, _)| c) {
+        return Done((input, offset), codes[pos].1.clone());
+      }
+    }
+
+    Error(error_position!(ErrorKind::PrefixCode, start))
+  }
+
+  /// decodes a value from a bit stream read most significant bit first, like `take_bits!`
+  ///
+  /// Returns a `PrefixCode` error if the bits do not match any code, and `Incomplete`
+  /// with the number of bits read so far plus one if the input ends before a code is found
+  pub fn decode<'a>(&self, input: (&'a [u8], usize)) -> IResult<(&'a [u8], usize), T> {
+    self.decode_bits(input, false)
+  }
+
+  /// decodes a value from a bit stream read least significant bit first, like `take_bits_lsb!`
+  pub fn decode_lsb<'a>(&self, input: (&'a [u8], usize)) -> IResult<(&'a [u8], usize), T> {
+    self.decode_bits(input, true)
+  }
+}
+
+/// `prefix_code!(&PrefixCode<T>) => (&[u8], usize) -> IResult<(&[u8], usize), T>`
+/// decodes a value of a prefix code, reading the bit stream most significant bit first
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::{IResult,PrefixCode};
+/// # fn main() {
+///  fn symbols<'a>(input: &'a [u8], code: &PrefixCode<usize>) -> IResult<&'a [u8], Vec<usize>> {
+///    bits!(input, count!(prefix_code!(code), 3))
+///  }
+///
+///  let code = PrefixCode::from_lengths(&[1, 2, 2]).unwrap();
+///  assert_eq!(symbols(&[0b01011000][..], &code), Done(&b""[..], vec![0, 1, 2]));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! prefix_code (
+  ($i:expr, $code:expr) => (
+    ($code).decode($i)
+  );
+);
+
+/// `prefix_code_lsb!(&PrefixCode<T>) => (&[u8], usize) -> IResult<(&[u8], usize), T>`
+/// decodes a value of a prefix code, reading the bit stream least significant bit first as in DEFLATE
+#[macro_export]
+macro_rules! prefix_code_lsb (
+  ($i:expr, $code:expr) => (
+    ($code).decode_lsb($i)
+  );
+);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use internal::IResult::*;
+  use internal::Needed;
+  use util::ErrorKind;
+
+  #[test]
+  fn canonical_codes() {
+    // RFC 1951 3.2.2: lengths (3, 3, 3, 3, 3, 2, 4, 4) for A to H
+    let code = PrefixCode::from_lengths(&[3, 3, 3, 3, 3, 2, 4, 4]).unwrap();
+    assert_eq!(code.max_length(), 4);
+    let expected = [(0b010, 3), (0b011, 3), (0b100, 3), (0b101, 3), (0b110, 3), (0b00, 2), (0b1110, 4), (0b1111, 4)];
+    for (value, &(bits, len)) in expected.iter().enumerate() {
+      let byte = [(bits << (8 - len)) as u8];
+      assert_eq!(code.decode((&byte[..], 0)), Done((&byte[..], len), value));
+    }
+  }
+
+  #[test]
+  fn canonical_errors() {
+    assert_eq!(PrefixCode::from_lengths(&[1, 1, 1]), Err(PrefixCodeError::OverSubscribed));
+    assert_eq!(PrefixCode::from_lengths(&[0, 33]), Err(PrefixCodeError::InvalidLength(1)));
+    // incomplete codes are accepted, unused bit patterns return an error
+    let code = PrefixCode::from_lengths(&[0, 2, 0, 1]).unwrap();
+    let input = &[0b01011000][..];
+    assert_eq!(code.decode((input, 0)), Done((input, 1), 3));
+    assert_eq!(code.decode((input, 1)), Done((input, 3), 1));
+    assert_eq!(code.decode((input, 3)), Error(error_position!(ErrorKind::PrefixCode, (input, 3))));
+  }
+
+  #[test]
+  fn explicit_codes() {
+    let code = PrefixCode::from_codes(&[(0b1, 1, "one"), (0b01, 2, "two"), (0b001, 3, "three")]).unwrap();
+    let input = &[0b10100100][..];
+    assert_eq!(code.decode((input, 0)), Done((input, 1), "one"));
+    assert_eq!(code.decode((input, 1)), Done((input, 3), "two"));
+    assert_eq!(code.decode((input, 3)), Done((input, 6), "three"));
+    assert_eq!(code.decode((input, 6)), Incomplete(Needed::Size(3)));
+
+    assert_eq!(PrefixCode::from_codes(&[(0b01, 2, 'a'), (0b01, 2, 'b')]), Err(PrefixCodeError::Ambiguous(0, 1)));
+    assert_eq!(PrefixCode::from_codes(&[(0b011, 3, 'a'), (0b0, 1, 'b')]), Err(PrefixCodeError::Ambiguous(0, 1)));
+    assert_eq!(PrefixCode::from_codes(&[(0b100, 2, 'a')]), Err(PrefixCodeError::InvalidLength(0)));
+    assert_eq!(PrefixCode::from_codes(&[(0, 0, 'a')]), Err(PrefixCodeError::InvalidLength(0)));
+    assert!(PrefixCode::from_codes(&[(0xFFFFFFFF, 32, 'a'), (0, 1, 'b')]).is_ok());
+  }
+
+  #[test]
+  fn lsb_first() {
+    // DEFLATE fixed literal code: 0-143 are 8 bits long, 144-255 9 bits, 256-279 7 bits, 280-287 8 bits
+    let mut lengths = vec![8u8; 144];
+    lengths.extend(vec![9u8; 112]);
+    lengths.extend(vec![7u8; 24]);
+    lengths.extend(vec![8u8; 8]);
+    let code = PrefixCode::from_lengths(&lengths).unwrap();
+
+    // 'a' (97) is 0x30 + 97 = 0b10010001 and end of block (256) is 0b0000000,
+    // packed from the least significant bit of each byte
+    let input = &[0b10001001, 0b00000000][..];
+    assert_eq!(code.decode_lsb((input, 0)), Done((&input[1..], 0), 97));
+    assert_eq!(code.decode_lsb((&input[1..], 0)), Done((&input[1..], 7), 256));
+    assert_eq!(code.decode_lsb((&input[1..], 2)), Incomplete(Needed::Size(7)));
+
+  }
+
+  // packs a code after `offset` bits, from the most or least significant bit of each byte
+  fn pack(code: u32, len: usize, offset: usize, lsb_first: bool) -> Vec<u8> {
+    let mut bytes = vec![0u8; (offset + len + 7) / 8];
+    for i in 0..len {
+      let bit = ((code >> (len - 1 - i)) & 1) as u8;
+      let position = offset + i;
+      let shift = if lsb_first { position % 8 } else { 7 - position % 8 };
+      bytes[position / 8] |= bit << shift;
+    }
+    bytes
+  }
+
+  #[test]
+  fn long_codes() {
+    // value n < 12 is n ones then a zero, value 12 is twelve ones
+    let mut lengths: Vec<u8> = (1..13).collect();
+    lengths.push(12);
+    let code = PrefixCode::from_lengths(&lengths).unwrap();
+    assert_eq!(code.max_length(), 12);
+
+    for (value, &len) in lengths.iter().enumerate() {
+      let len = len as usize;
+      let bits = if value == 12 { 0xFFF } else { ((1u32 << value) - 1) << 1 };
+      for offset in 0..8 {
+        let end = offset + len;
+        let input = pack(bits, len, offset, false);
+        assert_eq!(code.decode((&input[..], offset)), Done((&input[end / 8..], end % 8), value));
+        let input = pack(bits, len, offset, true);
+        assert_eq!(code.decode_lsb((&input[..], offset)), Done((&input[end / 8..], end % 8), value));
+      }
+    }
+
+    // input ending in the table, or after it
+    assert_eq!(code.decode((&[0xFF][..], 0)), Incomplete(Needed::Size(9)));
+    assert_eq!(code.decode((&[0xFF, 0xFF][..], 6)), Incomplete(Needed::Size(11)));
+    assert_eq!(code.decode_lsb((&[0xFF, 0xFF][..], 6)), Incomplete(Needed::Size(11)));
+
+    // unused pattern longer than the table
+    let code = PrefixCode::from_codes(&[(0b0, 1, 'a'), (0b10000000000, 11, 'b')]).unwrap();
+    let input = &[0xFF, 0xFF][..];
+    assert_eq!(code.decode((input, 0)), Error(error_position!(ErrorKind::PrefixCode, (input, 0))));
+    let input = &[0b10000000, 0b00011111][..];
+    assert_eq!(code.decode((input, 0)), Done((&input[1..], 3), 'b'));
+    assert_eq!(code.decode((input, 3)), Done((input, 4), 'a'));
+  }
+
+  #[test]
+  fn macros() {
+    let code = PrefixCode::from_lengths(&[1, 2, 2]).unwrap();
+    let input = &[0b10110000][..];
+    assert_eq!(prefix_code!((input, 0), &code), Done((input, 2), 1));
+    assert_eq!(prefix_code_lsb!((input, 0), &code), Done((input, 1), 0));
+    assert_eq!(prefix_code_lsb!((input, 4), code), Done((input, 6), 2));
+  }
+}
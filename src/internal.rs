@@ -86,6 +86,30 @@ pub enum IError<E=u32> {
   Incomplete(Needed)
 }
 
+#[cfg(feature = "verbose-errors")]
+/// Error returned by `parse_all`
+///
+/// Like `IError`, with an additional case for input left after a successful parse
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub enum ParseError<I,E=u32> {
+  Error(Err<I,E>),
+  Incomplete(Needed),
+  /// the parser succeeded, but did not consume the input starting at this offset
+  TrailingData(usize)
+}
+
+#[cfg(not(feature = "verbose-errors"))]
+/// Error returned by `parse_all`
+///
+/// Like `IError`, with an additional case for input left after a successful parse
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub enum ParseError<E=u32> {
+  Error(Err<E>),
+  Incomplete(Needed),
+  /// the parser succeeded, but did not consume the input starting at this offset
+  TrailingData(usize)
+}
+
 impl<I,O,E> IResult<I,O,E> {
   pub fn is_done(&self) -> bool {
     match *self {
@@ -303,6 +327,10 @@ macro_rules! error_node_position(
 mod tests {
   use super::*;
   use util::ErrorKind;
+  #[cfg(feature = "verbose-errors")]
+  use verbose_errors::parse_all;
+  #[cfg(not(feature = "verbose-errors"))]
+  use simple_errors::parse_all;
 
   const REST: [u8; 0] = [];
   const DONE: IResult<&'static [u8], u32> = IResult::Done(&REST, 5);
@@ -417,4 +445,16 @@ mod tests {
     assert_eq!(INCOMPLETE.to_full_result(), Err(IError::Incomplete(Needed::Unknown)));
     assert_eq!(ERROR.to_full_result(), Err(IError::Error(error_code!(ErrorKind::Tag))));
   }
+
+  #[test]
+  fn parse_all_test() {
+    fn done(i: &[u8]) -> IResult<&[u8], u32> { IResult::Done(&i[2..], 5) }
+    fn error(_: &[u8]) -> IResult<&[u8], u32> { ERROR }
+    fn incomplete(_: &[u8]) -> IResult<&[u8], u32> { INCOMPLETE }
+
+    assert_eq!(parse_all(&b"ab"[..], done), Ok(5));
+    assert_eq!(parse_all(&b"abcd"[..], done), Err(ParseError::TrailingData(2)));
+    assert_eq!(parse_all(&b"abcd"[..], error), Err(ParseError::Error(error_code!(ErrorKind::Tag))));
+    assert_eq!(parse_all(&b"abcd"[..], incomplete), Err(ParseError::Incomplete(Needed::Unknown)));
+  }
 }
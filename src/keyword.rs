@@ -0,0 +1,267 @@
+//! Matching one of a large set of literals
+//!
+//! `alt!(tag!("IN") | tag!("INSERT"))` tries each literal in turn, and returns
+//! the first one that matches, so its result depends on the order of the branches.
+//! A `KeywordTable` stores the literals in a trie, and always returns
+//! the value associated with the longest matching literal.
+
+#[cfg(feature = "core")]
+use std::prelude::v1::*;
+use std::vec::Vec;
+
+use internal::IResult;
+use internal::IResult::*;
+use internal::Needed;
+use util::{AsBytes,ErrorKind};
+
+/// A set of literals, each associated with a value, compiled to a trie
+///
+/// Building the trie allocates, so build the table once and pass it to the parsers
+/// using it, instead of building it on each call.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::KeywordTable;
+/// # fn main() {
+///  #[derive(Clone,Debug,PartialEq)]
+///  enum Kw { In, Insert, Into }
+///
+///  let table = KeywordTable::new_nocase(&[("in", Kw::In), ("insert", Kw::Insert), ("into", Kw::Into)]);
+///
+///  assert_eq!(table.parse(&b"INSERT INTO t"[..]), Done(&b" INTO t"[..], Kw::Insert));
+///  assert_eq!(table.parse_s("in (1, 2)"), Done(" (1, 2)", Kw::In));
+/// # }
+/// ```
+#[derive(Debug,Clone)]
+pub struct KeywordTable<T> {
+  nodes:  Vec<KeywordNode<T>>,
+  nocase: bool,
+}
+
+#[derive(Debug,Clone)]
+struct KeywordNode<T> {
+  /// sorted by byte, to look up the next node with a binary search
+  children: Vec<(u8, usize)>,
+  value:    Option<T>,
+}
+
+impl<T: Clone> KeywordTable<T> {
+  /// builds a case sensitive table
+  ///
+  /// if a literal appears more than once, the last value is kept
+  pub fn new<K: AsBytes>(keywords: &[(K, T)]) -> KeywordTable<T> {
+    KeywordTable::build(keywords, false)
+  }
+
+  /// builds a table ignoring ASCII case, in the literals and in the input
+  ///
+  /// Only ASCII letters are folded: other bytes, including those of non-ASCII
+  /// characters, must match exactly.
+  pub fn new_nocase<K: AsBytes>(keywords: &[(K, T)]) -> KeywordTable<T> {
+    KeywordTable::build(keywords, true)
+  }
+
+  fn build<K: AsBytes>(keywords: &[(K, T)], nocase: bool) -> KeywordTable<T> {
+    let mut table = KeywordTable {
+      nodes:  vec![KeywordNode { children: Vec::new(), value: None }],
+      nocase: nocase,
+    };
+
+    for &(ref keyword, ref value) in keywords {
+      let mut node = 0;
+      for &c in keyword.as_bytes() {
+        let c = table.fold(c);
+        node = match table.nodes[node].children.binary_search_by(|&(b, _)| b.cmp(&c)) {
+          Ok(idx)  => table.nodes[node].children[idx].1,
+          Err(idx) => {
+            let next = table.nodes.len();
+            table.nodes.push(KeywordNode { children: Vec::new(), value: None });
+            table.nodes[node].children.insert(idx, (c, next));
+            next
+          }
+        };
+      }
+      table.nodes[node].value = Some(value.clone());
+    }
+
+    table
+  }
+
+  #[inline]
+  fn fold(&self, c: u8) -> u8 {
+    if self.nocase {
+      c.to_ascii_lowercase()
+    } else {
+      c
+    }
+  }
+
+  /// returns the length of the longest literal prefix of the input, and its value
+  ///
+  /// Only the lengths accepted by `is_end` are matches. If the input ends while
+  /// a longer literal could still match, it returns `Incomplete`, as `tag!` would do.
+  fn longest_match<F: Fn(usize) -> bool>(&self, input: &[u8], is_end: F) -> Result<Option<(usize, &T)>, Needed> {
+    let mut node  = 0;
+    let mut found = self.nodes[0].value.as_ref().map(|v| (0, v));
+
+    for (idx, &c) in input.iter().enumerate() {
+      let c = self.fold(c);
+      match self.nodes[node].children.binary_search_by(|&(b, _)| b.cmp(&c)) {
+        Ok(child) => node = self.nodes[node].children[child].1,
+        Err(_)    => return Ok(found),
+      }
+
+      if let Some(ref v) = self.nodes[node].value {
+        if is_end(idx + 1) {
+          found = Some((idx + 1, v));
+        }
+      }
+    }
+
+    if self.nodes[node].children.is_empty() {
+      Ok(found)
+    } else {
+      Err(Needed::Size(input.len() + 1))
+    }
+  }
+
+  /// applies the table on a byte slice
+  pub fn parse<'a>(&self, input: &'a [u8]) -> IResult<&'a [u8], T> {
+    match self.longest_match(input, |_| true) {
+      Ok(Some((len, value))) => Done(&input[len..], value.clone()),
+      Ok(None)               => Error(error_position!(ErrorKind::Keyword, input)),
+      Err(needed)            => Incomplete(needed),
+    }
+  }
+
+  /// applies the table on a string
+  ///
+  /// A literal which is not valid UTF-8 only matches if it ends at a character boundary
+  pub fn parse_s<'a>(&self, input: &'a str) -> IResult<&'a str, T> {
+    match self.longest_match(input.as_bytes(), |len| input.is_char_boundary(len)) {
+      Ok(Some((len, value))) => Done(&input[len..], value.clone()),
+      Ok(None)               => Error(error_position!(ErrorKind::Keyword, input)),
+      Err(needed)            => Incomplete(needed),
+    }
+  }
+}
+
+/// `keyword!(&KeywordTable<T>) => &[u8] -> IResult<&[u8], T>`
+/// returns the value associated with the longest literal of the table matching the input
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{self,Done};
+/// # use nom::KeywordTable;
+/// # fn main() {
+///  fn op<'a>(input: &'a [u8], table: &KeywordTable<u8>) -> IResult<&'a [u8], u8> {
+///    keyword!(input, table)
+///  }
+///
+///  // built once, then used by each call
+///  let table = KeywordTable::new(&[("<", 1), ("<=", 2), ("<<", 3), ("<<=", 4)]);
+///  assert_eq!(op(&b"<<= 2"[..], &table), Done(&b" 2"[..], 4));
+///  assert_eq!(op(&b"<= 2"[..], &table), Done(&b" 2"[..], 2));
+///  assert_eq!(op(&b"< 2"[..], &table), Done(&b" 2"[..], 1));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! keyword (
+  ($i:expr, $table:expr) => (
+    ($table).parse($i)
+  );
+);
+
+/// `keyword_s!(&KeywordTable<T>) => &str -> IResult<&str, T>`
+/// returns the value associated with the longest literal of the table matching the input
+#[macro_export]
+macro_rules! keyword_s (
+  ($i:expr, $table:expr) => (
+    ($table).parse_s($i)
+  );
+);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use internal::Needed;
+  use internal::IResult::*;
+  use util::ErrorKind;
+
+  #[derive(Clone,Copy,Debug,PartialEq)]
+  enum Sql { In, Insert, Into, Is, Select }
+
+  fn sql() -> KeywordTable<Sql> {
+    KeywordTable::new(&[("IN", Sql::In), ("INSERT", Sql::Insert), ("INTO", Sql::Into), ("IS", Sql::Is), ("SELECT", Sql::Select)])
+  }
+
+  #[test]
+  fn longest_match() {
+    let table = sql();
+
+    assert_eq!(table.parse(&b"INSERT x"[..]), Done(&b" x"[..], Sql::Insert));
+    assert_eq!(table.parse(&b"IN x"[..]), Done(&b" x"[..], Sql::In));
+    assert_eq!(table.parse(&b"INS x"[..]), Done(&b"S x"[..], Sql::In));
+    assert_eq!(table.parse(&b"INTO x"[..]), Done(&b" x"[..], Sql::Into));
+    assert_eq!(table.parse(&b"IS x"[..]), Done(&b" x"[..], Sql::Is));
+    assert_eq!(table.parse(&b"SELECTED"[..]), Done(&b"ED"[..], Sql::Select));
+    assert_eq!(table.parse(&b"insert x"[..]), Error(error_position!(ErrorKind::Keyword, &b"insert x"[..])));
+    assert_eq!(table.parse(&b"FROM x"[..]), Error(error_position!(ErrorKind::Keyword, &b"FROM x"[..])));
+  }
+
+  #[test]
+  fn incomplete() {
+    let table = sql();
+
+    assert_eq!(table.parse(&b""[..]), Incomplete(Needed::Size(1)));
+    assert_eq!(table.parse(&b"IN"[..]), Incomplete(Needed::Size(3)));
+    assert_eq!(table.parse(&b"INSE"[..]), Incomplete(Needed::Size(5)));
+    assert_eq!(table.parse(&b"INSERT"[..]), Done(&b""[..], Sql::Insert));
+  }
+
+  #[test]
+  fn nocase() {
+    let table = KeywordTable::new_nocase(&[("In", Sql::In), ("INSERT", Sql::Insert)]);
+
+    assert_eq!(table.parse(&b"insert x"[..]), Done(&b" x"[..], Sql::Insert));
+    assert_eq!(table.parse(&b"iNsErT x"[..]), Done(&b" x"[..], Sql::Insert));
+    assert_eq!(table.parse_s("IN x"), Done(" x", Sql::In));
+
+    // only ASCII case is ignored
+    let table = KeywordTable::new_nocase(&[("été", 1)]);
+    assert_eq!(table.parse_s("ÉTÉ"), Error(error_position!(ErrorKind::Keyword, "ÉTÉ")));
+    assert_eq!(table.parse_s("éTé!"), Done("!", 1));
+  }
+
+  #[test]
+  fn str_input() {
+    let table = KeywordTable::new(&[("é", 1), ("éa", 2), ("e", 3)]);
+
+    assert_eq!(table.parse_s("éab"), Done("b", 2));
+    assert_eq!(table.parse_s("é b"), Done(" b", 1));
+    assert_eq!(table.parse_s("ê b"), Error(error_position!(ErrorKind::Keyword, "ê b")));
+
+    // byte literals ending inside a character do not match
+    let bytes = KeywordTable::new(&[(&b"\xc3"[..], 1u8), (&b"\xc3\xa9"[..], 2), (&b""[..], 0)]);
+    assert_eq!(bytes.parse_s("éa"), Done("a", 2));
+    assert_eq!(bytes.parse_s("èa"), Done("èa", 0));
+    assert_eq!(bytes.parse(&b"\xc3\xa8"[..]), Done(&b"\xa8"[..], 1));
+  }
+
+  #[test]
+  fn duplicates() {
+    let table = KeywordTable::new(&[(&b"ab"[..], 1), (&b"ab"[..], 2)]);
+    assert_eq!(table.parse(&b"ab "[..]), Done(&b" "[..], 2));
+  }
+
+  #[test]
+  fn keyword_macro() {
+    let table = sql();
+    fn is_or_in<'a>(input: &'a [u8], table: &KeywordTable<Sql>) -> IResult<&'a [u8], Vec<Sql>> {
+      separated_list!(input, tag!(" "), keyword!(table))
+    }
+
+    assert_eq!(is_or_in(&b"IS IN INTO;"[..], &table), Done(&b";"[..], vec![Sql::Is, Sql::In, Sql::Into]));
+  }
+}
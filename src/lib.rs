@@ -128,6 +128,7 @@ pub use self::bits::*;
 
 pub use self::nom::*;
 pub use self::character::*;
+pub use self::keyword::*;
 
 #[cfg(not(feature = "core"))]
 pub use self::whitespace::*;
@@ -142,6 +143,21 @@ pub use self::stream::*;
 #[cfg(not(feature = "core"))]
 pub use self::str::*;
 
+#[cfg(not(feature = "core"))]
+pub use self::literal::*;
+
+#[cfg(not(feature = "core"))]
+pub use self::unicode::*;
+
+#[cfg(not(feature = "core"))]
+pub use self::encoding::*;
+
+#[cfg(not(feature = "core"))]
+pub use self::huffman::*;
+
+#[cfg(not(feature = "core"))]
+pub use self::generator::*;
+
 #[macro_use] mod util;
 
 #[cfg(feature = "verbose-errors")] #[macro_use] mod verbose_errors;
@@ -159,6 +175,7 @@ pub use self::str::*;
 
 #[macro_use] mod nom;
 #[macro_use] mod character;
+#[macro_use] mod keyword;
 
 #[macro_use]
 #[cfg(not(feature = "core"))]
@@ -172,5 +189,23 @@ mod whitespace;
 #[cfg(feature = "stream")]
 mod stream;
 
+#[macro_use]
 #[cfg(not(feature = "core"))]
 mod str;
+
+#[cfg(not(feature = "core"))]
+#[macro_use] mod literal;
+
+#[cfg(not(feature = "core"))]
+mod unicode;
+#[cfg(not(feature = "core"))]
+mod unicode_tables;
+
+#[cfg(not(feature = "core"))]
+#[macro_use] mod encoding;
+
+#[cfg(not(feature = "core"))]
+#[macro_use] mod huffman;
+
+#[cfg(not(feature = "core"))]
+#[macro_use] mod generator;
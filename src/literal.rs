@@ -0,0 +1,395 @@
+//! Quoted string literals
+//!
+//! Parses string literals with the usual escape sequences, like JSON or Rust strings,
+//! and decodes them to a `Cow<str>` that borrows the input when there is nothing
+//! to unescape. Errors on escape sequences point at the backslash that starts them.
+
+use std::borrow::Cow;
+use std::char;
+
+use internal::IResult;
+use internal::IResult::*;
+use internal::Needed;
+use util::ErrorKind;
+
+/// Description of a string literal syntax
+///
+/// The `json` and `rust` methods return the usual configurations, and the
+/// fields can be changed to support other formats.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::StringLiteral;
+/// # use std::borrow::Cow;
+/// # fn main() {
+///  let json = StringLiteral::json();
+///  assert_eq!(json.parse("\"a\\tb\\ud83d\\ude00\": 1"), Done(": 1", Cow::Owned(String::from("a\tb\u{1f600}"))));
+///  assert_eq!(json.parse("\"abc\", 1"), Done(", 1", Cow::Borrowed("abc")));
+///
+///  let sql = StringLiteral { quotes: &['\''], escapes: &[('\'', '\''), ('\\', '\\')], ..StringLiteral::json() };
+///  assert_eq!(sql.parse("'it\\'s'"), Done("", Cow::Owned(String::from("it's"))));
+/// # }
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct StringLiteral<'a> {
+  /// characters accepted as quotes. A literal ends with the quote it started with
+  pub quotes:         &'a [char],
+  /// simple escape sequences, as the character following the backslash and the decoded character
+  pub escapes:        &'a [(char, char)],
+  /// accepts `\uXXXX` escapes, with UTF-16 surrogate pairs for characters outside of the BMP
+  pub fixed_unicode:  bool,
+  /// accepts `\u{X}` to `\u{XXXXXX}` escapes
+  pub braced_unicode: bool,
+  /// accepts `\xXX` escapes, up to `\x7f`
+  pub hex_escapes:    bool,
+  /// accepts raw strings without escapes, like `r"a\b"` or `r#"a"b"#`
+  pub raw:            bool,
+}
+
+static JSON_ESCAPES: &'static [(char, char)] = &[
+  ('"', '"'), ('\\', '\\'), ('/', '/'), ('b', '\u{8}'), ('f', '\u{c}'), ('n', '\n'), ('r', '\r'), ('t', '\t'),
+];
+
+static RUST_ESCAPES: &'static [(char, char)] = &[
+  ('"', '"'), ('\'', '\''), ('\\', '\\'), ('n', '\n'), ('r', '\r'), ('t', '\t'), ('0', '\0'),
+];
+
+/// result of decoding an escape sequence
+enum Escape {
+  /// the decoded character and the index following the sequence
+  Char(char, usize),
+  Incomplete(Needed),
+  /// the error and the index of the backslash
+  Invalid(ErrorKind, usize),
+}
+
+impl StringLiteral<'static> {
+  /// JSON strings: double quotes, `\uXXXX` escapes and `\" \\ \/ \b \f \n \r \t`
+  pub fn json() -> StringLiteral<'static> {
+    StringLiteral {
+      quotes:         &['"'],
+      escapes:        JSON_ESCAPES,
+      fixed_unicode:  true,
+      braced_unicode: false,
+      hex_escapes:    false,
+      raw:            false,
+    }
+  }
+
+  /// Rust strings: double quotes, raw strings, `\u{...}` and `\xXX` escapes and `\" \' \\ \n \r \t \0`
+  pub fn rust() -> StringLiteral<'static> {
+    StringLiteral {
+      quotes:         &['"'],
+      escapes:        RUST_ESCAPES,
+      fixed_unicode:  false,
+      braced_unicode: true,
+      hex_escapes:    true,
+      raw:            true,
+    }
+  }
+}
+
+impl<'a> StringLiteral<'a> {
+  /// parses a literal at the beginning of the input, and returns its decoded content
+  ///
+  /// The output borrows the input if the literal contains no escape sequence.
+  /// If the input ends before the closing quote, it returns `Incomplete`.
+  pub fn parse<'b>(&self, input: &'b str) -> IResult<&'b str, Cow<'b, str>> {
+    let quote = match input.chars().next() {
+      None    => return Incomplete(Needed::Size(1)),
+      Some(c) => c,
+    };
+
+    if self.raw && quote == 'r' {
+      return self.parse_raw(input);
+    }
+    if !self.quotes.contains(&quote) {
+      return Error(error_position!(ErrorKind::StringLiteral, input));
+    }
+
+    let start = quote.len_utf8();
+    let mut index = start;
+    let mut res: Cow<'b, str> = Cow::Borrowed(&input[start..start]);
+
+    loop {
+      let (pos, c) = match input[index..].char_indices().find(|&(_, c)| c == quote || c == '\\') {
+        None         => return Incomplete(Needed::Size(input.len() + 1)),
+        Some((o, c)) => (index + o, c),
+      };
+
+      match res {
+        Cow::Owned(ref mut s) => s.push_str(&input[index..pos]),
+        Cow::Borrowed(_)      => res = Cow::Borrowed(&input[start..pos]),
+      }
+
+      if c == quote {
+        return Done(&input[pos + quote.len_utf8()..], res);
+      }
+
+      match self.escape(input, pos) {
+        Escape::Char(c, next)      => {
+          res.to_mut().push(c);
+          index = next;
+        },
+        Escape::Incomplete(needed) => return Incomplete(needed),
+        Escape::Invalid(kind, pos) => return Error(error_position!(kind, &input[pos..])),
+      }
+    }
+  }
+
+  /// decodes the escape sequence starting with the backslash at `pos`
+  fn escape(&self, input: &str, pos: usize) -> Escape {
+    let c = match input[pos + 1..].chars().next() {
+      None    => return Escape::Incomplete(Needed::Size(pos + 2)),
+      Some(c) => c,
+    };
+
+    if c == 'u' && self.braced_unicode && input[pos + 2..].starts_with('{') {
+      let digits = &input.as_bytes()[pos + 3..];
+      return match digits.iter().position(|&b| b == b'}') {
+        None if digits.len() < 7 && digits.iter().all(|b| (*b as char).is_digit(16)) => {
+          Escape::Incomplete(Needed::Size(input.len() + 1))
+        },
+        Some(len) if len > 0 && len <= 6 => {
+          match hex_value(&digits[..len]).and_then(char::from_u32) {
+            Some(c) => Escape::Char(c, pos + 4 + len),
+            None    => Escape::Invalid(ErrorKind::InvalidUnicodeEscape, pos),
+          }
+        },
+        _ => Escape::Invalid(ErrorKind::InvalidUnicodeEscape, pos),
+      };
+    }
+
+    if c == 'u' && self.fixed_unicode {
+      let bytes = input.as_bytes();
+      if bytes.len() < pos + 6 {
+        return if bytes[pos + 2..].iter().all(|b| (*b as char).is_digit(16)) {
+          Escape::Incomplete(Needed::Size(pos + 6))
+        } else {
+          Escape::Invalid(ErrorKind::InvalidUnicodeEscape, pos)
+        };
+      }
+      let unit = match hex_value(&bytes[pos + 2..pos + 6]) {
+        Some(unit) => unit,
+        None       => return Escape::Invalid(ErrorKind::InvalidUnicodeEscape, pos),
+      };
+
+      return match unit {
+        0xD800...0xDBFF => {
+          // a high surrogate must be followed by an escaped low surrogate
+          let rest = &bytes[pos + 6..];
+          if rest.len() < 6 {
+            let partial = rest.iter().enumerate().all(|(i, &b)| match i {
+              0 => b == b'\\',
+              1 => b == b'u',
+              _ => (b as char).is_digit(16),
+            });
+            return if partial {
+              Escape::Incomplete(Needed::Size(pos + 12))
+            } else {
+              Escape::Invalid(ErrorKind::InvalidUnicodeEscape, pos)
+            };
+          }
+          match (rest.starts_with(b"\\u"), hex_value(&rest[2..6])) {
+            (true, Some(low @ 0xDC00...0xDFFF)) => {
+              let c = 0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00);
+              Escape::Char(char::from_u32(c).unwrap(), pos + 12)
+            },
+            _ => Escape::Invalid(ErrorKind::InvalidUnicodeEscape, pos),
+          }
+        },
+        _ => match char::from_u32(unit) {
+          Some(c) => Escape::Char(c, pos + 6),
+          None    => Escape::Invalid(ErrorKind::InvalidUnicodeEscape, pos),
+        },
+      };
+    }
+
+    if c == 'x' && self.hex_escapes {
+      let bytes = input.as_bytes();
+      if bytes.len() < pos + 4 {
+        return if bytes[pos + 2..].iter().all(|b| (*b as char).is_digit(16)) {
+          Escape::Incomplete(Needed::Size(pos + 4))
+        } else {
+          Escape::Invalid(ErrorKind::InvalidEscape, pos)
+        };
+      }
+      return match hex_value(&bytes[pos + 2..pos + 4]) {
+        Some(v) if v < 0x80 => Escape::Char(v as u8 as char, pos + 4),
+        _                   => Escape::Invalid(ErrorKind::InvalidEscape, pos),
+      };
+    }
+
+    match self.escapes.iter().find(|&&(e, _)| e == c) {
+      Some(&(_, decoded)) => Escape::Char(decoded, pos + 1 + c.len_utf8()),
+      None                => Escape::Invalid(ErrorKind::InvalidEscape, pos),
+    }
+  }
+
+  /// parses a raw string, `r` followed by any number of `#`, a quote,
+  /// and the content up to the same quote followed by the same number of `#`
+  fn parse_raw<'b>(&self, input: &'b str) -> IResult<&'b str, Cow<'b, str>> {
+    let hashes = input[1..].bytes().take_while(|&b| b == b'#').count();
+    let quote = match input[1 + hashes..].chars().next() {
+      None    => return Incomplete(Needed::Size(input.len() + 1)),
+      Some(c) => c,
+    };
+    if !self.quotes.contains(&quote) {
+      return Error(error_position!(ErrorKind::StringLiteral, input));
+    }
+
+    let start = 1 + hashes + quote.len_utf8();
+    let mut terminator = String::with_capacity(quote.len_utf8() + hashes);
+    terminator.push(quote);
+    for _ in 0..hashes {
+      terminator.push('#');
+    }
+
+    match input[start..].find(&terminator[..]) {
+      None      => Incomplete(Needed::Size(input.len() + 1)),
+      Some(len) => Done(&input[start + len + terminator.len()..], Cow::Borrowed(&input[start..start + len])),
+    }
+  }
+}
+
+/// value of a non empty list of ASCII hexadecimal digits, if it fits in a `u32`
+fn hex_value(digits: &[u8]) -> Option<u32> {
+  if digits.is_empty() || digits.len() > 8 {
+    return None;
+  }
+  digits.iter().fold(Some(0), |acc, &b| {
+    acc.and_then(|v| (b as char).to_digit(16).map(|d| (v << 4) | d))
+  })
+}
+
+/// `string_literal!(&StringLiteral) => &str -> IResult<&str, Cow<str>>`
+/// parses a quoted string literal with the syntax described by a `StringLiteral`
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::StringLiteral;
+/// # use std::borrow::Cow;
+/// # fn main() {
+///  named!(strings<&str, Vec<Cow<str> > >,
+///    separated_list!(tag_s!(", "), string_literal!(&StringLiteral::rust()))
+///  );
+///
+///  assert_eq!(
+///    strings("\"a\\u{e9}\", r#\"\"b\"\"#;"),
+///    Done(";", vec![Cow::Owned(String::from("aé")), Cow::Borrowed("\"b\"")])
+///  );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! string_literal (
+  ($i:expr, $literal:expr) => (
+    ($literal).parse($i)
+  );
+);
+
+/// parses a JSON string, see `StringLiteral::json`
+pub fn json_string(input: &str) -> IResult<&str, Cow<str>> {
+  StringLiteral::json().parse(input)
+}
+
+/// parses a Rust string, see `StringLiteral::rust`
+pub fn rust_string(input: &str) -> IResult<&str, Cow<str>> {
+  StringLiteral::rust().parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::borrow::Cow;
+  use internal::Needed;
+  use internal::IResult::*;
+  use util::ErrorKind;
+
+  fn owned(s: &str) -> Cow<str> {
+    Cow::Owned(String::from(s))
+  }
+
+  #[test]
+  fn json() {
+    assert_eq!(json_string("\"abc\" x"), Done(" x", Cow::Borrowed("abc")));
+    assert_eq!(json_string("\"\""), Done("", Cow::Borrowed("")));
+    assert_eq!(json_string("\"a\\\"b\\\\c\\/d\""), Done("", owned("a\"b\\c/d")));
+    assert_eq!(json_string("\"\\b\\f\\n\\r\\t\""), Done("", owned("\u{8}\u{c}\n\r\t")));
+    assert_eq!(json_string("\"été\\u00e9\""), Done("", owned("étéé")));
+    assert_eq!(json_string("'abc'"), Error(error_position!(ErrorKind::StringLiteral, "'abc'")));
+  }
+
+  #[test]
+  fn borrowed() {
+    let input = "\"àbc\" x";
+    match json_string(input) {
+      Done(_, Cow::Borrowed(o)) => assert_eq!(o.as_ptr(), input[1..].as_ptr()),
+      other                     => panic!("expected a borrowed output, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn surrogates() {
+    assert_eq!(json_string("\"\\ud83d\\ude00!\""), Done("", owned("\u{1f600}!")));
+    assert_eq!(json_string("\"\\uD834\\uDD1E\""), Done("", owned("\u{1d11e}")));
+    assert_eq!(json_string("\"ab\\ud83d!\""), Error(error_position!(ErrorKind::InvalidUnicodeEscape, "\\ud83d!\"")));
+    assert_eq!(json_string("\"ab\\ud83d\\u0041\""), Error(error_position!(ErrorKind::InvalidUnicodeEscape, "\\ud83d\\u0041\"")));
+    assert_eq!(json_string("\"\\ude00\""), Error(error_position!(ErrorKind::InvalidUnicodeEscape, "\\ude00\"")));
+  }
+
+  #[test]
+  fn invalid_escapes() {
+    assert_eq!(json_string("\"ab\\q\""), Error(error_position!(ErrorKind::InvalidEscape, "\\q\"")));
+    assert_eq!(json_string("\"ab\\u12g4\""), Error(error_position!(ErrorKind::InvalidUnicodeEscape, "\\u12g4\"")));
+    assert_eq!(json_string("\"\\u1\" "), Error(error_position!(ErrorKind::InvalidUnicodeEscape, "\\u1\" ")));
+    assert_eq!(rust_string("\"\\x4\""), Error(error_position!(ErrorKind::InvalidEscape, "\\x4\"")));
+    assert_eq!(json_string("\"\\x41\""), Error(error_position!(ErrorKind::InvalidEscape, "\\x41\"")));
+    assert_eq!(rust_string("\"\\x80\""), Error(error_position!(ErrorKind::InvalidEscape, "\\x80\"")));
+    assert_eq!(rust_string("\"\\u{}\""), Error(error_position!(ErrorKind::InvalidUnicodeEscape, "\\u{}\"")));
+    assert_eq!(rust_string("\"\\u{1234567}\""), Error(error_position!(ErrorKind::InvalidUnicodeEscape, "\\u{1234567}\"")));
+    assert_eq!(rust_string("\"\\u{d800}\""), Error(error_position!(ErrorKind::InvalidUnicodeEscape, "\\u{d800}\"")));
+    assert_eq!(rust_string("\"\\u{110000}\""), Error(error_position!(ErrorKind::InvalidUnicodeEscape, "\\u{110000}\"")));
+  }
+
+  #[test]
+  fn rust() {
+    assert_eq!(rust_string("\"a\\u{1F600}\\x41\\0\\'\""), Done("", owned("a\u{1f600}A\0'")));
+    assert_eq!(rust_string("\"\\u{e9}\""), Done("", owned("é")));
+    assert_eq!(rust_string("r\"a\\b\" x"), Done(" x", Cow::Borrowed("a\\b")));
+    assert_eq!(rust_string("r##\"a\"#b\"## x"), Done(" x", Cow::Borrowed("a\"#b")));
+    assert_eq!(rust_string("rb"), Error(error_position!(ErrorKind::StringLiteral, "rb")));
+  }
+
+  #[test]
+  fn quotes() {
+    let literal = StringLiteral { quotes: &['"', '\'', '«'], escapes: &[('\\', '\\')], ..StringLiteral::json() };
+    assert_eq!(literal.parse("'a\"b' x"), Done(" x", Cow::Borrowed("a\"b")));
+    assert_eq!(literal.parse("«a\\\\b«"), Done("", owned("a\\b")));
+    assert_eq!(literal.parse("\"a\\\"\""), Error(error_position!(ErrorKind::InvalidEscape, "\\\"\"")));
+  }
+
+  #[test]
+  fn incomplete() {
+    assert_eq!(json_string(""), Incomplete(Needed::Size(1)));
+    assert_eq!(json_string("\"abc"), Incomplete(Needed::Size(5)));
+    assert_eq!(json_string("\"abc\\"), Incomplete(Needed::Size(6)));
+    assert_eq!(json_string("\"a\\u00"), Incomplete(Needed::Size(8)));
+    assert_eq!(json_string("\"a\\ud83d"), Incomplete(Needed::Size(14)));
+    assert_eq!(json_string("\"a\\ud83d\\u"), Incomplete(Needed::Size(14)));
+    assert_eq!(rust_string("\"a\\u{1F6"), Incomplete(Needed::Size(9)));
+    assert_eq!(rust_string("\"a\\x4"), Incomplete(Needed::Size(6)));
+    assert_eq!(rust_string("r#\"a\"b"), Incomplete(Needed::Size(7)));
+    assert_eq!(rust_string("r##"), Incomplete(Needed::Size(4)));
+  }
+
+  #[test]
+  fn string_literal_macro() {
+    named!(pair<&str, (Cow<str>, Cow<str>)>, separated_pair!(
+      string_literal!(&StringLiteral::json()), tag_s!(":"), call!(json_string)
+    ));
+
+    assert_eq!(pair("\"k\":\"v\\n\""), Done("", (Cow::Borrowed("k"), owned("v\n"))));
+  }
+}
@@ -482,6 +482,52 @@ macro_rules! map_opt_impl (
   );
 );
 
+/// `verify!(I -> IResult<I,O>, O -> bool) => I -> IResult<I, O>`
+/// returns the result of the child parser if it satisfies a verification function
+///
+/// the verification function takes as argument a reference to the output of the
+/// parser. If it returns false, an `ErrorKind::Verify` error is returned at the
+/// position where the child parser started. This works on byte, string and bit inputs.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{Done,Error};
+/// # #[cfg(feature = "verbose-errors")]
+/// # use nom::Err::Position;
+/// # use nom::{be_u8,ErrorKind};
+/// # fn main() {
+///  named!(version<u8>, verify!(be_u8, |v: &u8| *v >= 1 && *v <= 3));
+///
+///  assert_eq!(version(&[2, 0][..]), Done(&[0][..], 2));
+///  assert_eq!(version(&[4, 0][..]), Error(error_position!(ErrorKind::Verify, &[4, 0][..])));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! verify (
+  ($i:expr, $submac:ident!( $($args:tt)* ), $submac2:ident!( $($args2:tt)* )) => (
+    {
+      match $submac!($i, $($args)*) {
+        $crate::IResult::Error(e)      => $crate::IResult::Error(e),
+        $crate::IResult::Incomplete(i) => $crate::IResult::Incomplete(i),
+        $crate::IResult::Done(i, o)    => if $submac2!(&o, $($args2)*) {
+          $crate::IResult::Done(i, o)
+        } else {
+          $crate::IResult::Error(error_position!($crate::ErrorKind::Verify, $i))
+        }
+      }
+    }
+  );
+  ($i:expr, $submac:ident!( $($args:tt)* ), $g:expr) => (
+    verify!($i, $submac!($($args)*), call!($g));
+  );
+  ($i:expr, $f:expr, $g:expr) => (
+    verify!($i, call!($f), call!($g));
+  );
+  ($i:expr, $f:expr, $submac:ident!( $($args:tt)* )) => (
+    verify!($i, call!($f), $submac!($($args)*));
+  );
+);
+
 /// `value!(T, R -> IResult<R, S> ) => R -> IResult<R, T>`
 ///
 /// or `value!(T) => R -> IResult<R, T>`
@@ -926,6 +972,48 @@ macro_rules! eof (
   );
 );
 
+/// `all_consuming!(I -> IResult<I,O>) => I -> IResult<I, O>`
+/// returns the result of the child parser only if it consumed all the input
+///
+/// If some input remains, an `ErrorKind::Eof` error is returned,
+/// positioned at the first unconsumed element
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{Done, Error};
+/// # #[cfg(feature = "verbose-errors")]
+/// # use nom::Err::Position;
+/// # use nom::ErrorKind;
+/// # fn main() {
+///  named!(whole, all_consuming!(tag!("abcd")));
+///
+///  assert_eq!(whole(&b"abcd"[..]), Done(&b""[..], &b"abcd"[..]));
+///  assert_eq!(whole(&b"abcdefgh"[..]), Error(error_position!(ErrorKind::Eof, &b"efgh"[..])));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! all_consuming (
+  ($i:expr, $submac:ident!( $($args:tt)* )) => (
+    {
+      use $crate::InputLength;
+      match $submac!($i, $($args)*) {
+        $crate::IResult::Done(i,o)     => {
+          if i.input_len() == 0 {
+            $crate::IResult::Done(i, o)
+          } else {
+            $crate::IResult::Error(error_position!($crate::ErrorKind::Eof, i))
+          }
+        },
+        $crate::IResult::Error(e)      => $crate::IResult::Error(e),
+        $crate::IResult::Incomplete(i) => $crate::IResult::Incomplete(i)
+      }
+    }
+  );
+  ($i:expr, $f:expr) => (
+    all_consuming!($i, call!($f));
+  );
+);
+
 #[cfg(test)]
 mod tests {
   use internal::{Needed,IResult};
@@ -1100,4 +1188,25 @@ mod tests {
     assert_eq!(not_aaa(&b"aa"[..]), Done(&b"aa"[..], &b""[..]));
     assert_eq!(not_aaa(&b"abcd"[..]), Done(&b"abcd"[..], &b""[..]));
   }
+
+  #[test]
+  fn verify() {
+    named!(even<&[u8], &[u8]>, verify!(take!(2), |s: &&[u8]| s[1] % 2 == 0));
+    named!(count<&[u8], usize>, verify!(map!(take!(2), |s: &[u8]| s.len()), |l: &usize| *l == 2));
+
+    assert_eq!(even(&b"a2bc"[..]), Done(&b"bc"[..], &b"a2"[..]));
+    assert_eq!(even(&b"a3bc"[..]), Error(error_position!(ErrorKind::Verify, &b"a3bc"[..])));
+    assert_eq!(even(&b"a"[..]), Incomplete(Needed::Size(2)));
+    assert_eq!(count(&b"abc"[..]), Done(&b"c"[..], 2));
+  }
+
+  #[test]
+  fn all_consuming() {
+    named!(all_abcd, all_consuming!(tag!("abcd")));
+
+    assert_eq!(all_abcd(&b"abcd"[..]), Done(&b""[..], &b"abcd"[..]));
+    assert_eq!(all_abcd(&b"abcdef"[..]), Error(error_position!(ErrorKind::Eof, &b"ef"[..])));
+    assert_eq!(all_abcd(&b"ab"[..]), Incomplete(Needed::Size(4)));
+    assert_eq!(all_abcd(&b"xxxx"[..]), Error(error_position!(ErrorKind::Tag, &b"xxxx"[..])));
+  }
 }
@@ -578,6 +578,122 @@ macro_rules! length_value(
   );
 );
 
+/// `length_value_exact!(I -> IResult<I, nb>, I -> IResult<I,O>) => I -> IResult<I, O>`
+/// gets a length from the first parser, then applies the second parser on
+/// exactly that many bytes of the remaining input
+///
+/// The child parser must consume the whole window. If it needs more data than
+/// the window holds, an `ErrorKind::LengthValueShort` error is returned, and if it
+/// leaves bytes unconsumed, an `ErrorKind::LengthValueLeftover` error is returned,
+/// positioned at the leftover bytes. If the input is shorter than the window,
+/// `Incomplete` is returned.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{Done, Error};
+/// # #[cfg(feature = "verbose-errors")]
+/// # use nom::Err::Position;
+/// # use nom::{be_u8,be_u16,ErrorKind};
+/// # fn main() {
+///  named!(value<&[u8], u16>, length_value_exact!(be_u8, be_u16));
+///
+///  assert_eq!(value(&[2, 1, 2, 3][..]), Done(&[3][..], 258));
+///  assert_eq!(value(&[1, 1, 2, 3][..]), Error(error_position!(ErrorKind::LengthValueShort, &[1][..])));
+///  assert_eq!(value(&[3, 1, 2, 3][..]), Error(error_position!(ErrorKind::LengthValueLeftover, &[3][..])));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! length_value_exact(
+  ($i:expr, $submac:ident!( $($args:tt)* ), $submac2:ident!( $($args2:tt)* )) => (
+    {
+      match $submac!($i, $($args)*) {
+        $crate::IResult::Error(e)         => $crate::IResult::Error(e),
+        $crate::IResult::Incomplete(x)    => $crate::IResult::Incomplete(x),
+        $crate::IResult::Done(inum, onum) => {
+          let length_token = $i.len() - inum.len();
+          match sized_value!(inum, onum, $submac2!($($args2)*)) {
+            $crate::IResult::Incomplete($crate::Needed::Size(n)) => {
+              $crate::IResult::Incomplete($crate::Needed::Size(length_token + n))
+            },
+            res => res
+          }
+        }
+      }
+    }
+  );
+  ($i:expr, $submac:ident!( $($args:tt)* ), $g:expr) => (
+    length_value_exact!($i, $submac!($($args)*), call!($g));
+  );
+  ($i:expr, $f:expr, $submac:ident!( $($args:tt)* )) => (
+    length_value_exact!($i, call!($f), $submac!($($args)*));
+  );
+  ($i:expr, $f:expr, $g:expr) => (
+    length_value_exact!($i, call!($f), call!($g));
+  );
+);
+
+/// `sized_value!(nb, I -> IResult<I,O>) => I -> IResult<I, O>`
+/// applies the child parser on exactly `nb` bytes of the input
+///
+/// This is the building block of `length_value_exact!`, for cases where the
+/// length was parsed earlier or must be adjusted, like a box header whose size
+/// field includes the header itself. It returns the same errors, and on `&str`
+/// input, a `LengthValueShort` error if `nb` bytes end inside a character.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::be_u32;
+/// # fn main() {
+///  named!(boxed<&[u8], &[u8]>,
+///    chain!(
+///      size: be_u32                        ~
+///            tag!("free")                  ~
+///      len:  expr_opt!(size.checked_sub(8)) ~
+///      data: sized_value!(len, take!(len)) ,
+///      || { data }
+///    )
+///  );
+///
+///  let a = b"\x00\x00\x00\x0Afree\x01\x02rest";
+///  assert_eq!(boxed(&a[..]), Done(&b"rest"[..], &b"\x01\x02"[..]));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! sized_value(
+  ($i:expr, $nb:expr, $submac:ident!( $($args:tt)* )) => (
+    {
+      use $crate::{InputBoundary,InputLength};
+      let input = $i;
+      let nb    = $nb as usize;
+
+      if input.input_len() < nb {
+        $crate::IResult::Incomplete($crate::Needed::Size(nb))
+      } else if !input.is_boundary(nb) {
+        $crate::IResult::Error(error_position!($crate::ErrorKind::LengthValueShort, input))
+      } else {
+        let window = &input[..nb];
+        match $submac!(window, $($args)*) {
+          $crate::IResult::Error(e)      => $crate::IResult::Error(e),
+          $crate::IResult::Incomplete(_) => {
+            $crate::IResult::Error(error_position!($crate::ErrorKind::LengthValueShort, window))
+          },
+          $crate::IResult::Done(i, o)    => {
+            if i.input_len() == 0 {
+              $crate::IResult::Done(&input[nb..], o)
+            } else {
+              $crate::IResult::Error(error_position!($crate::ErrorKind::LengthValueLeftover, i))
+            }
+          }
+        }
+      }
+    }
+  );
+  ($i:expr, $nb:expr, $f:expr) => (
+    sized_value!($i, $nb, call!($f));
+  );
+);
+
 /// `fold_many0!(I -> IResult<I,O>, R, Fn(R, O) -> R) => I -> IResult<I, R>`
 /// Applies the parser 0 or more times and folds the list of return values
 ///
@@ -1142,6 +1258,50 @@ mod tests {
     assert_eq!(length_value_2(&i5), IResult::Incomplete(Needed::Size(7)));
   }
 
+  #[test]
+  fn length_value_exact_test() {
+    named!(length_value_1<&[u8], u16>, length_value_exact!(be_u8, be_u16));
+    named!(length_value_2<&[u8], Vec<u16> >, length_value_exact!(be_u8, many0!(be_u16)));
+
+    let i1 = vec![2, 5, 6, 3];
+    assert_eq!(length_value_1(&i1), IResult::Done(&i1[3..], 1286));
+    assert_eq!(length_value_2(&i1), IResult::Done(&i1[3..], vec![1286]));
+
+    let i2 = vec![4, 5, 6, 3, 4, 5];
+    assert_eq!(length_value_1(&i2), IResult::Error(error_position!(ErrorKind::LengthValueLeftover, &i2[3..5])));
+    assert_eq!(length_value_2(&i2), IResult::Done(&i2[5..], vec![1286, 772]));
+
+    let i3 = vec![1, 5, 6];
+    assert_eq!(length_value_1(&i3), IResult::Error(error_position!(ErrorKind::LengthValueShort, &i3[1..2])));
+    assert_eq!(length_value_2(&i3), IResult::Error(error_position!(ErrorKind::LengthValueShort, &i3[1..2])));
+
+    let i4 = vec![4, 5, 6];
+    assert_eq!(length_value_1(&i4), IResult::Incomplete(Needed::Size(5)));
+    assert_eq!(length_value_2(&i4), IResult::Incomplete(Needed::Size(5)));
+
+    let i5: Vec<u8> = vec![];
+    assert_eq!(length_value_1(&i5), IResult::Incomplete(Needed::Size(1)));
+  }
+
+  #[test]
+  fn sized_value_test() {
+    named!(tagged<&[u8], &[u8]>, sized_value!(4, tag!("abcd")));
+    named!(tagged_short<&[u8], &[u8]>, sized_value!(2, tag!("abcd")));
+    named!(tagged_long<&[u8], &[u8]>, sized_value!(6, tag!("abcd")));
+
+    assert_eq!(tagged(&b"abcdef"[..]), Done(&b"ef"[..], &b"abcd"[..]));
+    assert_eq!(tagged(&b"abc"[..]), Incomplete(Needed::Size(4)));
+    assert_eq!(tagged(&b"efghij"[..]), Error(error_position!(ErrorKind::Tag, &b"efgh"[..])));
+    assert_eq!(tagged_short(&b"abcdef"[..]), Error(error_position!(ErrorKind::LengthValueShort, &b"ab"[..])));
+    assert_eq!(tagged_long(&b"abcdef"[..]), Error(error_position!(ErrorKind::LengthValueLeftover, &b"ef"[..])));
+
+    // on strings, the length must end on a character boundary
+    named!(word<&str, &str>, sized_value!(5, ::rest_s));
+    named!(cut<&str, &str>, sized_value!(1, ::rest_s));
+    assert_eq!(word("étéé"), Done("é", "été"));
+    assert_eq!(cut("été"), Error(error_position!(ErrorKind::LengthValueShort, "été")));
+  }
+
   #[test]
   fn fold_many0() {
     fn fold_into_vec<T>(mut acc: Vec<T>, item: T) -> Vec<T> {
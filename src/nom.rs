@@ -15,6 +15,7 @@ use internal::*;
 use internal::IResult::*;
 use util::{AsChar,ErrorKind,InputLength,IterIndices};
 use std::mem::transmute;
+use std::str;
 
 #[inline]
 pub fn tag_cl<'a,'b>(rec:&'a[u8]) ->  Box<Fn(&'b[u8]) -> IResult<&'b[u8], &'b[u8]> + 'a> {
@@ -322,6 +323,39 @@ pub fn be_u64(i: &[u8]) -> IResult<&[u8], u64> {
   }
 }
 
+/// Recognizes big endian unsigned 3 bytes integer
+#[inline]
+pub fn be_u24(i: &[u8]) -> IResult<&[u8], u32> {
+  if i.len() < 3 {
+    Incomplete(Needed::Size(3))
+  } else {
+    let res = ((i[0] as u32) << 16) + ((i[1] as u32) << 8) + i[2] as u32;
+    Done(&i[3..], res)
+  }
+}
+
+/// Recognizes big endian unsigned 6 bytes integer
+#[inline]
+pub fn be_u48(i: &[u8]) -> IResult<&[u8], u64> {
+  if i.len() < 6 {
+    Incomplete(Needed::Size(6))
+  } else {
+    let res = i[..6].iter().fold(0, |acc, &b| (acc << 8) + b as u64);
+    Done(&i[6..], res)
+  }
+}
+
+/// Recognizes big endian unsigned 16 bytes integer
+#[inline]
+pub fn be_u128(i: &[u8]) -> IResult<&[u8], u128> {
+  if i.len() < 16 {
+    Incomplete(Needed::Size(16))
+  } else {
+    let res = i[..16].iter().fold(0, |acc, &b| (acc << 8) + b as u128);
+    Done(&i[16..], res)
+  }
+}
+
 /// Recognizes a signed 1 byte integer (equivalent to take!(1)
 #[inline]
 pub fn be_i8(i:&[u8]) -> IResult<&[u8], i8> {
@@ -346,6 +380,24 @@ pub fn be_i64(i:&[u8]) -> IResult<&[u8], i64> {
   map!(i, be_u64, | x | { x as i64 })
 }
 
+/// Recognizes big endian signed 3 bytes integer
+#[inline]
+pub fn be_i24(i:&[u8]) -> IResult<&[u8], i32> {
+  map!(i, be_u24, | x | { ((x << 8) as i32) >> 8 })
+}
+
+/// Recognizes big endian signed 6 bytes integer
+#[inline]
+pub fn be_i48(i:&[u8]) -> IResult<&[u8], i64> {
+  map!(i, be_u48, | x | { ((x << 16) as i64) >> 16 })
+}
+
+/// Recognizes big endian signed 16 bytes integer
+#[inline]
+pub fn be_i128(i:&[u8]) -> IResult<&[u8], i128> {
+  map!(i, be_u128, | x | { x as i128 })
+}
+
 /// Recognizes an unsigned 1 byte integer (equivalent to take!(1)
 #[inline]
 pub fn le_u8(i: &[u8]) -> IResult<&[u8], u8> {
@@ -390,6 +442,39 @@ pub fn le_u64(i: &[u8]) -> IResult<&[u8], u64> {
   }
 }
 
+/// Recognizes little endian unsigned 3 bytes integer
+#[inline]
+pub fn le_u24(i: &[u8]) -> IResult<&[u8], u32> {
+  if i.len() < 3 {
+    Incomplete(Needed::Size(3))
+  } else {
+    let res = ((i[2] as u32) << 16) + ((i[1] as u32) << 8) + i[0] as u32;
+    Done(&i[3..], res)
+  }
+}
+
+/// Recognizes little endian unsigned 6 bytes integer
+#[inline]
+pub fn le_u48(i: &[u8]) -> IResult<&[u8], u64> {
+  if i.len() < 6 {
+    Incomplete(Needed::Size(6))
+  } else {
+    let res = i[..6].iter().rev().fold(0, |acc, &b| (acc << 8) + b as u64);
+    Done(&i[6..], res)
+  }
+}
+
+/// Recognizes little endian unsigned 16 bytes integer
+#[inline]
+pub fn le_u128(i: &[u8]) -> IResult<&[u8], u128> {
+  if i.len() < 16 {
+    Incomplete(Needed::Size(16))
+  } else {
+    let res = i[..16].iter().rev().fold(0, |acc, &b| (acc << 8) + b as u128);
+    Done(&i[16..], res)
+  }
+}
+
 /// Recognizes a signed 1 byte integer (equivalent to take!(1)
 #[inline]
 pub fn le_i8(i:&[u8]) -> IResult<&[u8], i8> {
@@ -414,31 +499,219 @@ pub fn le_i64(i:&[u8]) -> IResult<&[u8], i64> {
   map!(i, le_u64, | x | { x as i64 })
 }
 
-/// if parameter is true, parse a big endian u16 integer,
-/// otherwise a little endian u16 integer
+/// Recognizes little endian signed 3 bytes integer
+#[inline]
+pub fn le_i24(i:&[u8]) -> IResult<&[u8], i32> {
+  map!(i, le_u24, | x | { ((x << 8) as i32) >> 8 })
+}
+
+/// Recognizes little endian signed 6 bytes integer
+#[inline]
+pub fn le_i48(i:&[u8]) -> IResult<&[u8], i64> {
+  map!(i, le_u48, | x | { ((x << 16) as i64) >> 16 })
+}
+
+/// Recognizes little endian signed 16 bytes integer
+#[inline]
+pub fn le_i128(i:&[u8]) -> IResult<&[u8], i128> {
+  map!(i, le_u128, | x | { x as i128 })
+}
+
+/// Byte order of binary numbers
+///
+/// The `u16!`, `i32!`, `f64!`... combinators take an `Endianness` or a `bool`
+/// (true for big endian), so a grammar can pass the byte order of a file to its
+/// sub parsers. The `endianness!` combinator detects it from a magic value.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::{Endianness,IResult};
+/// # fn main() {
+///  // a TIFF header starts with "II" or "MM", then 42 and the offset of the first directory
+///  fn offset(input: &[u8], endian: Endianness) -> IResult<&[u8], u32> {
+///    do_parse!(input, tag!(&[42, 0][..]) >> o: u32!(endian) >> (o))
+///  }
+///  fn reversed_offset(input: &[u8], endian: Endianness) -> IResult<&[u8], u32> {
+///    do_parse!(input, tag!(&[0, 42][..]) >> o: u32!(endian) >> (o))
+///  }
+///
+///  named!(header<(Endianness, u32)>, do_parse!(
+///    endian: endianness!(&b"MM"[..], &b"II"[..]) >>
+///    offset: alt!(call!(offset, endian) | call!(reversed_offset, endian)) >>
+///    (endian, offset)
+///  ));
+///
+///  assert_eq!(header(b"II\x2a\x00\x08\x00\x00\x00"), Done(&b""[..], (Endianness::Little, 8)));
+///  assert_eq!(header(b"MM\x00\x2a\x00\x00\x00\x08"), Done(&b""[..], (Endianness::Big, 8)));
+/// # }
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum Endianness {
+  /// most significant byte first
+  Big,
+  /// least significant byte first
+  Little,
+  /// byte order of the target platform
+  Native,
+}
+
+impl Endianness {
+  /// true if numbers are read most significant byte first, resolving `Native` for the target platform
+  #[inline]
+  pub fn is_big(self) -> bool {
+    match self {
+      Endianness::Big    => true,
+      Endianness::Little => false,
+      Endianness::Native => cfg!(target_endian = "big"),
+    }
+  }
+}
+
+impl From<bool> for Endianness {
+  /// `true` is big endian, `false` little endian, as the `u16!`... combinators used to take
+  #[inline]
+  fn from(big: bool) -> Endianness {
+    if big { Endianness::Big } else { Endianness::Little }
+  }
+}
+
+/// `endianness!(magic) => &[u8] -> IResult<&[u8], Endianness>`
+/// `endianness!(big_magic, little_magic) => &[u8] -> IResult<&[u8], Endianness>`
+/// consumes a magic value and returns the byte order it was written in
+///
+/// With one argument, the magic value is given in big endian order, and the little
+/// endian one is its reverse. Returns an `Endianness` error if the input starts with neither.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # use nom::{Endianness,ErrorKind};
+/// # fn main() {
+///  // pcap files start with 0xa1b2c3d4 in the byte order of the file
+///  named!(pcap_magic<Endianness>, endianness!(&[0xa1, 0xb2, 0xc3, 0xd4][..]));
+///
+///  assert_eq!(pcap_magic(&[0xd4, 0xc3, 0xb2, 0xa1]), Done(&b""[..], Endianness::Little));
+///  assert_eq!(pcap_magic(&[0xa1, 0xb2, 0xc3, 0xd4]), Done(&b""[..], Endianness::Big));
+///  assert_eq!(pcap_magic(&[0, 0, 0, 0]), nom::IResult::Error(error_position!(ErrorKind::Endianness, &[0, 0, 0, 0][..])));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! endianness (
+  ($i:expr, $magic:expr) => (
+    {
+      let magic: &[u8] = $magic;
+      $crate::detect_endianness($i, magic, None)
+    }
+  );
+  ($i:expr, $big:expr, $little:expr) => (
+    {
+      let big: &[u8] = $big;
+      let little: &[u8] = $little;
+      $crate::detect_endianness($i, big, Some(little))
+    }
+  );
+);
+
+/// compares the beginning of the input with the big and little endian magic values,
+/// the little endian one being the reverse of the big endian one if it is `None`
+#[doc(hidden)]
+pub fn detect_endianness<'a>(input: &'a [u8], big: &[u8], little: Option<&[u8]>) -> IResult<&'a [u8], Endianness> {
+  // returns whether the input starts with the magic value, or is a prefix of it
+  fn compare(input: &[u8], magic: &[u8], reversed: bool) -> (bool, bool) {
+    let n = if input.len() < magic.len() { input.len() } else { magic.len() };
+    let same = (0..n).all(|k| input[k] == if reversed { magic[magic.len() - 1 - k] } else { magic[k] });
+    (same && n == magic.len(), same && n < magic.len())
+  }
+
+  let (little, reversed) = match little {
+    Some(l) => (l, false),
+    None    => (big, true),
+  };
+  let (big_match, big_prefix) = compare(input, big, false);
+  let (little_match, little_prefix) = compare(input, little, reversed);
+
+  if big_match {
+    Done(&input[big.len()..], Endianness::Big)
+  } else if little_match {
+    Done(&input[little.len()..], Endianness::Little)
+  } else if big_prefix {
+    Incomplete(Needed::Size(big.len()))
+  } else if little_prefix {
+    Incomplete(Needed::Size(little.len()))
+  } else {
+    Error(error_position!(ErrorKind::Endianness, input))
+  }
+}
+
+/// parses a u16 integer in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
+#[macro_export]
+macro_rules! u16 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_u16($i) } else { $crate::le_u16($i) } } ););
+/// parses a u24 integer (3 bytes) in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
 #[macro_export]
-macro_rules! u16 ( ($i:expr, $e:expr) => ( {if $e { $crate::be_u16($i) } else { $crate::le_u16($i) } } ););
-/// if parameter is true, parse a big endian u32 integer,
-/// otherwise a little endian u32 integer
+macro_rules! u24 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_u24($i) } else { $crate::le_u24($i) } } ););
+/// parses a u32 integer in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
 #[macro_export]
-macro_rules! u32 ( ($i:expr, $e:expr) => ( {if $e { $crate::be_u32($i) } else { $crate::le_u32($i) } } ););
-/// if parameter is true, parse a big endian u64 integer,
-/// otherwise a little endian u64 integer
+macro_rules! u32 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_u32($i) } else { $crate::le_u32($i) } } ););
+/// parses a u48 integer (6 bytes) in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
 #[macro_export]
-macro_rules! u64 ( ($i:expr, $e:expr) => ( {if $e { $crate::be_u64($i) } else { $crate::le_u64($i) } } ););
+macro_rules! u48 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_u48($i) } else { $crate::le_u48($i) } } ););
+/// parses a u64 integer in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
+#[macro_export]
+macro_rules! u64 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_u64($i) } else { $crate::le_u64($i) } } ););
+/// parses a u128 integer in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
+#[macro_export]
+macro_rules! u128 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_u128($i) } else { $crate::le_u128($i) } } ););
 
-/// if parameter is true, parse a big endian i16 integer,
-/// otherwise a little endian i16 integer
+/// parses a i16 integer in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
+#[macro_export]
+macro_rules! i16 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_i16($i) } else { $crate::le_i16($i) } } ););
+/// parses a i24 integer (3 bytes) in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
 #[macro_export]
-macro_rules! i16 ( ($i:expr, $e:expr) => ( {if $e { $crate::be_i16($i) } else { $crate::le_i16($i) } } ););
-/// if parameter is true, parse a big endian i32 integer,
-/// otherwise a little endian i32 integer
+macro_rules! i24 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_i24($i) } else { $crate::le_i24($i) } } ););
+/// parses a i32 integer in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
 #[macro_export]
-macro_rules! i32 ( ($i:expr, $e:expr) => ( {if $e { $crate::be_i32($i) } else { $crate::le_i32($i) } } ););
-/// if parameter is true, parse a big endian i64 integer,
-/// otherwise a little endian i64 integer
+macro_rules! i32 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_i32($i) } else { $crate::le_i32($i) } } ););
+/// parses a i48 integer (6 bytes) in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
 #[macro_export]
-macro_rules! i64 ( ($i:expr, $e:expr) => ( {if $e { $crate::be_i64($i) } else { $crate::le_i64($i) } } ););
+macro_rules! i48 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_i48($i) } else { $crate::le_i48($i) } } ););
+/// parses a i64 integer in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
+#[macro_export]
+macro_rules! i64 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_i64($i) } else { $crate::le_i64($i) } } ););
+/// parses a i128 integer in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
+#[macro_export]
+macro_rules! i128 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_i128($i) } else { $crate::le_i128($i) } } ););
+
+/// parses a f32 floating point number in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
+#[macro_export]
+macro_rules! f32 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_f32($i) } else { $crate::le_f32($i) } } ););
+/// parses a f64 floating point number in the byte order given by an `Endianness`,
+/// or by a bool: true for big endian, false for little endian
+#[macro_export]
+macro_rules! f64 ( ($i:expr, $e:expr) => ( {if $crate::Endianness::from($e).is_big() { $crate::be_f64($i) } else { $crate::le_f64($i) } } ););
+
+/// `uint!(T, n, endianness) => &[u8] -> IResult<&[u8], T>`
+/// parses an unsigned integer of `n` bytes in the byte order given by an `Endianness` or a bool,
+/// see the `be_uint` and `le_uint` functions
+#[macro_export]
+macro_rules! uint (
+  ($i:expr, $t:ty, $n:expr, $e:expr) => (
+    {if $crate::Endianness::from($e).is_big() { $crate::be_uint::<$t>($i, $n as usize) } else { $crate::le_uint::<$t>($i, $n as usize) } }
+  );
+);
 
 /// Recognizes big endian 4 bytes floating point number
 #[inline]
@@ -496,33 +769,807 @@ pub fn le_f64(input: &[u8]) -> IResult<&[u8], f64> {
   }
 }
 
-/// Recognizes a hex-encoded integer
+/// decodes an unsigned LEB128 integer of at most `bits` bits
+fn uleb128(input: &[u8], bits: u32) -> IResult<&[u8], u64> {
+  let mut value: u64 = 0;
+  let mut shift: u32 = 0;
+
+  for (idx, &byte) in input.iter().enumerate() {
+    let low = (byte & 0x7F) as u64;
+    if shift >= bits || (shift + 7 > bits && low >> (bits - shift) != 0) {
+      return Error(error_position!(ErrorKind::Overflow, input));
+    }
+    value |= low << shift;
+
+    if byte & 0x80 == 0 {
+      return Done(&input[idx + 1..], value);
+    }
+    shift += 7;
+  }
+
+  Incomplete(Needed::Size(input.len() + 1))
+}
+
+/// decodes a signed LEB128 integer of at most `bits` bits
+fn sleb128(input: &[u8], bits: u32) -> IResult<&[u8], i64> {
+  let mut value: i64 = 0;
+  let mut shift: u32 = 0;
+
+  for (idx, &byte) in input.iter().enumerate() {
+    let low = (byte & 0x7F) as i64;
+    if shift >= bits {
+      return Error(error_position!(ErrorKind::Overflow, input));
+    }
+    if shift + 7 > bits {
+      // the bits above the sign bit must be copies of it
+      let sign_bits = low >> (bits - shift - 1);
+      if sign_bits != 0 && sign_bits != (1 << (8 + shift - bits)) - 1 {
+        return Error(error_position!(ErrorKind::Overflow, input));
+      }
+    }
+    value |= low << shift;
+    shift += 7;
+
+    if byte & 0x80 == 0 {
+      if shift < 64 && byte & 0x40 != 0 {
+        value |= -1i64 << shift;
+      }
+      return Done(&input[idx + 1..], value);
+    }
+  }
+
+  Incomplete(Needed::Size(input.len() + 1))
+}
+
+/// decodes a big endian variable length quantity of at most `bits` bits
+///
+/// with `offset`, each continuation adds one to the value, as in Git pack files
+fn vlq(input: &[u8], bits: u32, offset: bool) -> IResult<&[u8], u64> {
+  let mut value: u64 = 0;
+
+  for (idx, &byte) in input.iter().enumerate() {
+    if idx > 0 {
+      if offset {
+        value += 1;
+      }
+      if value >> (bits - 7) != 0 {
+        return Error(error_position!(ErrorKind::Overflow, input));
+      }
+      value <<= 7;
+    }
+    value |= (byte & 0x7F) as u64;
+
+    if byte & 0x80 == 0 {
+      return Done(&input[idx + 1..], value);
+    }
+  }
+
+  Incomplete(Needed::Size(input.len() + 1))
+}
+
+/// Recognizes an unsigned LEB128 integer, returning an `Overflow` error if it does not fit in a u32
+///
+/// ```
+/// # use nom::IResult::{Done,Incomplete};
+/// # use nom::{uleb128_u32,Needed};
+/// assert_eq!(uleb128_u32(&[0xE5, 0x8E, 0x26, 0x01]), Done(&[0x01][..], 624485));
+/// assert_eq!(uleb128_u32(&[0xE5, 0x8E]), Incomplete(Needed::Size(3)));
+/// ```
+#[inline]
+pub fn uleb128_u32(input: &[u8]) -> IResult<&[u8], u32> {
+  uleb128(input, 32).map(|v| v as u32)
+}
+
+/// Recognizes an unsigned LEB128 integer, returning an `Overflow` error if it does not fit in a u64
+#[inline]
+pub fn uleb128_u64(input: &[u8]) -> IResult<&[u8], u64> {
+  uleb128(input, 64)
+}
+
+/// Recognizes a signed LEB128 integer, returning an `Overflow` error if it does not fit in an i32
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::sleb128_i32;
+/// assert_eq!(sleb128_i32(&[0xC0, 0xBB, 0x78]), Done(&b""[..], -123456));
+/// ```
+#[inline]
+pub fn sleb128_i32(input: &[u8]) -> IResult<&[u8], i32> {
+  sleb128(input, 32).map(|v| v as i32)
+}
+
+/// Recognizes a signed LEB128 integer, returning an `Overflow` error if it does not fit in an i64
+#[inline]
+pub fn sleb128_i64(input: &[u8]) -> IResult<&[u8], i64> {
+  sleb128(input, 64)
+}
+
+/// Recognizes a protocol buffers varint (`uint32`), which is an unsigned LEB128 integer
+#[inline]
+pub fn varint_u32(input: &[u8]) -> IResult<&[u8], u32> {
+  uleb128_u32(input)
+}
+
+/// Recognizes a protocol buffers varint (`uint64`), which is an unsigned LEB128 integer
+#[inline]
+pub fn varint_u64(input: &[u8]) -> IResult<&[u8], u64> {
+  uleb128_u64(input)
+}
+
+/// Recognizes a protocol buffers `int32` varint, the two's complement of the value in 64 bits
+///
+/// Negative values are sign extended to 64 bits, so they take 10 bytes. Returns an
+/// `Overflow` error if the value does not fit in an i32
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::varint_i32;
+/// assert_eq!(varint_i32(&[0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]), Done(&b""[..], -2));
+/// assert_eq!(varint_i32(&[0x96, 0x01]), Done(&b""[..], 150));
+/// ```
+#[inline]
+pub fn varint_i32(input: &[u8]) -> IResult<&[u8], i32> {
+  match uleb128_u64(input) {
+    Done(i, v) => {
+      let v = v as i64;
+      if v < ::std::i32::MIN as i64 || v > ::std::i32::MAX as i64 {
+        Error(error_position!(ErrorKind::Overflow, input))
+      } else {
+        Done(i, v as i32)
+      }
+    },
+    Error(e)      => Error(e),
+    Incomplete(e) => Incomplete(e),
+  }
+}
+
+/// Recognizes a protocol buffers `int64` varint, the two's complement of the value in 64 bits
+#[inline]
+pub fn varint_i64(input: &[u8]) -> IResult<&[u8], i64> {
+  uleb128_u64(input).map(|v| v as i64)
+}
+
+/// decodes a zigzag encoded integer: 0, -1, 1, -2 are encoded as 0, 1, 2, 3
+#[inline]
+pub fn zigzag_i32(value: u32) -> i32 {
+  ((value >> 1) as i32) ^ -((value & 1) as i32)
+}
+
+/// decodes a zigzag encoded integer: 0, -1, 1, -2 are encoded as 0, 1, 2, 3
+#[inline]
+pub fn zigzag_i64(value: u64) -> i64 {
+  ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Recognizes a protocol buffers `sint32` varint, which is zigzag encoded
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::zigzag_varint_i32;
+/// assert_eq!(zigzag_varint_i32(&[0x03]), Done(&b""[..], -2));
+/// assert_eq!(zigzag_varint_i32(&[0xFF, 0xFF, 0xFF, 0xFF, 0x0F]), Done(&b""[..], -2147483648));
+/// ```
+#[inline]
+pub fn zigzag_varint_i32(input: &[u8]) -> IResult<&[u8], i32> {
+  uleb128_u32(input).map(zigzag_i32)
+}
+
+/// Recognizes a protocol buffers `sint64` varint, which is zigzag encoded
+#[inline]
+pub fn zigzag_varint_i64(input: &[u8]) -> IResult<&[u8], i64> {
+  uleb128_u64(input).map(zigzag_i64)
+}
+
+/// Recognizes a variable length quantity, as in MIDI files: 7 bits per byte, most significant
+/// first, the high bit set on all bytes but the last. Returns an `Overflow` error if it does not fit in a u32
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::vlq_u32;
+/// assert_eq!(vlq_u32(&[0x81, 0x80, 0x00, 0x90]), Done(&[0x90][..], 16384));
+/// ```
+#[inline]
+pub fn vlq_u32(input: &[u8]) -> IResult<&[u8], u32> {
+  vlq(input, 32, false).map(|v| v as u32)
+}
+
+/// Recognizes a variable length quantity, as in MIDI files, returning an `Overflow` error if it does not fit in a u64
+#[inline]
+pub fn vlq_u64(input: &[u8]) -> IResult<&[u8], u64> {
+  vlq(input, 64, false)
+}
+
+/// Recognizes a variable length quantity where each continuation adds one to the value, as the
+/// offsets of Git pack files, so that there is only one encoding of a value.
+/// Returns an `Overflow` error if it does not fit in a u32
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::vlq_offset_u32;
+/// assert_eq!(vlq_offset_u32(&[0x80, 0x00]), Done(&b""[..], 128));
+/// ```
+#[inline]
+pub fn vlq_offset_u32(input: &[u8]) -> IResult<&[u8], u32> {
+  vlq(input, 32, true).map(|v| v as u32)
+}
+
+/// Recognizes a variable length quantity where each continuation adds one to the value, as the
+/// offsets of Git pack files. Returns an `Overflow` error if it does not fit in a u64
+#[inline]
+pub fn vlq_offset_u64(input: &[u8]) -> IResult<&[u8], u64> {
+  vlq(input, 64, true)
+}
+
+/// Recognizes a hex-encoded integer, in lowercase or uppercase
+///
+/// Returns an `Overflow` error if the value does not fit in a u32.
+/// Deprecated: `integer_radix::<u32>(input, 16)` also accepts a `0x` prefix and `_` separators,
+/// and returns `Incomplete` if the number reaches the end of the input
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::hex_u32;
+/// assert_eq!(hex_u32(&b"00C5a31Be2;"[..]), Done(&b";"[..], 0xc5a31be2));
+/// ```
 #[inline]
 pub fn hex_u32(input: &[u8]) -> IResult<&[u8], u32> {
-  match is_a!(input, &b"0123456789abcdef"[..]) {
-    Error(e)    => Error(e),
+  let len = input.iter().position(|&b| !is_hex_digit(b)).unwrap_or(input.len());
+  if len == 0 && !input.is_empty() {
+    return Error(error_position!(ErrorKind::IsA, input));
+  }
+
+  let mut res = 0u32;
+  for &b in &input[..len] {
+    if res >> 28 != 0 {
+      return Error(error_position!(ErrorKind::Overflow, input));
+    }
+    res = (res << 4) | (b as char).to_digit(16).unwrap_or(0);
+  }
+  Done(&input[len..], res)
+}
+
+/// length of the decimal floating point number at the beginning of the input
+///
+/// returns `Incomplete` if the input ends where the number could continue
+fn float_length(input: &[u8]) -> IResult<&[u8], usize> {
+  let len = input.len();
+  let mut i = 0;
+
+  if i < len && (input[i] == b'+' || input[i] == b'-') {
+    i += 1;
+  }
+  if i == len {
+    return Incomplete(Needed::Size(i + 1));
+  }
+
+  // the longest word of the input, and the shortest word the input is a prefix of
+  let rest = &input[i..];
+  let mut complete = None;
+  let mut partial  = None;
+  for word in [&b"infinity"[..], &b"inf"[..], &b"nan"[..]].iter() {
+    if rest.len() >= word.len() {
+      if complete.is_none() && rest[..word.len()].eq_ignore_ascii_case(word) {
+        complete = Some(word.len());
+      }
+    } else if rest.eq_ignore_ascii_case(&word[..rest.len()]) {
+      partial = match partial {
+        Some(n) if n < word.len() => Some(n),
+        _                         => Some(word.len()),
+      };
+    }
+  }
+  if let Some(n) = partial {
+    // after "inf", the next byte tells if it continues as "infinity"
+    return Incomplete(Needed::Size(if complete.is_some() { len + 1 } else { i + n }));
+  }
+  if let Some(n) = complete {
+    return Done(&input[i + n..], i + n);
+  }
+
+  let int_start = i;
+  while i < len && is_digit(input[i]) {
+    i += 1;
+  }
+  let mut digits = i - int_start;
+
+  if i < len && input[i] == b'.' {
+    i += 1;
+    let frac_start = i;
+    while i < len && is_digit(input[i]) {
+      i += 1;
+    }
+    digits += i - frac_start;
+  }
+
+  // digits may follow "." or "-." at the end of the input
+  if i == len {
+    return Incomplete(Needed::Size(len + 1));
+  }
+  if digits == 0 {
+    return Error(error_position!(ErrorKind::Float, input));
+  }
+
+  // the exponent is only part of the number if it has digits
+  if input[i] == b'e' || input[i] == b'E' {
+    let mut j = i + 1;
+    if j < len && (input[j] == b'+' || input[j] == b'-') {
+      j += 1;
+    }
+    let exp_start = j;
+    while j < len && is_digit(input[j]) {
+      j += 1;
+    }
+    if j == len {
+      return Incomplete(Needed::Size(len + 1));
+    }
+    if j > exp_start {
+      i = j;
+    }
+  }
+
+  Done(&input[i..], i)
+}
+
+/// converts a number recognized by `float_length`, mapping `inf`, `infinity` and `nan`
+/// explicitly as `str::parse` only accepts them from Rust 1.55
+fn float_value<F: str::FromStr + From<f32>>(text: &[u8]) -> Option<F> {
+  let (negative, word) = match text.first() {
+    Some(&b'-') => (true, &text[1..]),
+    Some(&b'+') => (false, &text[1..]),
+    _           => (false, text),
+  };
+  if word.eq_ignore_ascii_case(b"nan") {
+    Some(F::from(::std::f32::NAN))
+  } else if word.eq_ignore_ascii_case(b"inf") || word.eq_ignore_ascii_case(b"infinity") {
+    Some(F::from(if negative { ::std::f32::NEG_INFINITY } else { ::std::f32::INFINITY }))
+  } else {
+    str::from_utf8(text).ok().and_then(|s| s.parse().ok())
+  }
+}
+
+/// Recognizes a decimal floating point number, like `-1.5e-3`, `.5`, `2.` or `inf`
+///
+/// It accepts a sign, an integral part, a fractional part and an exponent,
+/// or `inf`, `infinity` and `nan` in any case.
+/// As it cannot know if the number continues after the end of the input,
+/// it returns `Incomplete` if the number reaches the end of the input.
+///
+/// ```
+/// # use nom::IResult::{Done,Incomplete};
+/// # use nom::{Needed,recognize_float};
+/// assert_eq!(recognize_float(&b"-1.5e-3;"[..]), Done(&b";"[..], &b"-1.5e-3"[..]));
+/// assert_eq!(recognize_float(&b"12e;"[..]), Done(&b"e;"[..], &b"12"[..]));
+/// assert_eq!(recognize_float(&b"1.5"[..]), Incomplete(Needed::Size(4)));
+/// ```
+#[inline]
+pub fn recognize_float(input: &[u8]) -> IResult<&[u8], &[u8]> {
+  match float_length(input) {
+    Done(i, len)  => Done(i, &input[..len]),
+    Error(e)      => Error(e),
     Incomplete(e) => Incomplete(e),
-    Done(i,o) => {
-      let mut res = 0u32;
-
-      // Do not parse more than 8 characters for a u32
-      let mut remaining = i;
-      let mut parsed    = o;
-      if o.len() > 8 {
-        remaining = &input[8..];
-        parsed    = &input[..8];
+  }
+}
+
+/// Recognizes a decimal floating point number in a string, see `recognize_float`
+#[inline]
+pub fn recognize_float_s(input: &str) -> IResult<&str, &str> {
+  match float_length(input.as_bytes()) {
+    Done(_, len)  => Done(&input[len..], &input[..len]),
+    Error(_)      => Error(error_position!(ErrorKind::Float, input)),
+    Incomplete(e) => Incomplete(e),
+  }
+}
+
+/// Recognizes a decimal floating point number and converts it to a `f32`, see `recognize_float`
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::float;
+/// assert_eq!(float(&b"-1.5e-3, 2"[..]), Done(&b", 2"[..], -1.5e-3));
+/// ```
+#[inline]
+pub fn float(input: &[u8]) -> IResult<&[u8], f32> {
+  match recognize_float(input) {
+    Done(i, o)    => match float_value(o) {
+      Some(f) => Done(i, f),
+      None    => Error(error_position!(ErrorKind::Float, input)),
+    },
+    Error(e)      => Error(e),
+    Incomplete(e) => Incomplete(e),
+  }
+}
+
+/// Recognizes a decimal floating point number and converts it to a `f64`, see `recognize_float`
+#[inline]
+pub fn double(input: &[u8]) -> IResult<&[u8], f64> {
+  match recognize_float(input) {
+    Done(i, o)    => match float_value(o) {
+      Some(f) => Done(i, f),
+      None    => Error(error_position!(ErrorKind::Float, input)),
+    },
+    Error(e)      => Error(e),
+    Incomplete(e) => Incomplete(e),
+  }
+}
+
+/// Recognizes a decimal floating point number in a string and converts it to a `f32`, see `recognize_float`
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::float_s;
+/// assert_eq!(float_s("+.5 m"), Done(" m", 0.5));
+/// ```
+#[inline]
+pub fn float_s(input: &str) -> IResult<&str, f32> {
+  match recognize_float_s(input) {
+    Done(i, o)    => match float_value(o.as_bytes()) {
+      Some(f) => Done(i, f),
+      None    => Error(error_position!(ErrorKind::Float, input)),
+    },
+    Error(e)      => Error(e),
+    Incomplete(e) => Incomplete(e),
+  }
+}
+
+/// Recognizes a decimal floating point number in a string and converts it to a `f64`, see `recognize_float`
+#[inline]
+pub fn double_s(input: &str) -> IResult<&str, f64> {
+  match recognize_float_s(input) {
+    Done(i, o)    => match float_value(o.as_bytes()) {
+      Some(f) => Done(i, f),
+      None    => Error(error_position!(ErrorKind::Float, input)),
+    },
+    Error(e)      => Error(e),
+    Incomplete(e) => Incomplete(e),
+  }
+}
+
+/// Integer types that can be parsed from text by `integer` and `integer_radix`
+pub trait FromDigits: Sized + Copy {
+  /// true if the type accepts a `-` sign
+  fn signed() -> bool;
+  fn zero() -> Self;
+  /// `self * radix + digit`, or `self * radix - digit` for negative numbers,
+  /// or `None` on overflow
+  fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self>;
+}
+
+macro_rules! from_digits_impl (
+  ($t:ty, $signed:expr) => (
+    impl FromDigits for $t {
+      #[inline]
+      fn signed() -> bool {
+        $signed
+      }
+
+      #[inline]
+      fn zero() -> $t {
+        0
       }
 
-      for &e in parsed {
-        let digit = e as char;
-        let value = digit.to_digit(16).unwrap_or(0);
-        res = value + (res << 4);
+      #[inline]
+      fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<$t> {
+        self.checked_mul(radix as $t).and_then(|v| if negative {
+          v.checked_sub(digit as $t)
+        } else {
+          v.checked_add(digit as $t)
+        })
       }
-      Done(remaining, res)
     }
+  );
+);
+
+from_digits_impl!(u8,  false);
+from_digits_impl!(u16, false);
+from_digits_impl!(u32, false);
+from_digits_impl!(u64, false);
+from_digits_impl!(u128, false);
+from_digits_impl!(i8,  true);
+from_digits_impl!(i16, true);
+from_digits_impl!(i32, true);
+from_digits_impl!(i64, true);
+from_digits_impl!(i128, true);
+
+/// Integer types that can be read byte by byte by `be_uint` and `le_uint`
+pub trait FromBytes: Sized + Copy {
+  fn zero() -> Self;
+  /// `self << 8 | byte`, or `None` on overflow
+  fn push_byte(self, byte: u8) -> Option<Self>;
+}
+
+macro_rules! from_bytes_impl (
+  ($($t:ty)*) => ($(
+    impl FromBytes for $t {
+      #[inline]
+      fn zero() -> $t {
+        0
+      }
+
+      #[inline]
+      fn push_byte(self, byte: u8) -> Option<$t> {
+        // shifted twice by 4 bits, as 8 bit types cannot be shifted by 8
+        let value = ((self << 4) << 4) | byte as $t;
+        if (value >> 4) >> 4 == self {
+          Some(value)
+        } else {
+          None
+        }
+      }
+    }
+  )*);
+);
+
+from_bytes_impl!(u8 u16 u32 u64 u128 i8 i16 i32 i64 i128);
+
+/// Recognizes a big endian unsigned integer of `n` bytes, and returns it as a `T`
+///
+/// Returns an `Overflow` error if the value does not fit in `T`
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::be_uint;
+/// assert_eq!(be_uint::<u32>(&[0x01, 0x02, 0x03, 0x04], 3), Done(&[0x04][..], 0x010203));
+/// assert_eq!(be_uint::<u16>(&[0x00, 0x01, 0x02], 3), Done(&b""[..], 0x0102));
+/// ```
+pub fn be_uint<T: FromBytes>(input: &[u8], n: usize) -> IResult<&[u8], T> {
+  if input.len() < n {
+    return Incomplete(Needed::Size(n));
+  }
+
+  match input[..n].iter().fold(Some(T::zero()), |acc, &b| acc.and_then(|v| v.push_byte(b))) {
+    Some(v) => Done(&input[n..], v),
+    None    => Error(error_position!(ErrorKind::Overflow, input)),
+  }
+}
+
+/// Recognizes a little endian unsigned integer of `n` bytes, and returns it as a `T`
+///
+/// Returns an `Overflow` error if the value does not fit in `T`
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::le_uint;
+/// assert_eq!(le_uint::<u64>(&[0x01, 0x02, 0x03, 0x04, 0x05], 5), Done(&b""[..], 0x0504030201));
+/// ```
+pub fn le_uint<T: FromBytes>(input: &[u8], n: usize) -> IResult<&[u8], T> {
+  if input.len() < n {
+    return Incomplete(Needed::Size(n));
+  }
+
+  match input[..n].iter().rev().fold(Some(T::zero()), |acc, &b| acc.and_then(|v| v.push_byte(b))) {
+    Some(v) => Done(&input[n..], v),
+    None    => Error(error_position!(ErrorKind::Overflow, input)),
   }
 }
 
+/// `be_uint!(T, n) => &[u8] -> IResult<&[u8], T>`
+/// parses a big endian unsigned integer of `n` bytes, see the `be_uint` function
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(sample<u32>, be_uint!(u32, 3));
+///  assert_eq!(sample(&[0x7F, 0xFF, 0xFF]), Done(&b""[..], 0x7FFFFF));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! be_uint (
+  ($i:expr, $t:ty, $n:expr) => (
+    $crate::be_uint::<$t>($i, $n as usize)
+  );
+);
+
+/// `le_uint!(T, n) => &[u8] -> IResult<&[u8], T>`
+/// parses a little endian unsigned integer of `n` bytes, see the `le_uint` function
+#[macro_export]
+macro_rules! le_uint (
+  ($i:expr, $t:ty, $n:expr) => (
+    $crate::le_uint::<$t>($i, $n as usize)
+  );
+);
+
+/// result of scanning an integer, with the length of its text
+enum IntegerScan<T> {
+  Done(usize, T),
+  Incomplete(Needed),
+  Error(ErrorKind),
+}
+
+/// scans an integer at the beginning of the input
+///
+/// without radix, a `0x`, `0o` or `0b` prefix selects it, and it defaults to 10.
+/// With a radix of 16, 8 or 2, the corresponding prefix is optional
+fn scan_integer<T: FromDigits>(input: &[u8], radix: Option<u32>) -> IntegerScan<T> {
+  if let Some(r) = radix {
+    if r < 2 || r > 36 {
+      return IntegerScan::Error(ErrorKind::Radix);
+    }
+  }
+
+  let len = input.len();
+  let mut i = 0;
+  let mut negative = false;
+
+  if i < len && (input[i] == b'+' || input[i] == b'-') {
+    negative = input[i] == b'-';
+    if negative && !T::signed() {
+      return IntegerScan::Error(ErrorKind::Digit);
+    }
+    i += 1;
+  }
+  if i == len {
+    return IntegerScan::Incomplete(Needed::Size(i + 1));
+  }
+
+  let mut base = radix.unwrap_or(10);
+  if input[i] == b'0' {
+    if i + 1 == len {
+      return IntegerScan::Incomplete(Needed::Size(len + 1));
+    }
+    let prefix = match input[i + 1] | 0x20 {
+      b'x' => Some(16),
+      b'o' => Some(8),
+      b'b' => Some(2),
+      _    => None,
+    };
+    // the prefix is only part of the number if a digit follows
+    if let Some(p) = prefix {
+      if radix.is_none() || radix == Some(p) {
+        if i + 2 == len {
+          return IntegerScan::Incomplete(Needed::Size(len + 1));
+        }
+        if (input[i + 2] as char).is_digit(p) {
+          base = p;
+          i += 2;
+        }
+      }
+    }
+  }
+
+  let start = i;
+  let mut value = T::zero();
+  while i < len {
+    // a separator is only part of the number if a digit follows
+    if input[i] == b'_' && i > start {
+      if i + 1 == len {
+        return IntegerScan::Incomplete(Needed::Size(len + 1));
+      }
+      if (input[i + 1] as char).is_digit(base) {
+        i += 1;
+        continue;
+      }
+      break;
+    }
+    match (input[i] as char).to_digit(base) {
+      Some(d) => match value.push_digit(base, d, negative) {
+        Some(v) => value = v,
+        None    => return IntegerScan::Error(ErrorKind::Overflow),
+      },
+      None    => break,
+    }
+    i += 1;
+  }
+
+  if i == start {
+    IntegerScan::Error(ErrorKind::Digit)
+  } else if i == len {
+    IntegerScan::Incomplete(Needed::Size(len + 1))
+  } else {
+    IntegerScan::Done(i, value)
+  }
+}
+
+/// Recognizes an integer and converts it to any integer type
+///
+/// It accepts a sign (`-` only for signed types), a `0x`, `0o` or `0b` prefix
+/// for hexadecimal, octal and binary numbers, and single `_` separators between digits.
+/// A value that does not fit in the type returns an `ErrorKind::Overflow` error
+/// instead of being truncated.
+/// As it cannot know if the number continues after the end of the input,
+/// it returns `Incomplete` if the number reaches the end of the input.
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{Done,Error};
+/// # use nom::{integer,ErrorKind};
+/// # #[cfg(feature = "verbose-errors")]
+/// # use nom::Err::Position;
+/// # fn main() {
+/// assert_eq!(integer::<u32>(&b"1_000_000;"[..]), Done(&b";"[..], 1000000));
+/// assert_eq!(integer::<i16>(&b"-0x7F;"[..]), Done(&b";"[..], -127));
+/// assert_eq!(integer::<u8>(&b"0b1111_0000;"[..]), Done(&b";"[..], 240));
+/// assert_eq!(integer::<u8>(&b"256;"[..]), Error(error_position!(ErrorKind::Overflow, &b"256;"[..])));
+/// # }
+/// ```
+#[inline]
+pub fn integer<T: FromDigits>(input: &[u8]) -> IResult<&[u8], T> {
+  integer_scan(input, scan_integer(input, None))
+}
+
+/// Recognizes an integer in the given radix and converts it to any integer type, see `integer`
+///
+/// For a radix of 16, 8 or 2, the `0x`, `0o` or `0b` prefix is optional.
+/// A radix outside of the 2 to 36 range returns an `ErrorKind::Radix` error.
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::integer_radix;
+/// assert_eq!(integer_radix::<u64>(&b"DEAD_beef "[..], 16), Done(&b" "[..], 0xdeadbeef));
+/// assert_eq!(integer_radix::<u64>(&b"0xff "[..], 16), Done(&b" "[..], 255));
+/// assert_eq!(integer_radix::<i32>(&b"-777 "[..], 8), Done(&b" "[..], -511));
+/// ```
+#[inline]
+pub fn integer_radix<T: FromDigits>(input: &[u8], radix: u32) -> IResult<&[u8], T> {
+  integer_scan(input, scan_integer(input, Some(radix)))
+}
+
+/// Recognizes an integer in a string and converts it to any integer type, see `integer`
+#[inline]
+pub fn integer_s<T: FromDigits>(input: &str) -> IResult<&str, T> {
+  integer_scan_s(input, scan_integer(input.as_bytes(), None))
+}
+
+/// Recognizes an integer in the given radix in a string and converts it to any integer type, see `integer_radix`
+#[inline]
+pub fn integer_radix_s<T: FromDigits>(input: &str, radix: u32) -> IResult<&str, T> {
+  integer_scan_s(input, scan_integer(input.as_bytes(), Some(radix)))
+}
+
+#[inline]
+fn integer_scan<T>(input: &[u8], scan: IntegerScan<T>) -> IResult<&[u8], T> {
+  match scan {
+    IntegerScan::Done(len, value) => Done(&input[len..], value),
+    IntegerScan::Incomplete(n)    => Incomplete(n),
+    IntegerScan::Error(kind)      => Error(error_position!(kind, input)),
+  }
+}
+
+#[inline]
+fn integer_scan_s<T>(input: &str, scan: IntegerScan<T>) -> IResult<&str, T> {
+  match scan {
+    IntegerScan::Done(len, value) => Done(&input[len..], value),
+    IntegerScan::Incomplete(n)    => Incomplete(n),
+    IntegerScan::Error(kind)      => Error(error_position!(kind, input)),
+  }
+}
+
+/// `integer!(T) => &[u8] -> IResult<&[u8], T>`
+/// parses an integer of type `T`, with an optional radix, see the `integer` and `integer_radix` functions
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::Done;
+/// # fn main() {
+///  named!(color<(u8, u8, u8)>, tuple!(
+///    integer!(u8), preceded!(tag!(","), integer!(u8)), preceded!(tag!(","), integer!(u8, 16))
+///  ));
+///
+///  assert_eq!(color(&b"0xff,0o17,7f;"[..]), Done(&b";"[..], (255, 15, 127)));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! integer (
+  ($i:expr, $t:ty) => (
+    $crate::integer::<$t>($i)
+  );
+  ($i:expr, $t:ty, $radix:expr) => (
+    $crate::integer_radix::<$t>($i, $radix)
+  );
+);
+
+/// `integer_s!(T) => &str -> IResult<&str, T>`
+/// parses an integer of type `T` in a string, with an optional radix, see the `integer` and `integer_radix` functions
+#[macro_export]
+macro_rules! integer_s (
+  ($i:expr, $t:ty) => (
+    $crate::integer_s::<$t>($i)
+  );
+  ($i:expr, $t:ty, $radix:expr) => (
+    $crate::integer_radix_s::<$t>($i, $radix)
+  );
+);
+
 /// Recognizes non empty buffers
 #[inline]
 pub fn non_empty<'a, T:?Sized>(input: &'a T) -> IResult<&'a T,&'a T> where
@@ -554,6 +1601,133 @@ mod tests {
   use internal::IResult::*;
   use util::ErrorKind;
 
+  #[test]
+  fn recognize_float_test() {
+    assert_eq!(recognize_float(&b"1.5;"[..]), Done(&b";"[..], &b"1.5"[..]));
+    assert_eq!(recognize_float(&b"-12.5e+10 "[..]), Done(&b" "[..], &b"-12.5e+10"[..]));
+    assert_eq!(recognize_float(&b".5E3,"[..]), Done(&b","[..], &b".5E3"[..]));
+    assert_eq!(recognize_float(&b"+3.,"[..]), Done(&b","[..], &b"+3."[..]));
+    assert_eq!(recognize_float(&b"12e+;"[..]), Done(&b"e+;"[..], &b"12"[..]));
+    assert_eq!(recognize_float(&b"-inf,"[..]), Done(&b","[..], &b"-inf"[..]));
+    assert_eq!(recognize_float(&b"Infinity,"[..]), Done(&b","[..], &b"Infinity"[..]));
+    assert_eq!(recognize_float(&b"NaN)"[..]), Done(&b")"[..], &b"NaN"[..]));
+    assert_eq!(recognize_float(&b"-.e1"[..]), Error(error_position!(ErrorKind::Float, &b"-.e1"[..])));
+    assert_eq!(recognize_float(&b"abc"[..]), Error(error_position!(ErrorKind::Float, &b"abc"[..])));
+  }
+
+  #[test]
+  fn recognize_float_incomplete() {
+    assert_eq!(recognize_float(&b""[..]), Incomplete(Needed::Size(1)));
+    assert_eq!(recognize_float(&b"-"[..]), Incomplete(Needed::Size(2)));
+    assert_eq!(recognize_float(&b"12"[..]), Incomplete(Needed::Size(3)));
+    assert_eq!(recognize_float(&b"12."[..]), Incomplete(Needed::Size(4)));
+    assert_eq!(recognize_float(&b"1.5e"[..]), Incomplete(Needed::Size(5)));
+    assert_eq!(recognize_float(&b"1.5e-"[..]), Incomplete(Needed::Size(6)));
+    assert_eq!(recognize_float(&b"1.5e-3"[..]), Incomplete(Needed::Size(7)));
+    assert_eq!(recognize_float(&b"."[..]), Incomplete(Needed::Size(2)));
+    assert_eq!(recognize_float(&b"-."[..]), Incomplete(Needed::Size(3)));
+    assert_eq!(recognize_float(&b"+."[..]), Incomplete(Needed::Size(3)));
+    assert_eq!(recognize_float(&b"in"[..]), Incomplete(Needed::Size(3)));
+    assert_eq!(recognize_float(&b"-inf"[..]), Incomplete(Needed::Size(5)));
+    assert_eq!(recognize_float(&b"infin"[..]), Incomplete(Needed::Size(6)));
+    assert_eq!(recognize_float(&b"infix"[..]), Done(&b"ix"[..], &b"inf"[..]));
+    assert_eq!(recognize_float(&b"na"[..]), Incomplete(Needed::Size(3)));
+  }
+
+  #[test]
+  fn float_test() {
+    assert_eq!(float(&b"-1.5e-3;"[..]), Done(&b";"[..], -1.5e-3f32));
+    assert_eq!(double(&b"3.141592653589793 "[..]), Done(&b" "[..], 3.141592653589793f64));
+    assert_eq!(double(&b"1e400 "[..]), Done(&b" "[..], ::std::f64::INFINITY));
+    assert_eq!(double(&b"-INF "[..]), Done(&b" "[..], ::std::f64::NEG_INFINITY));
+    assert!(float(&b"nan "[..]).unwrap().1.is_nan());
+    assert_eq!(float(&b"x"[..]), Error(error_position!(ErrorKind::Float, &b"x"[..])));
+    assert_eq!(double(&b"25"[..]), Incomplete(Needed::Size(3)));
+  }
+
+  #[test]
+  fn float_str_test() {
+    assert_eq!(recognize_float_s("2.5e3 é"), Done(" é", "2.5e3"));
+    assert_eq!(recognize_float_s("é"), Error(error_position!(ErrorKind::Float, "é")));
+    assert_eq!(float_s("+.5 m"), Done(" m", 0.5f32));
+    assert_eq!(double_s("-0.125)"), Done(")", -0.125f64));
+    assert_eq!(double_s("0.1"), Incomplete(Needed::Size(4)));
+    assert_eq!(double_s("Infinity,"), Done(",", ::std::f64::INFINITY));
+    assert_eq!(float_s("+inf,"), Done(",", ::std::f32::INFINITY));
+    assert_eq!(float_s("-iNfInItY,"), Done(",", ::std::f32::NEG_INFINITY));
+    assert!(double_s("-NaN,").unwrap().1.is_nan());
+  }
+
+  #[test]
+  fn integer_test() {
+    assert_eq!(integer::<u8>(&b"255;"[..]), Done(&b";"[..], 255));
+    assert_eq!(integer::<u16>(&b"+65_535;"[..]), Done(&b";"[..], 65535));
+    assert_eq!(integer::<u32>(&b"4294967295;"[..]), Done(&b";"[..], 4294967295));
+    assert_eq!(integer::<u64>(&b"18446744073709551615;"[..]), Done(&b";"[..], 18446744073709551615));
+    assert_eq!(integer::<i8>(&b"-128;"[..]), Done(&b";"[..], -128));
+    assert_eq!(integer::<i16>(&b"-32768;"[..]), Done(&b";"[..], -32768));
+    assert_eq!(integer::<i32>(&b"2147483647;"[..]), Done(&b";"[..], 2147483647));
+    assert_eq!(integer::<i64>(&b"-9223372036854775808;"[..]), Done(&b";"[..], -9223372036854775808));
+    assert_eq!(integer::<u32>(&b"12ab"[..]), Done(&b"ab"[..], 12));
+    assert_eq!(integer::<u32>(&b"0_1_;"[..]), Done(&b"_;"[..], 1));
+    assert_eq!(integer::<u32>(&b"1__0;"[..]), Done(&b"__0;"[..], 1));
+    assert_eq!(integer::<u32>(&b"10_"[..]), Incomplete(Needed::Size(4)));
+  }
+
+  #[test]
+  fn integer_prefixes() {
+    assert_eq!(integer::<u32>(&b"0xFf;"[..]), Done(&b";"[..], 255));
+    assert_eq!(integer::<u32>(&b"0X1_0;"[..]), Done(&b";"[..], 16));
+    assert_eq!(integer::<i32>(&b"-0o17;"[..]), Done(&b";"[..], -15));
+    assert_eq!(integer::<u8>(&b"0b101;"[..]), Done(&b";"[..], 5));
+    assert_eq!(integer::<u8>(&b"0xg;"[..]), Done(&b"xg;"[..], 0));
+    assert_eq!(integer::<u8>(&b"0b2;"[..]), Done(&b"b2;"[..], 0));
+    assert_eq!(integer_radix::<u32>(&b"0b1;"[..], 16), Done(&b";"[..], 0xb1));
+    assert_eq!(integer_radix::<u32>(&b"0x1;"[..], 8), Done(&b"x1;"[..], 0));
+    assert_eq!(integer_radix::<u32>(&b"zz;"[..], 36), Done(&b";"[..], 1295));
+    assert_eq!(integer_radix::<u32>(&b"1010;"[..], 2), Done(&b";"[..], 10));
+    assert_eq!(integer::<u8>(&b"0;"[..]), Done(&b";"[..], 0));
+    assert_eq!(integer::<u8>(&b"0 "[..]), Done(&b" "[..], 0));
+    assert_eq!(integer::<i8>(&b"-0;"[..]), Done(&b";"[..], 0));
+    assert_eq!(integer::<u8>(&b"07;"[..]), Done(&b";"[..], 7));
+  }
+
+  #[test]
+  fn integer_errors() {
+    assert_eq!(integer::<u8>(&b"256;"[..]), Error(error_position!(ErrorKind::Overflow, &b"256;"[..])));
+    assert_eq!(integer::<i8>(&b"-129;"[..]), Error(error_position!(ErrorKind::Overflow, &b"-129;"[..])));
+    assert_eq!(integer::<i8>(&b"128;"[..]), Error(error_position!(ErrorKind::Overflow, &b"128;"[..])));
+    assert_eq!(integer::<u32>(&b"0x1_0000_0000;"[..]), Error(error_position!(ErrorKind::Overflow, &b"0x1_0000_0000;"[..])));
+    assert_eq!(integer::<u8>(&b"1000"[..]), Error(error_position!(ErrorKind::Overflow, &b"1000"[..])));
+    assert_eq!(integer::<u8>(&b"-1;"[..]), Error(error_position!(ErrorKind::Digit, &b"-1;"[..])));
+    assert_eq!(integer::<u8>(&b"_1;"[..]), Error(error_position!(ErrorKind::Digit, &b"_1;"[..])));
+    assert_eq!(integer::<i8>(&b"-a"[..]), Error(error_position!(ErrorKind::Digit, &b"-a"[..])));
+    assert_eq!(integer_radix::<u8>(&b"1;"[..], 37), Error(error_position!(ErrorKind::Radix, &b"1;"[..])));
+    assert_eq!(integer_radix::<u8>(&b"1;"[..], 1), Error(error_position!(ErrorKind::Radix, &b"1;"[..])));
+  }
+
+  #[test]
+  fn integer_incomplete() {
+    assert_eq!(integer::<u8>(&b""[..]), Incomplete(Needed::Size(1)));
+    assert_eq!(integer::<i8>(&b"-"[..]), Incomplete(Needed::Size(2)));
+    assert_eq!(integer::<u8>(&b"25"[..]), Incomplete(Needed::Size(3)));
+    assert_eq!(integer::<u8>(&b"0"[..]), Incomplete(Needed::Size(2)));
+    assert_eq!(integer::<u8>(&b"0x"[..]), Incomplete(Needed::Size(3)));
+    assert_eq!(integer::<i8>(&b"-0b"[..]), Incomplete(Needed::Size(4)));
+    assert_eq!(integer_radix::<u8>(&b"0x"[..], 16), Incomplete(Needed::Size(3)));
+    assert_eq!(integer_radix::<u8>(&b"0x"[..], 10), Done(&b"x"[..], 0));
+    assert_eq!(integer::<u8>(&b"0xf"[..]), Incomplete(Needed::Size(4)));
+    assert_eq!(integer::<u8>(&b"1_"[..]), Incomplete(Needed::Size(3)));
+  }
+
+  #[test]
+  fn integer_str() {
+    assert_eq!(integer_s::<i64>("-1_234 é"), Done(" é", -1234));
+    assert_eq!(integer_radix_s::<u16>("ffff)", 16), Done(")", 65535));
+    assert_eq!(integer_s::<u16>("0x1_0000)"), Error(error_position!(ErrorKind::Overflow, "0x1_0000)")));
+    assert_eq!(integer_s::<u16>("é"), Error(error_position!(ErrorKind::Digit, "é")));
+  }
+
   #[test]
   fn tag_closure() {
     let x = tag_cl(&b"abcd"[..]);
@@ -783,6 +1957,142 @@ mod tests {
     assert_eq!(le_i64(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80]), Done(&b""[..], -9223372036854775808_i64));
   }
 
+  #[test]
+  fn leb128_tests() {
+    assert_eq!(uleb128_u32(&[0x00]), Done(&b""[..], 0));
+    assert_eq!(uleb128_u32(&[0x7F, 0x01]), Done(&[0x01][..], 127));
+    assert_eq!(uleb128_u32(&[0x80, 0x01]), Done(&b""[..], 128));
+    assert_eq!(uleb128_u32(&[]), Incomplete(Needed::Size(1)));
+    assert_eq!(uleb128_u32(&[0x80, 0x80]), Incomplete(Needed::Size(3)));
+    // padded encodings are accepted
+    assert_eq!(uleb128_u32(&[0x81, 0x80, 0x80, 0x80, 0x00]), Done(&b""[..], 1));
+    assert_eq!(uleb128_u32(&[0xFF, 0xFF, 0xFF, 0xFF, 0x0F]), Done(&b""[..], 4294967295));
+    let too_big = &[0xFF, 0xFF, 0xFF, 0xFF, 0x1F][..];
+    assert_eq!(uleb128_u32(too_big), Error(error_position!(ErrorKind::Overflow, too_big)));
+    let too_long = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x00][..];
+    assert_eq!(uleb128_u32(too_long), Error(error_position!(ErrorKind::Overflow, too_long)));
+
+    let max = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01][..];
+    assert_eq!(uleb128_u64(max), Done(&b""[..], ::std::u64::MAX));
+    let too_big = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02][..];
+    assert_eq!(uleb128_u64(too_big), Error(error_position!(ErrorKind::Overflow, too_big)));
+  }
+
+  #[test]
+  fn sleb128_tests() {
+    assert_eq!(sleb128_i32(&[0x02]), Done(&b""[..], 2));
+    assert_eq!(sleb128_i32(&[0x7E]), Done(&b""[..], -2));
+    assert_eq!(sleb128_i32(&[0xFF, 0x00]), Done(&b""[..], 127));
+    assert_eq!(sleb128_i32(&[0x80, 0x7F]), Done(&b""[..], -128));
+    assert_eq!(sleb128_i32(&[0xC0]), Incomplete(Needed::Size(2)));
+    assert_eq!(sleb128_i32(&[0xFF, 0xFF, 0xFF, 0xFF, 0x07]), Done(&b""[..], ::std::i32::MAX));
+    assert_eq!(sleb128_i32(&[0x80, 0x80, 0x80, 0x80, 0x78]), Done(&b""[..], ::std::i32::MIN));
+    let too_big = &[0x80, 0x80, 0x80, 0x80, 0x08][..];
+    assert_eq!(sleb128_i32(too_big), Error(error_position!(ErrorKind::Overflow, too_big)));
+    let too_small = &[0xFF, 0xFF, 0xFF, 0xFF, 0x77][..];
+    assert_eq!(sleb128_i32(too_small), Error(error_position!(ErrorKind::Overflow, too_small)));
+
+    let min = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F][..];
+    assert_eq!(sleb128_i64(min), Done(&b""[..], ::std::i64::MIN));
+    let max = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00][..];
+    assert_eq!(sleb128_i64(max), Done(&b""[..], ::std::i64::MAX));
+    let too_big = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01][..];
+    assert_eq!(sleb128_i64(too_big), Error(error_position!(ErrorKind::Overflow, too_big)));
+  }
+
+  #[test]
+  fn varint_tests() {
+    assert_eq!(varint_u32(&[0xAC, 0x02]), Done(&b""[..], 300));
+    assert_eq!(varint_u64(&[0x96, 0x01]), Done(&b""[..], 150));
+    let minus_one = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01][..];
+    assert_eq!(varint_i64(minus_one), Done(&b""[..], -1));
+    assert_eq!(varint_i32(minus_one), Done(&b""[..], -1));
+    let i32_min = &[0x80, 0x80, 0x80, 0x80, 0xF8, 0xFF, 0xFF, 0xFF, 0xFF, 0x01][..];
+    assert_eq!(varint_i32(i32_min), Done(&b""[..], ::std::i32::MIN));
+    let too_big = &[0x80, 0x80, 0x80, 0x80, 0x08][..];
+    assert_eq!(varint_i32(too_big), Error(error_position!(ErrorKind::Overflow, too_big)));
+    assert_eq!(varint_i32(&minus_one[..5]), Incomplete(Needed::Size(6)));
+    assert_eq!(varint_u32(minus_one), Error(error_position!(ErrorKind::Overflow, minus_one)));
+
+    assert_eq!(zigzag_i32(0), 0);
+    assert_eq!(zigzag_i32(1), -1);
+    assert_eq!(zigzag_i32(4294967294), 2147483647);
+    assert_eq!(zigzag_i64(18446744073709551615), ::std::i64::MIN);
+    assert_eq!(zigzag_varint_i32(&[0x02]), Done(&b""[..], 1));
+    assert_eq!(zigzag_varint_i64(&[0x81, 0x01]), Done(&b""[..], -65));
+    assert_eq!(zigzag_varint_i64(&[0x81]), Incomplete(Needed::Size(2)));
+  }
+
+  #[test]
+  fn vlq_tests() {
+    assert_eq!(vlq_u32(&[0x7F]), Done(&b""[..], 127));
+    assert_eq!(vlq_u32(&[0x81, 0x00]), Done(&b""[..], 128));
+    assert_eq!(vlq_u32(&[0xFF, 0xFF, 0xFF, 0x7F]), Done(&b""[..], 0x0FFFFFFF));
+    assert_eq!(vlq_u32(&[0x8F, 0xFF, 0xFF, 0xFF, 0x7F]), Done(&b""[..], ::std::u32::MAX));
+    let too_big = &[0x90, 0x80, 0x80, 0x80, 0x00][..];
+    assert_eq!(vlq_u32(too_big), Error(error_position!(ErrorKind::Overflow, too_big)));
+    assert_eq!(vlq_u32(&[0x81, 0x80]), Incomplete(Needed::Size(3)));
+    assert_eq!(vlq_u64(&[0x81, 0x80, 0x80, 0x80, 0x80, 0x00]), Done(&b""[..], 1 << 35));
+
+    assert_eq!(vlq_offset_u32(&[0x7F]), Done(&b""[..], 127));
+    assert_eq!(vlq_offset_u32(&[0x80, 0x7F]), Done(&b""[..], 255));
+    assert_eq!(vlq_offset_u32(&[0x81, 0x00]), Done(&b""[..], 256));
+    assert_eq!(vlq_offset_u64(&[0x80, 0x80, 0x00]), Done(&b""[..], 16512));
+  }
+
+  #[test]
+  fn odd_width_tests() {
+    assert_eq!(be_u24(&[0x01, 0x02, 0x03, 0x04]), Done(&[0x04][..], 0x010203));
+    assert_eq!(le_u24(&[0x01, 0x02, 0x03]), Done(&b""[..], 0x030201));
+    assert_eq!(be_u24(&[0x01, 0x02]), Incomplete(Needed::Size(3)));
+    assert_eq!(be_i24(&[0xFF, 0xFF, 0xFE]), Done(&b""[..], -2));
+    assert_eq!(be_i24(&[0x7F, 0xFF, 0xFF]), Done(&b""[..], 8388607));
+    assert_eq!(le_i24(&[0x00, 0x00, 0x80]), Done(&b""[..], -8388608));
+
+    assert_eq!(be_u48(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06]), Done(&b""[..], 0x010203040506));
+    assert_eq!(le_u48(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06]), Done(&b""[..], 0x060504030201));
+    assert_eq!(le_u48(&[0x01]), Incomplete(Needed::Size(6)));
+    assert_eq!(be_i48(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]), Done(&b""[..], -256));
+    assert_eq!(le_i48(&[0x00, 0x01, 0x00, 0x00, 0x00, 0x00]), Done(&b""[..], 256));
+  }
+
+  #[test]
+  fn int128_tests() {
+    let bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11];
+    assert_eq!(be_u128(&bytes), Done(&[0x11][..], 0x0102030405060708090A0B0C0D0E0F10));
+    assert_eq!(le_u128(&bytes), Done(&[0x11][..], 0x100F0E0D0C0B0A090807060504030201));
+    assert_eq!(be_u128(&bytes[..15]), Incomplete(Needed::Size(16)));
+    assert_eq!(be_i128(&[0xFF; 16]), Done(&b""[..], -1));
+    let mut min = [0u8; 16];
+    min[15] = 0x80;
+    assert_eq!(le_i128(&min), Done(&b""[..], ::std::i128::MIN));
+  }
+
+  #[test]
+  fn uint_tests() {
+    assert_eq!(be_uint::<u8>(&[0x2A], 1), Done(&b""[..], 42));
+    assert_eq!(be_uint::<u8>(&[0x01, 0x02], 2), Error(error_position!(ErrorKind::Overflow, &[0x01, 0x02][..])));
+    assert_eq!(le_uint::<u8>(&[0xFF, 0x00], 2), Done(&b""[..], 255));
+    assert_eq!(be_uint::<u32>(&[0xAB], 0), Done(&[0xAB][..], 0));
+    assert_eq!(le_uint::<u32>(&[0x01, 0x02, 0x03], 3), Done(&b""[..], 0x030201));
+    assert_eq!(be_uint::<u64>(&[0x01, 0x02], 3), Incomplete(Needed::Size(3)));
+    let bytes = [0xFF; 16];
+    assert_eq!(be_uint::<u128>(&bytes, 16), Done(&b""[..], ::std::u128::MAX));
+    assert_eq!(le_uint::<u128>(&bytes, 11), Done(&bytes[11..], (1 << 88) - 1));
+    assert_eq!(be_uint::<i8>(&[0x7F], 1), Done(&b""[..], 127));
+    assert_eq!(be_uint::<i8>(&[0x80], 1), Error(error_position!(ErrorKind::Overflow, &[0x80][..])));
+    assert_eq!(le_uint::<i16>(&[0xFF, 0x7F], 2), Done(&b""[..], 32767));
+    assert_eq!(be_uint::<i16>(&[0x80, 0x00], 2), Error(error_position!(ErrorKind::Overflow, &[0x80, 0x00][..])));
+    assert_eq!(be_uint::<u16>(&[0x01, 0x00, 0x00], 3), Error(error_position!(ErrorKind::Overflow, &[0x01, 0x00, 0x00][..])));
+    assert_eq!(be_uint::<u16>(&bytes, 3), Error(error_position!(ErrorKind::Overflow, &bytes[..])));
+    assert_eq!(be_uint::<i16>(&[0x80, 0x00], 2), Error(error_position!(ErrorKind::Overflow, &[0x80, 0x00][..])));
+
+    named!(be3<u32>, be_uint!(u32, 3));
+    named!(le3<u32>, le_uint!(u32, 3));
+    assert_eq!(be3(&[0x00, 0x01, 0x00]), Done(&b""[..], 256));
+    assert_eq!(le3(&[0x00, 0x01, 0x00]), Done(&b""[..], 256));
+  }
+
   #[test]
   fn be_f32_tests() {
     assert_eq!(be_f32(&[0x00, 0x00, 0x00, 0x00]), Done(&b""[..], 0_f32));
@@ -813,10 +2123,12 @@ mod tests {
     assert_eq!(hex_u32(&b"ff"[..]), Done(&b""[..], 255));
     assert_eq!(hex_u32(&b"1be2"[..]), Done(&b""[..], 7138));
     assert_eq!(hex_u32(&b"c5a31be2"[..]), Done(&b""[..], 3315801058));
-    assert_eq!(hex_u32(&b"00c5a31be2"[..]), Done(&b"e2"[..], 12952347));
-    assert_eq!(hex_u32(&b"c5a31be201"[..]), Done(&b"01"[..], 3315801058));
+    assert_eq!(hex_u32(&b"00c5a31be2"[..]), Done(&b""[..], 3315801058));
+    assert_eq!(hex_u32(&b"c5a31be201"[..]), Error(error_position!(ErrorKind::Overflow, &b"c5a31be201"[..])));
     assert_eq!(hex_u32(&b"ffffffff"[..]), Done(&b""[..], 4294967295));
+    assert_eq!(hex_u32(&b"FfFf;"[..]), Done(&b";"[..], 65535));
     assert_eq!(hex_u32(&b"0x1be2"[..]), Done(&b"x1be2"[..], 0));
+    assert_eq!(hex_u32(&b"g"[..]), Error(error_position!(ErrorKind::IsA, &b"g"[..])));
   }
 
     #[test]
@@ -866,6 +2178,51 @@ mod tests {
 
   }
 
+  #[test]
+  fn endianness_enum() {
+    named!(be_tst24<u32>, u24!(Endianness::Big));
+    named!(le_tst24<i32>, i24!(Endianness::Little));
+    assert_eq!(be_tst24(&[0x01, 0x00, 0x00]), Done(&b""[..], 65536));
+    assert_eq!(le_tst24(&[0xFF, 0xFF, 0xFF]), Done(&b""[..], -1));
+
+    named!(le_tstf32<f32>, f32!(Endianness::Little));
+    named!(be_tstf64<f64>, f64!(Endianness::Big));
+    assert_eq!(le_tstf32(&[0x00, 0x00, 0x80, 0x3f]), Done(&b""[..], 1.0));
+    assert_eq!(be_tstf64(&[0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]), Done(&b""[..], -2.0));
+
+    named!(native<u32>, u32!(Endianness::Native));
+    let one = 1u32;
+    let bytes: [u8; 4] = unsafe { transmute(one) };
+    assert_eq!(native(&bytes), Done(&b""[..], 1));
+    assert_eq!(Endianness::Native.is_big(), cfg!(target_endian = "big"));
+
+    named!(le_tst128<u128>, u128!(false));
+    assert_eq!(le_tst128(&[1; 16]), Done(&b""[..], 0x01010101010101010101010101010101));
+    // the byte order is a parameter of the parser
+    fn tst48(i: &[u8], endian: Endianness) -> IResult<&[u8], i64> {
+      i48!(i, endian)
+    }
+    assert_eq!(tst48(&[0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF], Endianness::Little), Done(&b""[..], -2));
+    assert_eq!(tst48(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE], Endianness::Big), Done(&b""[..], -2));
+
+    named!(le_uint5<u64>, uint!(u64, 5, Endianness::Little));
+    assert_eq!(le_uint5(&[0, 0, 0, 0, 1]), Done(&b""[..], 1 << 32));
+  }
+
+  #[test]
+  fn endianness_magic() {
+    named!(tiff<Endianness>, endianness!(&b"MM"[..], &b"II"[..]));
+    assert_eq!(tiff(&b"II*"[..]), Done(&b"*"[..], Endianness::Little));
+    assert_eq!(tiff(&b"MM"[..]), Done(&b""[..], Endianness::Big));
+    assert_eq!(tiff(&b"I"[..]), Incomplete(Needed::Size(2)));
+    assert_eq!(tiff(&b""[..]), Incomplete(Needed::Size(2)));
+    assert_eq!(tiff(&b"IM"[..]), Error(error_position!(ErrorKind::Endianness, &b"IM"[..])));
+
+    named!(elf_like<Endianness>, endianness!(&[0xfe, 0xed][..]));
+    assert_eq!(elf_like(&[0xed, 0xfe, 0x01]), Done(&[0x01][..], Endianness::Little));
+    assert_eq!(elf_like(&[0xfe]), Incomplete(Needed::Size(2)));
+  }
+
   #[test]
   fn manual_configurable_endianness_test() {
     let x = 1;
@@ -1,5 +1,6 @@
 use util::ErrorKind;
-use internal::{IResult, IError};
+use internal::{IResult, IError, ParseError};
+use util::InputLength;
 use internal::IResult::*;
 
 pub type Err<E=u32> = ErrorKind<E>;
@@ -47,6 +48,39 @@ impl<I,O,E> IResult<I,O,E> {
   }
 }
 
+/// Applies a parser on the whole input and converts its result to a std::result::Result
+///
+/// The parser must consume all the input: if some of it remains after
+/// a successful parse, `ParseError::TrailingData` contains the offset
+/// of the first unconsumed element
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::{parse_all, ParseError};
+/// # fn main() {
+///  named!(abcd, tag!("abcd"));
+///
+///  assert_eq!(parse_all(&b"abcd"[..], abcd), Ok(&b"abcd"[..]));
+///  assert_eq!(parse_all(&b"abcdefgh"[..], abcd), Err(ParseError::TrailingData(4)));
+/// # }
+/// ```
+pub fn parse_all<I,O,E,F>(input: I, parser: F) -> Result<O, ParseError<E>>
+  where I: InputLength,
+        F: FnOnce(I) -> IResult<I,O,E> {
+  let len = input.input_len();
+  match parser(input) {
+    Done(i, o)    => {
+      if i.input_len() == 0 {
+        Ok(o)
+      } else {
+        Err(ParseError::TrailingData(len - i.input_len()))
+      }
+    },
+    Incomplete(n) => Err(ParseError::Incomplete(n)),
+    Error(e)      => Err(ParseError::Error(e))
+  }
+}
+
 /// translate parser result from IResult<I,O,u32> to IResult<I,O,E> with a custom type
 ///
 /// ```
@@ -37,7 +37,8 @@ macro_rules! tag_s (
 /// `tag_nocase_s!(&str) => &str -> IResult<&str, &str>`
 /// declares a case-insensitive string as a suite to recognize
 ///
-/// consumes the recognized characters
+/// consumes the recognized characters. Characters are compared with their full Unicode case
+/// folding, for all scripts, so the recognized input can have a different length than the tag
 ///
 /// ```
 /// # #[macro_use] extern crate nom;
@@ -54,17 +55,15 @@ macro_rules! tag_s (
 macro_rules! tag_nocase_s (
   ($i:expr, $tag: expr) => (
     {
-      let res: $crate::IResult<_,_> = if $tag.len() > $i.len() {
-        $crate::IResult::Incomplete($crate::Needed::Size($tag.len()))
-      } else if $i[0..$tag.len()].chars().map(|c| (c).to_lowercase().next().unwrap_or(c))
-                    .zip($tag.chars().map(|c| (c).to_lowercase().next().unwrap_or(c)))
-                    .map(|(tc, ic)| tc == ic)
-                    .take_while(|r| *r == true)
-                    .count() == $tag.len()
-      {
-        $crate::IResult::Done(&$i[$tag.len()..], &$i[0..$tag.len()])
-      } else {
-        $crate::IResult::Error($crate::Err::Position($crate::ErrorKind::TagStr, $i))
+      let input: &str = $i;
+      let tag: &str   = $tag;
+
+      let res: $crate::IResult<_,_> = match $crate::tag_nocase_len(input, tag) {
+        $crate::NocaseMatch::Match(n)   => $crate::IResult::Done(&input[n..], &input[..n]),
+        $crate::NocaseMatch::Incomplete => {
+          $crate::IResult::Incomplete($crate::Needed::Size(if input.len() < tag.len() { tag.len() } else { input.len() + 1 }))
+        },
+        $crate::NocaseMatch::Mismatch   => $crate::IResult::Error(error_position!($crate::ErrorKind::TagStr, input)),
       };
       res
     }
@@ -409,9 +408,360 @@ macro_rules! take_until_s (
   );
 );
 
+/// `is_a_nocase_s!(&str) => &str -> IResult<&str, &str>`
+/// returns the longest list of characters that appear in the provided string, ignoring case
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{self,Done};
+/// # fn main() {
+///  fn greek(input: &str) -> IResult<&str, &str> {
+///    is_a_nocase_s!(input, "αβγ")
+///  }
+///  assert_eq!(greek("ΑβΓα!"), Done("!", "ΑβΓα"));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! is_a_nocase_s (
+  ($input:expr, $arr:expr) => (
+    {
+      let input: &str = $input;
+      let arr: &str   = $arr;
+
+      let offset = match input.char_indices().find(|&(_, c)| {
+        !arr.chars().any(|a| $crate::fold_case(a).eq($crate::fold_case(c)))
+      }) {
+        Some((o, _)) => o,
+        None         => input.len(),
+      };
+      let res: $crate::IResult<_,_> = if offset == 0 {
+        $crate::IResult::Error(error_position!($crate::ErrorKind::IsAStr,input))
+      } else {
+        $crate::IResult::Done(&input[offset..], &input[..offset])
+      };
+      res
+    }
+  );
+);
+
+/// `take_until_nocase_s!(&str) => &str -> IResult<&str, &str>`
+/// consumes characters until it finds the specified string, ignoring case
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{self,Done};
+/// # fn main() {
+///  fn before_end(input: &str) -> IResult<&str, &str> {
+///    take_until_nocase_s!(input, "end")
+///  }
+///  assert_eq!(before_end("été END"), Done("END", "été "));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! take_until_nocase_s (
+  ($input:expr, $substr:expr) => (
+    {
+      let input: &str  = $input;
+      let substr: &str = $substr;
+
+      let res: $crate::IResult<&str, &str> = if substr.len() > input.len() {
+        $crate::IResult::Incomplete($crate::Needed::Size(substr.len()))
+      } else {
+        let found = input.char_indices().map(|(o, _)| o).find(|&o| {
+          match $crate::tag_nocase_len(&input[o..], substr) {
+            $crate::NocaseMatch::Match(_) => true,
+            _                             => false,
+          }
+        });
+        match found {
+          Some(offset) => $crate::IResult::Done(&input[offset..], &input[..offset]),
+          None         => $crate::IResult::Error(error_position!($crate::ErrorKind::TakeUntilStr,input))
+        }
+      };
+      res
+    }
+  );
+);
+
+/// `escaped_s!(&str -> IResult<&str, &str>, char, &str -> IResult<&str, &str>) => &str -> IResult<&str, &str>`
+/// matches a string with escaped characters.
+///
+/// The first argument matches the normal characters (it must not accept the control character), the second argument is the control character (like `\` in most languages),
+/// the third argument matches the escaped characters
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{self,Done};
+/// # fn main() {
+///  fn esc(input: &str) -> IResult<&str, &str> {
+///    escaped_s!(input, is_not_s!("\\\""), '\\', is_a_s!("\"n\\"))
+///  }
+///  assert_eq!(esc("ab\\\"cd\" x"), Done("\" x", "ab\\\"cd"));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! escaped_s (
+  ($i:expr, $submac:ident!( $($args:tt)* ), $control_char: expr, $escapable:ident!( $($args2:tt)* )) => (
+    {
+      let input: &str = $i;
+      let cl = || {
+        use $crate::Offset;
+        let mut index  = 0;
+
+        while index < input.len() {
+          if let $crate::IResult::Done(i,_) = $submac!(&input[index..], $($args)*) {
+            if i.is_empty() {
+              return $crate::IResult::Done(&input[input.len()..], input)
+            } else {
+              index = input.offset(i);
+            }
+          } else if input[index..].starts_with($control_char) {
+            let next = index + $control_char.len_utf8();
+            if next >= input.len() {
+              return $crate::IResult::Error(error_position!($crate::ErrorKind::Escaped, &input[index..]));
+            } else {
+              match $escapable!(&input[next..], $($args2)*) {
+                $crate::IResult::Done(i,_) => {
+                  if i.is_empty() {
+                    return $crate::IResult::Done(&input[input.len()..], input)
+                  } else {
+                    index = input.offset(i);
+                  }
+                },
+                $crate::IResult::Incomplete(i) => return $crate::IResult::Incomplete(i),
+                $crate::IResult::Error(e)      => return $crate::IResult::Error(e)
+              }
+            }
+          } else {
+            if index == 0 {
+              return $crate::IResult::Error(error_position!($crate::ErrorKind::Escaped, &input[index..]))
+            } else {
+              return $crate::IResult::Done(&input[index..], &input[..index])
+            }
+          }
+        }
+        $crate::IResult::Done(&input[index..], &input[..index])
+      };
+      let res: $crate::IResult<_,_> = cl();
+      match res {
+        $crate::IResult::Incomplete(x) => $crate::IResult::Incomplete(x),
+        $crate::IResult::Done(i, o)    => $crate::IResult::Done(i, o),
+        $crate::IResult::Error(e)      => {
+          $crate::IResult::Error(error_node_position!($crate::ErrorKind::Escaped, input, e))
+        }
+      }
+    }
+  );
+  ($i:expr, $f:expr, $control_char: expr, $escapable:ident!( $($args2:tt)* )) => (
+    escaped_s!($i, call!($f), $control_char, $escapable!($($args2)*))
+  );
+  ($i:expr, $submac:ident!( $($args:tt)* ), $control_char: expr, $g:expr) => (
+    escaped_s!($i, $submac!($($args)*), $control_char, call!($g))
+  );
+  ($i:expr, $f:expr, $control_char: expr, $g:expr) => (
+    escaped_s!($i, call!($f), $control_char, call!($g))
+  );
+);
+
+/// `escaped_transform_s!(&str -> IResult<&str, &str>, char, &str -> IResult<&str, T>) => &str -> IResult<&str, Cow<str>>`
+/// matches a string with escaped characters, and transforms them.
+///
+/// The first argument matches the normal characters (it must not match the control character), the second argument is the control character (like `\` in most languages),
+/// the third argument matches the escaped characters and transforms them to a `&str` or a `String`.
+///
+/// The output borrows the input if it contains no escaped characters, and is only
+/// allocated at the first escaped sequence
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::IResult::{self,Done};
+/// # use std::borrow::Cow;
+/// # fn main() {
+///  fn unescape(input: &str) -> IResult<&str, Cow<str>> {
+///    escaped_transform_s!(input, is_not_s!("\\\""), '\\',
+///      alt!(
+///          tag_s!("\\") => { |_| "\\" }
+///        | tag_s!("\"") => { |_| "\"" }
+///        | tag_s!("n")  => { |_| "\n" }
+///      )
+///    )
+///  }
+///  assert_eq!(unescape("été\\n\\\"x\"!"), Done("\"!", Cow::Owned(String::from("été\n\"x"))));
+///  assert_eq!(unescape("été\"!"), Done("\"!", Cow::Borrowed("été")));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! escaped_transform_s (
+  ($i:expr, $submac:ident!( $($args:tt)* ), $control_char: expr, $transform:ident!( $($args2:tt)* )) => (
+    {
+      let input: &str = $i;
+      let cl = || {
+        use $crate::Offset;
+        use ::std::borrow::Cow;
+        let mut index  = 0;
+        // borrows the input until the first escaped sequence
+        let mut res: Cow<str> = Cow::Borrowed(&input[..0]);
+
+        while index < input.len() {
+          if let $crate::IResult::Done(i,o) = $submac!(&input[index..], $($args)*) {
+            match res {
+              Cow::Owned(ref mut s) => s.push_str(o),
+              Cow::Borrowed(_)      => res = Cow::Borrowed(&input[..input.offset(i)]),
+            }
+            if i.is_empty() {
+              return $crate::IResult::Done(&input[input.len()..], res)
+            } else {
+              index = input.offset(i);
+            }
+          } else if input[index..].starts_with($control_char) {
+            let next = index + $control_char.len_utf8();
+            if next >= input.len() {
+              return $crate::IResult::Error(error_position!($crate::ErrorKind::EscapedTransform, &input[index..]));
+            } else {
+              match $transform!(&input[next..], $($args2)*) {
+                $crate::IResult::Done(i,o) => {
+                  res.to_mut().push_str(o.as_ref());
+                  if i.is_empty() {
+                    return $crate::IResult::Done(&input[input.len()..], res)
+                  } else {
+                    index = input.offset(i);
+                  }
+                },
+                $crate::IResult::Incomplete(i) => return $crate::IResult::Incomplete(i),
+                $crate::IResult::Error(e)      => return $crate::IResult::Error(e)
+              }
+            }
+          } else {
+            if index == 0 {
+              return $crate::IResult::Error(error_position!($crate::ErrorKind::EscapedTransform, &input[index..]))
+            } else {
+              return $crate::IResult::Done(&input[index..], res)
+            }
+          }
+        }
+        $crate::IResult::Done(&input[index..], res)
+      };
+      let res: $crate::IResult<_,_> = cl();
+      match res {
+        $crate::IResult::Incomplete(x) => $crate::IResult::Incomplete(x),
+        $crate::IResult::Done(i, o)    => $crate::IResult::Done(i, o),
+        $crate::IResult::Error(e)      => {
+          $crate::IResult::Error(error_node_position!($crate::ErrorKind::EscapedTransform, input, e))
+        }
+      }
+    }
+  );
+  ($i:expr, $f:expr, $control_char: expr, $transform:ident!( $($args2:tt)* )) => (
+    escaped_transform_s!($i, call!($f), $control_char, $transform!($($args2)*))
+  );
+  ($i:expr, $submac:ident!( $($args:tt)* ), $control_char: expr, $g:expr) => (
+    escaped_transform_s!($i, $submac!($($args)*), $control_char, call!($g))
+  );
+  ($i:expr, $f:expr, $control_char: expr, $g:expr) => (
+    escaped_transform_s!($i, call!($f), $control_char, call!($g))
+  );
+);
+
 #[cfg(test)]
 mod test {
     use ::IResult;
+    use ::Needed;
+
+    #[test]
+    fn verify_str() {
+        named!(short_word<&str, &str>, verify!(take_while_s!(|c: char| c.is_alphabetic()), |s: &&str| s.chars().count() <= 3));
+
+        assert_eq!(short_word("été!"), IResult::Done("!", "été"));
+        assert_eq!(short_word("étés!"), IResult::Error(error_position!(ErrorKind::Verify, "étés!")));
+    }
+
+    #[test]
+    fn consumed_str() {
+        named!(word<&str, (&str, usize)>, consumed!(map!(take_while_s!(|c: char| c.is_alphabetic()), |s: &str| s.chars().count())));
+
+        assert_eq!(word("héllo wörld"), IResult::Done(" wörld", ("héllo", 5)));
+    }
+
+    #[test]
+    fn with_span_str() {
+        let source = "fn été() {}";
+        fn ident<'a>(input: &'a str, base: &'a str) -> IResult<&'a str, (&'a str, usize, (usize, usize))> {
+            with_span!(input, base, map!(take_while_s!(|c: char| c.is_alphabetic()), |s: &str| s.chars().count()))
+        }
+
+        assert_eq!(ident(source, source), IResult::Done(" été() {}", ("fn", 2, (0, 2))));
+        assert_eq!(ident(&source[3..], source), IResult::Done("() {}", ("été", 3, (3, 8))));
+    }
+
+    #[test]
+    fn escaped_str() {
+        named!(esc<&str, &str>, escaped_s!(is_not_s!("\\\""), '\\', is_a_s!("\"n\\")));
+
+        assert_eq!(esc("abcd"), IResult::Done("", "abcd"));
+        assert_eq!(esc("ab\\\"cd\"x"), IResult::Done("\"x", "ab\\\"cd"));
+        assert_eq!(esc("\\nété\""), IResult::Done("\"", "\\nété"));
+        assert_eq!(esc("ab\\"), IResult::Error(error_node_position!(ErrorKind::Escaped, "ab\\",
+          error_position!(ErrorKind::Escaped, "\\"))));
+        assert_eq!(esc("ab\\x"), IResult::Error(error_node_position!(ErrorKind::Escaped, "ab\\x",
+          error_position!(ErrorKind::IsAStr, "x"))));
+    }
+
+    #[test]
+    fn escaped_transform_str() {
+        use std::borrow::Cow;
+
+        named!(unescape<&str, Cow<str> >, escaped_transform_s!(is_not_s!("&"), '&',
+          alt!(
+              tag_s!("amp;")    => { |_| "&" }
+            | tag_s!("egrave;") => { |_| "è" }
+          )));
+
+        assert_eq!(unescape("ab&egrave;D&amp;EF"), IResult::Done("", Cow::Owned(String::from("abèD&EF"))));
+        assert_eq!(unescape("&egrave;"), IResult::Done("", Cow::Owned(String::from("è"))));
+        assert_eq!(unescape("&unknown;"), IResult::Error(error_node_position!(ErrorKind::EscapedTransform, "&unknown;",
+          error_position!(ErrorKind::Alt, "unknown;"))));
+
+        let input = "àbc";
+        match unescape(input) {
+            IResult::Done("", Cow::Borrowed(o)) => assert_eq!(o.as_ptr(), input.as_ptr()),
+            other => panic!("Parser `escaped_transform_s` should borrow input without escapes. \
+                             Got `{:?}`.", other),
+        }
+    }
+
+    #[test]
+    fn integer_str() {
+        named!(pair<&str, (u8, i32)>, separated_pair!(integer_s!(u8), tag_s!(":"), integer_s!(i32, 16)));
+
+        assert_eq!(pair("0b11:-ff "), IResult::Done(" ", (3, -255)));
+    }
+
+    #[test]
+    fn tag_nocase_str() {
+        named!(test<&str, &str>, tag_nocase_s!("ÉTÉ"));
+        named!(sigma<&str, &str>, tag_nocase_s!("ΣΑΣ"));
+        named!(kelvin<&str, &str>, tag_nocase_s!("k"));
+
+        assert_eq!(test("été!"), IResult::Done("!", "été"));
+        assert_eq!(test("ÉtÉ!"), IResult::Done("!", "ÉtÉ"));
+        assert_eq!(test("eté!"), IResult::Error(error_position!(ErrorKind::TagStr, "eté!")));
+        assert_eq!(test("ét"), IResult::Incomplete(Needed::Size(5)));
+        assert_eq!(sigma("σας"), IResult::Done("", "σας"));
+        assert_eq!(kelvin("\u{212a}m"), IResult::Done("m", "\u{212a}"));
+    }
+
+    #[test]
+    fn is_a_take_until_nocase_str() {
+        named!(hex<&str, &str>, is_a_nocase_s!("0123456789abcdef"));
+        named!(until<&str, &str>, take_until_nocase_s!("straße"));
+
+        assert_eq!(hex("fF0Ag"), IResult::Done("g", "fF0A"));
+        assert_eq!(hex("g"), IResult::Error(error_position!(ErrorKind::IsAStr, "g")));
+        assert_eq!(until("à la STRASE ou à la STRASSE"), IResult::Done("STRASSE", "à la STRASE ou à la "));
+        assert_eq!(until("à la STRAßE"), IResult::Done("STRAßE", "à la "));
+        assert_eq!(until("à la STRASE"), IResult::Error(error_position!(ErrorKind::TakeUntilStr, "à la STRASE")));
+        assert_eq!(until("à"), IResult::Incomplete(Needed::Size(7)));
+    }
 
     #[test]
     fn tag_str_succeed() {
@@ -0,0 +1,299 @@
+//! Unicode character classes and identifiers for string parsers
+//!
+//! The functions of the `nom` module check ASCII bytes. This module classifies
+//! characters with the Unicode tables: identifiers following the XID_Start and
+//! XID_Continue properties (as Rust and Python identifiers do), whitespace,
+//! line terminators, and general categories.
+
+use internal::IResult;
+use internal::IResult::*;
+use util::ErrorKind;
+use unicode_tables::{XID_START, XID_CONTINUE, CATEGORIES};
+
+/// Major Unicode general category of a character
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum UnicodeCategory {
+  /// Lu, Ll, Lt, Lm, Lo
+  Letter,
+  /// Mn, Mc, Me
+  Mark,
+  /// Nd, Nl, No
+  Number,
+  /// Pc, Pd, Ps, Pe, Pi, Pf, Po
+  Punctuation,
+  /// Sm, Sc, Sk, So
+  Symbol,
+  /// Zs, Zl, Zp
+  Separator,
+  /// Cc, Cf, Cs, Co, Cn: control, format, private use and unassigned characters
+  Other,
+}
+
+#[inline]
+fn in_table(c: char, table: &[(u32, u32)]) -> bool {
+  let c = c as u32;
+  table.binary_search_by(|&(start, end)| {
+    if end < c {
+      ::std::cmp::Ordering::Less
+    } else if start > c {
+      ::std::cmp::Ordering::Greater
+    } else {
+      ::std::cmp::Ordering::Equal
+    }
+  }).is_ok()
+}
+
+/// returns the general category of a character
+///
+/// ```
+/// # use nom::{category,UnicodeCategory};
+/// assert_eq!(category('é'), UnicodeCategory::Letter);
+/// assert_eq!(category('٣'), UnicodeCategory::Number);
+/// assert_eq!(category('«'), UnicodeCategory::Punctuation);
+/// assert_eq!(category('€'), UnicodeCategory::Symbol);
+/// assert_eq!(category('\u{a0}'), UnicodeCategory::Separator);
+/// ```
+pub fn category(c: char) -> UnicodeCategory {
+  let cp = c as u32;
+  let found = CATEGORIES.binary_search_by(|&(start, end, _)| {
+    if end < cp {
+      ::std::cmp::Ordering::Less
+    } else if start > cp {
+      ::std::cmp::Ordering::Greater
+    } else {
+      ::std::cmp::Ordering::Equal
+    }
+  });
+
+  match found {
+    Ok(idx) => CATEGORIES[idx].2,
+    Err(_)  => UnicodeCategory::Other,
+  }
+}
+
+/// Tests if a character can start an identifier (XID_Start property)
+///
+/// `_` is not XID_Start, `identifier_s` accepts it as a first character
+#[inline]
+pub fn is_xid_start(c: char) -> bool {
+  if (c as u32) < 0x80 {
+    (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z')
+  } else {
+    in_table(c, XID_START)
+  }
+}
+
+/// Tests if a character can continue an identifier (XID_Continue property)
+#[inline]
+pub fn is_xid_continue(c: char) -> bool {
+  if (c as u32) < 0x80 {
+    (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9') || c == '_'
+  } else {
+    in_table(c, XID_CONTINUE)
+  }
+}
+
+/// Tests if a character is a letter (general category L)
+#[inline]
+pub fn is_letter(c: char) -> bool {
+  category(c) == UnicodeCategory::Letter
+}
+
+/// Tests if a character is a combining mark (general category M)
+#[inline]
+pub fn is_mark(c: char) -> bool {
+  category(c) == UnicodeCategory::Mark
+}
+
+/// Tests if a character is a number, decimal digit or not (general category N)
+#[inline]
+pub fn is_number(c: char) -> bool {
+  category(c) == UnicodeCategory::Number
+}
+
+/// Tests if a character is a punctuation mark (general category P)
+#[inline]
+pub fn is_punctuation(c: char) -> bool {
+  category(c) == UnicodeCategory::Punctuation
+}
+
+/// Tests if a character is a symbol (general category S)
+#[inline]
+pub fn is_symbol(c: char) -> bool {
+  category(c) == UnicodeCategory::Symbol
+}
+
+/// Tests if a character is a line terminator: LF, VT, FF, CR, NEL, LINE SEPARATOR or PARAGRAPH SEPARATOR
+#[inline]
+pub fn is_line_terminator(c: char) -> bool {
+  match c {
+    '\n' | '\u{b}' | '\u{c}' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}' => true,
+    _ => false,
+  }
+}
+
+/// Tests if a character is whitespace (White_Space property), but not a line terminator
+#[inline]
+pub fn is_unicode_space(c: char) -> bool {
+  c.is_whitespace() && !is_line_terminator(c)
+}
+
+/// returns the longest prefix of characters satisfying the predicate,
+/// or an error if there is none
+#[inline]
+fn take_while1_unicode<F: Fn(char) -> bool>(input: &str, kind: ErrorKind, predicate: F) -> IResult<&str, &str> {
+  match input.char_indices().find(|&(_, c)| !predicate(c)) {
+    Some((0, _))   => Error(error_position!(kind, input)),
+    Some((idx, _)) => Done(&input[idx..], &input[..idx]),
+    None if input.is_empty() => Error(error_position!(kind, input)),
+    None           => Done(&input[input.len()..], input),
+  }
+}
+
+/// Recognizes an identifier: a XID_Start character or `_`, followed by XID_Continue characters
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::identifier_s;
+/// assert_eq!(identifier_s("größe = 1"), Done(" = 1", "größe"));
+/// assert_eq!(identifier_s("_변수2;"), Done(";", "_변수2"));
+/// ```
+pub fn identifier_s(input: &str) -> IResult<&str, &str> {
+  match input.chars().next() {
+    Some(c) if c == '_' || is_xid_start(c) => {
+      let start = c.len_utf8();
+      let end = match input[start..].char_indices().find(|&(_, c)| !is_xid_continue(c)) {
+        Some((idx, _)) => start + idx,
+        None           => input.len(),
+      };
+      Done(&input[end..], &input[..end])
+    },
+    _ => Error(error_position!(ErrorKind::Identifier, input)),
+  }
+}
+
+/// Recognizes letters of any script (general category L)
+pub fn letter_s(input: &str) -> IResult<&str, &str> {
+  take_while1_unicode(input, ErrorKind::Alpha, is_letter)
+}
+
+/// Recognizes letters and numbers of any script (general categories L and N)
+pub fn letter_number_s(input: &str) -> IResult<&str, &str> {
+  take_while1_unicode(input, ErrorKind::AlphaNumeric, |c| {
+    match category(c) {
+      UnicodeCategory::Letter | UnicodeCategory::Number => true,
+      _ => false,
+    }
+  })
+}
+
+/// Recognizes whitespace, except line terminators, like spaces, tabulations or NO-BREAK SPACE
+pub fn unicode_space_s(input: &str) -> IResult<&str, &str> {
+  take_while1_unicode(input, ErrorKind::Space, is_unicode_space)
+}
+
+/// Recognizes whitespace, including line terminators
+pub fn unicode_multispace_s(input: &str) -> IResult<&str, &str> {
+  take_while1_unicode(input, ErrorKind::MultiSpace, char::is_whitespace)
+}
+
+/// Recognizes one line terminator, `\r\n` being a single line terminator
+///
+/// ```
+/// # use nom::IResult::Done;
+/// # use nom::line_terminator_s;
+/// assert_eq!(line_terminator_s("\r\nabc"), Done("abc", "\r\n"));
+/// assert_eq!(line_terminator_s("\u{2028}abc"), Done("abc", "\u{2028}"));
+/// ```
+pub fn line_terminator_s(input: &str) -> IResult<&str, &str> {
+  if input.starts_with("\r\n") {
+    return Done(&input[2..], &input[..2]);
+  }
+  match input.chars().next() {
+    Some(c) if is_line_terminator(c) => Done(&input[c.len_utf8()..], &input[..c.len_utf8()]),
+    _ => Error(error_position!(ErrorKind::CrLf, input)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use internal::IResult::*;
+  use util::ErrorKind;
+
+  #[test]
+  fn xid() {
+    assert!(is_xid_start('a'));
+    assert!(is_xid_start('É'));
+    assert!(is_xid_start('λ'));
+    assert!(is_xid_start('変'));
+    assert!(is_xid_start('ℕ'));
+    assert!(!is_xid_start('_'));
+    assert!(!is_xid_start('1'));
+    assert!(!is_xid_start('\u{301}'));
+    assert!(!is_xid_start('€'));
+
+    assert!(is_xid_continue('_'));
+    assert!(is_xid_continue('9'));
+    assert!(is_xid_continue('\u{301}'));
+    assert!(is_xid_continue('٣'));
+    assert!(!is_xid_continue('-'));
+    assert!(!is_xid_continue(' '));
+    assert!(!is_xid_continue('\u{a0}'));
+  }
+
+  #[test]
+  fn categories() {
+    assert_eq!(category('a'), UnicodeCategory::Letter);
+    assert_eq!(category('ǅ'), UnicodeCategory::Letter);
+    assert_eq!(category('\u{301}'), UnicodeCategory::Mark);
+    assert_eq!(category('Ⅻ'), UnicodeCategory::Number);
+    assert_eq!(category('½'), UnicodeCategory::Number);
+    assert_eq!(category('_'), UnicodeCategory::Punctuation);
+    assert_eq!(category('+'), UnicodeCategory::Symbol);
+    assert_eq!(category(' '), UnicodeCategory::Separator);
+    assert_eq!(category('\u{2029}'), UnicodeCategory::Separator);
+    assert_eq!(category('\n'), UnicodeCategory::Other);
+    assert_eq!(category('\u{e000}'), UnicodeCategory::Other);
+    assert_eq!(category('\u{10ffff}'), UnicodeCategory::Other);
+
+    assert!(is_letter('ß') && is_mark('\u{94d}') && is_number('7') && is_punctuation('¿') && is_symbol('∑'));
+  }
+
+  #[test]
+  fn identifiers() {
+    assert_eq!(identifier_s("abc_1 x"), Done(" x", "abc_1"));
+    assert_eq!(identifier_s("_"), Done("", "_"));
+    assert_eq!(identifier_s("é"), Done("", "é"));
+    assert_eq!(identifier_s("cafe\u{301}!"), Done("!", "cafe\u{301}"));
+    assert_eq!(identifier_s("Δx+1"), Done("+1", "Δx"));
+    assert_eq!(identifier_s("1abc"), Error(error_position!(ErrorKind::Identifier, "1abc")));
+    assert_eq!(identifier_s("\u{301}a"), Error(error_position!(ErrorKind::Identifier, "\u{301}a")));
+    assert_eq!(identifier_s(""), Error(error_position!(ErrorKind::Identifier, "")));
+  }
+
+  #[test]
+  fn classes() {
+    assert_eq!(letter_s("Straße1"), Done("1", "Straße"));
+    assert_eq!(letter_s("1"), Error(error_position!(ErrorKind::Alpha, "1")));
+    assert_eq!(letter_number_s("x٣½ "), Done(" ", "x٣½"));
+    assert_eq!(unicode_space_s(" \t\u{a0}\u{3000}\nx"), Done("\nx", " \t\u{a0}\u{3000}"));
+    assert_eq!(unicode_space_s("\n"), Error(error_position!(ErrorKind::Space, "\n")));
+    assert_eq!(unicode_multispace_s(" \r\n\u{2028}x"), Done("x", " \r\n\u{2028}"));
+    assert_eq!(line_terminator_s("\r\r\n"), Done("\r\n", "\r"));
+    assert_eq!(line_terminator_s("\u{85}"), Done("", "\u{85}"));
+    assert_eq!(line_terminator_s("a"), Error(error_position!(ErrorKind::CrLf, "a")));
+  }
+
+  #[test]
+  fn tables_are_sorted() {
+    for table in [XID_START, XID_CONTINUE].iter() {
+      for w in table.windows(2) {
+        assert!(w[0].0 <= w[0].1 && w[0].1 < w[1].0);
+      }
+    }
+    for w in CATEGORIES.windows(2) {
+      assert!(w[0].0 <= w[0].1 && w[0].1 < w[1].0);
+    }
+  }
+}
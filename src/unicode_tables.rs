@@ -0,0 +1,904 @@
+//! Unicode tables used by the `unicode` module
+//!
+//! Generated from the Unicode 14.0.0 character database: XID_Start and XID_Continue
+//! ranges, the ranges of each general category, except Other (Cc, Cf, Cs, Co, Cn),
+//! and the full case folding of the characters for which it differs from the lowercase
+//! mapping. Ranges are inclusive and sorted.
+
+use unicode::UnicodeCategory;
+use unicode::UnicodeCategory::*;
+
+pub static XID_START: &'static [(u32, u32)] = &[
+  (0x41, 0x5a), (0x61, 0x7a), (0xaa, 0xaa), (0xb5, 0xb5), (0xba, 0xba), (0xc0, 0xd6), (0xd8, 0xf6),
+  (0xf8, 0x2c1), (0x2c6, 0x2d1), (0x2e0, 0x2e4), (0x2ec, 0x2ec), (0x2ee, 0x2ee), (0x370, 0x374),
+  (0x376, 0x377), (0x37b, 0x37d), (0x37f, 0x37f), (0x386, 0x386), (0x388, 0x38a), (0x38c, 0x38c),
+  (0x38e, 0x3a1), (0x3a3, 0x3f5), (0x3f7, 0x481), (0x48a, 0x52f), (0x531, 0x556), (0x559, 0x559),
+  (0x560, 0x588), (0x5d0, 0x5ea), (0x5ef, 0x5f2), (0x620, 0x64a), (0x66e, 0x66f), (0x671, 0x6d3),
+  (0x6d5, 0x6d5), (0x6e5, 0x6e6), (0x6ee, 0x6ef), (0x6fa, 0x6fc), (0x6ff, 0x6ff), (0x710, 0x710),
+  (0x712, 0x72f), (0x74d, 0x7a5), (0x7b1, 0x7b1), (0x7ca, 0x7ea), (0x7f4, 0x7f5), (0x7fa, 0x7fa),
+  (0x800, 0x815), (0x81a, 0x81a), (0x824, 0x824), (0x828, 0x828), (0x840, 0x858), (0x860, 0x86a),
+  (0x870, 0x887), (0x889, 0x88e), (0x8a0, 0x8c9), (0x904, 0x939), (0x93d, 0x93d), (0x950, 0x950),
+  (0x958, 0x961), (0x971, 0x980), (0x985, 0x98c), (0x98f, 0x990), (0x993, 0x9a8), (0x9aa, 0x9b0),
+  (0x9b2, 0x9b2), (0x9b6, 0x9b9), (0x9bd, 0x9bd), (0x9ce, 0x9ce), (0x9dc, 0x9dd), (0x9df, 0x9e1),
+  (0x9f0, 0x9f1), (0x9fc, 0x9fc), (0xa05, 0xa0a), (0xa0f, 0xa10), (0xa13, 0xa28), (0xa2a, 0xa30),
+  (0xa32, 0xa33), (0xa35, 0xa36), (0xa38, 0xa39), (0xa59, 0xa5c), (0xa5e, 0xa5e), (0xa72, 0xa74),
+  (0xa85, 0xa8d), (0xa8f, 0xa91), (0xa93, 0xaa8), (0xaaa, 0xab0), (0xab2, 0xab3), (0xab5, 0xab9),
+  (0xabd, 0xabd), (0xad0, 0xad0), (0xae0, 0xae1), (0xaf9, 0xaf9), (0xb05, 0xb0c), (0xb0f, 0xb10),
+  (0xb13, 0xb28), (0xb2a, 0xb30), (0xb32, 0xb33), (0xb35, 0xb39), (0xb3d, 0xb3d), (0xb5c, 0xb5d),
+  (0xb5f, 0xb61), (0xb71, 0xb71), (0xb83, 0xb83), (0xb85, 0xb8a), (0xb8e, 0xb90), (0xb92, 0xb95),
+  (0xb99, 0xb9a), (0xb9c, 0xb9c), (0xb9e, 0xb9f), (0xba3, 0xba4), (0xba8, 0xbaa), (0xbae, 0xbb9),
+  (0xbd0, 0xbd0), (0xc05, 0xc0c), (0xc0e, 0xc10), (0xc12, 0xc28), (0xc2a, 0xc39), (0xc3d, 0xc3d),
+  (0xc58, 0xc5a), (0xc5d, 0xc5d), (0xc60, 0xc61), (0xc80, 0xc80), (0xc85, 0xc8c), (0xc8e, 0xc90),
+  (0xc92, 0xca8), (0xcaa, 0xcb3), (0xcb5, 0xcb9), (0xcbd, 0xcbd), (0xcdd, 0xcde), (0xce0, 0xce1),
+  (0xcf1, 0xcf2), (0xd04, 0xd0c), (0xd0e, 0xd10), (0xd12, 0xd3a), (0xd3d, 0xd3d), (0xd4e, 0xd4e),
+  (0xd54, 0xd56), (0xd5f, 0xd61), (0xd7a, 0xd7f), (0xd85, 0xd96), (0xd9a, 0xdb1), (0xdb3, 0xdbb),
+  (0xdbd, 0xdbd), (0xdc0, 0xdc6), (0xe01, 0xe30), (0xe32, 0xe32), (0xe40, 0xe46), (0xe81, 0xe82),
+  (0xe84, 0xe84), (0xe86, 0xe8a), (0xe8c, 0xea3), (0xea5, 0xea5), (0xea7, 0xeb0), (0xeb2, 0xeb2),
+  (0xebd, 0xebd), (0xec0, 0xec4), (0xec6, 0xec6), (0xedc, 0xedf), (0xf00, 0xf00), (0xf40, 0xf47),
+  (0xf49, 0xf6c), (0xf88, 0xf8c), (0x1000, 0x102a), (0x103f, 0x103f), (0x1050, 0x1055),
+  (0x105a, 0x105d), (0x1061, 0x1061), (0x1065, 0x1066), (0x106e, 0x1070), (0x1075, 0x1081),
+  (0x108e, 0x108e), (0x10a0, 0x10c5), (0x10c7, 0x10c7), (0x10cd, 0x10cd), (0x10d0, 0x10fa),
+  (0x10fc, 0x1248), (0x124a, 0x124d), (0x1250, 0x1256), (0x1258, 0x1258), (0x125a, 0x125d),
+  (0x1260, 0x1288), (0x128a, 0x128d), (0x1290, 0x12b0), (0x12b2, 0x12b5), (0x12b8, 0x12be),
+  (0x12c0, 0x12c0), (0x12c2, 0x12c5), (0x12c8, 0x12d6), (0x12d8, 0x1310), (0x1312, 0x1315),
+  (0x1318, 0x135a), (0x1380, 0x138f), (0x13a0, 0x13f5), (0x13f8, 0x13fd), (0x1401, 0x166c),
+  (0x166f, 0x167f), (0x1681, 0x169a), (0x16a0, 0x16ea), (0x16ee, 0x16f8), (0x1700, 0x1711),
+  (0x171f, 0x1731), (0x1740, 0x1751), (0x1760, 0x176c), (0x176e, 0x1770), (0x1780, 0x17b3),
+  (0x17d7, 0x17d7), (0x17dc, 0x17dc), (0x1820, 0x1878), (0x1880, 0x18a8), (0x18aa, 0x18aa),
+  (0x18b0, 0x18f5), (0x1900, 0x191e), (0x1950, 0x196d), (0x1970, 0x1974), (0x1980, 0x19ab),
+  (0x19b0, 0x19c9), (0x1a00, 0x1a16), (0x1a20, 0x1a54), (0x1aa7, 0x1aa7), (0x1b05, 0x1b33),
+  (0x1b45, 0x1b4c), (0x1b83, 0x1ba0), (0x1bae, 0x1baf), (0x1bba, 0x1be5), (0x1c00, 0x1c23),
+  (0x1c4d, 0x1c4f), (0x1c5a, 0x1c7d), (0x1c80, 0x1c88), (0x1c90, 0x1cba), (0x1cbd, 0x1cbf),
+  (0x1ce9, 0x1cec), (0x1cee, 0x1cf3), (0x1cf5, 0x1cf6), (0x1cfa, 0x1cfa), (0x1d00, 0x1dbf),
+  (0x1e00, 0x1f15), (0x1f18, 0x1f1d), (0x1f20, 0x1f45), (0x1f48, 0x1f4d), (0x1f50, 0x1f57),
+  (0x1f59, 0x1f59), (0x1f5b, 0x1f5b), (0x1f5d, 0x1f5d), (0x1f5f, 0x1f7d), (0x1f80, 0x1fb4),
+  (0x1fb6, 0x1fbc), (0x1fbe, 0x1fbe), (0x1fc2, 0x1fc4), (0x1fc6, 0x1fcc), (0x1fd0, 0x1fd3),
+  (0x1fd6, 0x1fdb), (0x1fe0, 0x1fec), (0x1ff2, 0x1ff4), (0x1ff6, 0x1ffc), (0x2071, 0x2071),
+  (0x207f, 0x207f), (0x2090, 0x209c), (0x2102, 0x2102), (0x2107, 0x2107), (0x210a, 0x2113),
+  (0x2115, 0x2115), (0x2118, 0x211d), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128),
+  (0x212a, 0x2139), (0x213c, 0x213f), (0x2145, 0x2149), (0x214e, 0x214e), (0x2160, 0x2188),
+  (0x2c00, 0x2ce4), (0x2ceb, 0x2cee), (0x2cf2, 0x2cf3), (0x2d00, 0x2d25), (0x2d27, 0x2d27),
+  (0x2d2d, 0x2d2d), (0x2d30, 0x2d67), (0x2d6f, 0x2d6f), (0x2d80, 0x2d96), (0x2da0, 0x2da6),
+  (0x2da8, 0x2dae), (0x2db0, 0x2db6), (0x2db8, 0x2dbe), (0x2dc0, 0x2dc6), (0x2dc8, 0x2dce),
+  (0x2dd0, 0x2dd6), (0x2dd8, 0x2dde), (0x3005, 0x3007), (0x3021, 0x3029), (0x3031, 0x3035),
+  (0x3038, 0x303c), (0x3041, 0x3096), (0x309d, 0x309f), (0x30a1, 0x30fa), (0x30fc, 0x30ff),
+  (0x3105, 0x312f), (0x3131, 0x318e), (0x31a0, 0x31bf), (0x31f0, 0x31ff), (0x3400, 0x4dbf),
+  (0x4e00, 0xa48c), (0xa4d0, 0xa4fd), (0xa500, 0xa60c), (0xa610, 0xa61f), (0xa62a, 0xa62b),
+  (0xa640, 0xa66e), (0xa67f, 0xa69d), (0xa6a0, 0xa6ef), (0xa717, 0xa71f), (0xa722, 0xa788),
+  (0xa78b, 0xa7ca), (0xa7d0, 0xa7d1), (0xa7d3, 0xa7d3), (0xa7d5, 0xa7d9), (0xa7f2, 0xa801),
+  (0xa803, 0xa805), (0xa807, 0xa80a), (0xa80c, 0xa822), (0xa840, 0xa873), (0xa882, 0xa8b3),
+  (0xa8f2, 0xa8f7), (0xa8fb, 0xa8fb), (0xa8fd, 0xa8fe), (0xa90a, 0xa925), (0xa930, 0xa946),
+  (0xa960, 0xa97c), (0xa984, 0xa9b2), (0xa9cf, 0xa9cf), (0xa9e0, 0xa9e4), (0xa9e6, 0xa9ef),
+  (0xa9fa, 0xa9fe), (0xaa00, 0xaa28), (0xaa40, 0xaa42), (0xaa44, 0xaa4b), (0xaa60, 0xaa76),
+  (0xaa7a, 0xaa7a), (0xaa7e, 0xaaaf), (0xaab1, 0xaab1), (0xaab5, 0xaab6), (0xaab9, 0xaabd),
+  (0xaac0, 0xaac0), (0xaac2, 0xaac2), (0xaadb, 0xaadd), (0xaae0, 0xaaea), (0xaaf2, 0xaaf4),
+  (0xab01, 0xab06), (0xab09, 0xab0e), (0xab11, 0xab16), (0xab20, 0xab26), (0xab28, 0xab2e),
+  (0xab30, 0xab5a), (0xab5c, 0xab69), (0xab70, 0xabe2), (0xac00, 0xd7a3), (0xd7b0, 0xd7c6),
+  (0xd7cb, 0xd7fb), (0xf900, 0xfa6d), (0xfa70, 0xfad9), (0xfb00, 0xfb06), (0xfb13, 0xfb17),
+  (0xfb1d, 0xfb1d), (0xfb1f, 0xfb28), (0xfb2a, 0xfb36), (0xfb38, 0xfb3c), (0xfb3e, 0xfb3e),
+  (0xfb40, 0xfb41), (0xfb43, 0xfb44), (0xfb46, 0xfbb1), (0xfbd3, 0xfc5d), (0xfc64, 0xfd3d),
+  (0xfd50, 0xfd8f), (0xfd92, 0xfdc7), (0xfdf0, 0xfdf9), (0xfe71, 0xfe71), (0xfe73, 0xfe73),
+  (0xfe77, 0xfe77), (0xfe79, 0xfe79), (0xfe7b, 0xfe7b), (0xfe7d, 0xfe7d), (0xfe7f, 0xfefc),
+  (0xff21, 0xff3a), (0xff41, 0xff5a), (0xff66, 0xff9d), (0xffa0, 0xffbe), (0xffc2, 0xffc7),
+  (0xffca, 0xffcf), (0xffd2, 0xffd7), (0xffda, 0xffdc), (0x10000, 0x1000b), (0x1000d, 0x10026),
+  (0x10028, 0x1003a), (0x1003c, 0x1003d), (0x1003f, 0x1004d), (0x10050, 0x1005d),
+  (0x10080, 0x100fa), (0x10140, 0x10174), (0x10280, 0x1029c), (0x102a0, 0x102d0),
+  (0x10300, 0x1031f), (0x1032d, 0x1034a), (0x10350, 0x10375), (0x10380, 0x1039d),
+  (0x103a0, 0x103c3), (0x103c8, 0x103cf), (0x103d1, 0x103d5), (0x10400, 0x1049d),
+  (0x104b0, 0x104d3), (0x104d8, 0x104fb), (0x10500, 0x10527), (0x10530, 0x10563),
+  (0x10570, 0x1057a), (0x1057c, 0x1058a), (0x1058c, 0x10592), (0x10594, 0x10595),
+  (0x10597, 0x105a1), (0x105a3, 0x105b1), (0x105b3, 0x105b9), (0x105bb, 0x105bc),
+  (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785),
+  (0x10787, 0x107b0), (0x107b2, 0x107ba), (0x10800, 0x10805), (0x10808, 0x10808),
+  (0x1080a, 0x10835), (0x10837, 0x10838), (0x1083c, 0x1083c), (0x1083f, 0x10855),
+  (0x10860, 0x10876), (0x10880, 0x1089e), (0x108e0, 0x108f2), (0x108f4, 0x108f5),
+  (0x10900, 0x10915), (0x10920, 0x10939), (0x10980, 0x109b7), (0x109be, 0x109bf),
+  (0x10a00, 0x10a00), (0x10a10, 0x10a13), (0x10a15, 0x10a17), (0x10a19, 0x10a35),
+  (0x10a60, 0x10a7c), (0x10a80, 0x10a9c), (0x10ac0, 0x10ac7), (0x10ac9, 0x10ae4),
+  (0x10b00, 0x10b35), (0x10b40, 0x10b55), (0x10b60, 0x10b72), (0x10b80, 0x10b91),
+  (0x10c00, 0x10c48), (0x10c80, 0x10cb2), (0x10cc0, 0x10cf2), (0x10d00, 0x10d23),
+  (0x10e80, 0x10ea9), (0x10eb0, 0x10eb1), (0x10f00, 0x10f1c), (0x10f27, 0x10f27),
+  (0x10f30, 0x10f45), (0x10f70, 0x10f81), (0x10fb0, 0x10fc4), (0x10fe0, 0x10ff6),
+  (0x11003, 0x11037), (0x11071, 0x11072), (0x11075, 0x11075), (0x11083, 0x110af),
+  (0x110d0, 0x110e8), (0x11103, 0x11126), (0x11144, 0x11144), (0x11147, 0x11147),
+  (0x11150, 0x11172), (0x11176, 0x11176), (0x11183, 0x111b2), (0x111c1, 0x111c4),
+  (0x111da, 0x111da), (0x111dc, 0x111dc), (0x11200, 0x11211), (0x11213, 0x1122b),
+  (0x11280, 0x11286), (0x11288, 0x11288), (0x1128a, 0x1128d), (0x1128f, 0x1129d),
+  (0x1129f, 0x112a8), (0x112b0, 0x112de), (0x11305, 0x1130c), (0x1130f, 0x11310),
+  (0x11313, 0x11328), (0x1132a, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339),
+  (0x1133d, 0x1133d), (0x11350, 0x11350), (0x1135d, 0x11361), (0x11400, 0x11434),
+  (0x11447, 0x1144a), (0x1145f, 0x11461), (0x11480, 0x114af), (0x114c4, 0x114c5),
+  (0x114c7, 0x114c7), (0x11580, 0x115ae), (0x115d8, 0x115db), (0x11600, 0x1162f),
+  (0x11644, 0x11644), (0x11680, 0x116aa), (0x116b8, 0x116b8), (0x11700, 0x1171a),
+  (0x11740, 0x11746), (0x11800, 0x1182b), (0x118a0, 0x118df), (0x118ff, 0x11906),
+  (0x11909, 0x11909), (0x1190c, 0x11913), (0x11915, 0x11916), (0x11918, 0x1192f),
+  (0x1193f, 0x1193f), (0x11941, 0x11941), (0x119a0, 0x119a7), (0x119aa, 0x119d0),
+  (0x119e1, 0x119e1), (0x119e3, 0x119e3), (0x11a00, 0x11a00), (0x11a0b, 0x11a32),
+  (0x11a3a, 0x11a3a), (0x11a50, 0x11a50), (0x11a5c, 0x11a89), (0x11a9d, 0x11a9d),
+  (0x11ab0, 0x11af8), (0x11c00, 0x11c08), (0x11c0a, 0x11c2e), (0x11c40, 0x11c40),
+  (0x11c72, 0x11c8f), (0x11d00, 0x11d06), (0x11d08, 0x11d09), (0x11d0b, 0x11d30),
+  (0x11d46, 0x11d46), (0x11d60, 0x11d65), (0x11d67, 0x11d68), (0x11d6a, 0x11d89),
+  (0x11d98, 0x11d98), (0x11ee0, 0x11ef2), (0x11fb0, 0x11fb0), (0x12000, 0x12399),
+  (0x12400, 0x1246e), (0x12480, 0x12543), (0x12f90, 0x12ff0), (0x13000, 0x1342e),
+  (0x14400, 0x14646), (0x16800, 0x16a38), (0x16a40, 0x16a5e), (0x16a70, 0x16abe),
+  (0x16ad0, 0x16aed), (0x16b00, 0x16b2f), (0x16b40, 0x16b43), (0x16b63, 0x16b77),
+  (0x16b7d, 0x16b8f), (0x16e40, 0x16e7f), (0x16f00, 0x16f4a), (0x16f50, 0x16f50),
+  (0x16f93, 0x16f9f), (0x16fe0, 0x16fe1), (0x16fe3, 0x16fe3), (0x17000, 0x187f7),
+  (0x18800, 0x18cd5), (0x18d00, 0x18d08), (0x1aff0, 0x1aff3), (0x1aff5, 0x1affb),
+  (0x1affd, 0x1affe), (0x1b000, 0x1b122), (0x1b150, 0x1b152), (0x1b164, 0x1b167),
+  (0x1b170, 0x1b2fb), (0x1bc00, 0x1bc6a), (0x1bc70, 0x1bc7c), (0x1bc80, 0x1bc88),
+  (0x1bc90, 0x1bc99), (0x1d400, 0x1d454), (0x1d456, 0x1d49c), (0x1d49e, 0x1d49f),
+  (0x1d4a2, 0x1d4a2), (0x1d4a5, 0x1d4a6), (0x1d4a9, 0x1d4ac), (0x1d4ae, 0x1d4b9),
+  (0x1d4bb, 0x1d4bb), (0x1d4bd, 0x1d4c3), (0x1d4c5, 0x1d505), (0x1d507, 0x1d50a),
+  (0x1d50d, 0x1d514), (0x1d516, 0x1d51c), (0x1d51e, 0x1d539), (0x1d53b, 0x1d53e),
+  (0x1d540, 0x1d544), (0x1d546, 0x1d546), (0x1d54a, 0x1d550), (0x1d552, 0x1d6a5),
+  (0x1d6a8, 0x1d6c0), (0x1d6c2, 0x1d6da), (0x1d6dc, 0x1d6fa), (0x1d6fc, 0x1d714),
+  (0x1d716, 0x1d734), (0x1d736, 0x1d74e), (0x1d750, 0x1d76e), (0x1d770, 0x1d788),
+  (0x1d78a, 0x1d7a8), (0x1d7aa, 0x1d7c2), (0x1d7c4, 0x1d7cb), (0x1df00, 0x1df1e),
+  (0x1e100, 0x1e12c), (0x1e137, 0x1e13d), (0x1e14e, 0x1e14e), (0x1e290, 0x1e2ad),
+  (0x1e2c0, 0x1e2eb), (0x1e7e0, 0x1e7e6), (0x1e7e8, 0x1e7eb), (0x1e7ed, 0x1e7ee),
+  (0x1e7f0, 0x1e7fe), (0x1e800, 0x1e8c4), (0x1e900, 0x1e943), (0x1e94b, 0x1e94b),
+  (0x1ee00, 0x1ee03), (0x1ee05, 0x1ee1f), (0x1ee21, 0x1ee22), (0x1ee24, 0x1ee24),
+  (0x1ee27, 0x1ee27), (0x1ee29, 0x1ee32), (0x1ee34, 0x1ee37), (0x1ee39, 0x1ee39),
+  (0x1ee3b, 0x1ee3b), (0x1ee42, 0x1ee42), (0x1ee47, 0x1ee47), (0x1ee49, 0x1ee49),
+  (0x1ee4b, 0x1ee4b), (0x1ee4d, 0x1ee4f), (0x1ee51, 0x1ee52), (0x1ee54, 0x1ee54),
+  (0x1ee57, 0x1ee57), (0x1ee59, 0x1ee59), (0x1ee5b, 0x1ee5b), (0x1ee5d, 0x1ee5d),
+  (0x1ee5f, 0x1ee5f), (0x1ee61, 0x1ee62), (0x1ee64, 0x1ee64), (0x1ee67, 0x1ee6a),
+  (0x1ee6c, 0x1ee72), (0x1ee74, 0x1ee77), (0x1ee79, 0x1ee7c), (0x1ee7e, 0x1ee7e),
+  (0x1ee80, 0x1ee89), (0x1ee8b, 0x1ee9b), (0x1eea1, 0x1eea3), (0x1eea5, 0x1eea9),
+  (0x1eeab, 0x1eebb), (0x20000, 0x2a6df), (0x2a700, 0x2b738), (0x2b740, 0x2b81d),
+  (0x2b820, 0x2cea1), (0x2ceb0, 0x2ebe0), (0x2f800, 0x2fa1d), (0x30000, 0x3134a),
+];
+
+pub static XID_CONTINUE: &'static [(u32, u32)] = &[
+  (0x30, 0x39), (0x41, 0x5a), (0x5f, 0x5f), (0x61, 0x7a), (0xaa, 0xaa), (0xb5, 0xb5), (0xb7, 0xb7),
+  (0xba, 0xba), (0xc0, 0xd6), (0xd8, 0xf6), (0xf8, 0x2c1), (0x2c6, 0x2d1), (0x2e0, 0x2e4),
+  (0x2ec, 0x2ec), (0x2ee, 0x2ee), (0x300, 0x374), (0x376, 0x377), (0x37b, 0x37d), (0x37f, 0x37f),
+  (0x386, 0x38a), (0x38c, 0x38c), (0x38e, 0x3a1), (0x3a3, 0x3f5), (0x3f7, 0x481), (0x483, 0x487),
+  (0x48a, 0x52f), (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x591, 0x5bd), (0x5bf, 0x5bf),
+  (0x5c1, 0x5c2), (0x5c4, 0x5c5), (0x5c7, 0x5c7), (0x5d0, 0x5ea), (0x5ef, 0x5f2), (0x610, 0x61a),
+  (0x620, 0x669), (0x66e, 0x6d3), (0x6d5, 0x6dc), (0x6df, 0x6e8), (0x6ea, 0x6fc), (0x6ff, 0x6ff),
+  (0x710, 0x74a), (0x74d, 0x7b1), (0x7c0, 0x7f5), (0x7fa, 0x7fa), (0x7fd, 0x7fd), (0x800, 0x82d),
+  (0x840, 0x85b), (0x860, 0x86a), (0x870, 0x887), (0x889, 0x88e), (0x898, 0x8e1), (0x8e3, 0x963),
+  (0x966, 0x96f), (0x971, 0x983), (0x985, 0x98c), (0x98f, 0x990), (0x993, 0x9a8), (0x9aa, 0x9b0),
+  (0x9b2, 0x9b2), (0x9b6, 0x9b9), (0x9bc, 0x9c4), (0x9c7, 0x9c8), (0x9cb, 0x9ce), (0x9d7, 0x9d7),
+  (0x9dc, 0x9dd), (0x9df, 0x9e3), (0x9e6, 0x9f1), (0x9fc, 0x9fc), (0x9fe, 0x9fe), (0xa01, 0xa03),
+  (0xa05, 0xa0a), (0xa0f, 0xa10), (0xa13, 0xa28), (0xa2a, 0xa30), (0xa32, 0xa33), (0xa35, 0xa36),
+  (0xa38, 0xa39), (0xa3c, 0xa3c), (0xa3e, 0xa42), (0xa47, 0xa48), (0xa4b, 0xa4d), (0xa51, 0xa51),
+  (0xa59, 0xa5c), (0xa5e, 0xa5e), (0xa66, 0xa75), (0xa81, 0xa83), (0xa85, 0xa8d), (0xa8f, 0xa91),
+  (0xa93, 0xaa8), (0xaaa, 0xab0), (0xab2, 0xab3), (0xab5, 0xab9), (0xabc, 0xac5), (0xac7, 0xac9),
+  (0xacb, 0xacd), (0xad0, 0xad0), (0xae0, 0xae3), (0xae6, 0xaef), (0xaf9, 0xaff), (0xb01, 0xb03),
+  (0xb05, 0xb0c), (0xb0f, 0xb10), (0xb13, 0xb28), (0xb2a, 0xb30), (0xb32, 0xb33), (0xb35, 0xb39),
+  (0xb3c, 0xb44), (0xb47, 0xb48), (0xb4b, 0xb4d), (0xb55, 0xb57), (0xb5c, 0xb5d), (0xb5f, 0xb63),
+  (0xb66, 0xb6f), (0xb71, 0xb71), (0xb82, 0xb83), (0xb85, 0xb8a), (0xb8e, 0xb90), (0xb92, 0xb95),
+  (0xb99, 0xb9a), (0xb9c, 0xb9c), (0xb9e, 0xb9f), (0xba3, 0xba4), (0xba8, 0xbaa), (0xbae, 0xbb9),
+  (0xbbe, 0xbc2), (0xbc6, 0xbc8), (0xbca, 0xbcd), (0xbd0, 0xbd0), (0xbd7, 0xbd7), (0xbe6, 0xbef),
+  (0xc00, 0xc0c), (0xc0e, 0xc10), (0xc12, 0xc28), (0xc2a, 0xc39), (0xc3c, 0xc44), (0xc46, 0xc48),
+  (0xc4a, 0xc4d), (0xc55, 0xc56), (0xc58, 0xc5a), (0xc5d, 0xc5d), (0xc60, 0xc63), (0xc66, 0xc6f),
+  (0xc80, 0xc83), (0xc85, 0xc8c), (0xc8e, 0xc90), (0xc92, 0xca8), (0xcaa, 0xcb3), (0xcb5, 0xcb9),
+  (0xcbc, 0xcc4), (0xcc6, 0xcc8), (0xcca, 0xccd), (0xcd5, 0xcd6), (0xcdd, 0xcde), (0xce0, 0xce3),
+  (0xce6, 0xcef), (0xcf1, 0xcf2), (0xd00, 0xd0c), (0xd0e, 0xd10), (0xd12, 0xd44), (0xd46, 0xd48),
+  (0xd4a, 0xd4e), (0xd54, 0xd57), (0xd5f, 0xd63), (0xd66, 0xd6f), (0xd7a, 0xd7f), (0xd81, 0xd83),
+  (0xd85, 0xd96), (0xd9a, 0xdb1), (0xdb3, 0xdbb), (0xdbd, 0xdbd), (0xdc0, 0xdc6), (0xdca, 0xdca),
+  (0xdcf, 0xdd4), (0xdd6, 0xdd6), (0xdd8, 0xddf), (0xde6, 0xdef), (0xdf2, 0xdf3), (0xe01, 0xe3a),
+  (0xe40, 0xe4e), (0xe50, 0xe59), (0xe81, 0xe82), (0xe84, 0xe84), (0xe86, 0xe8a), (0xe8c, 0xea3),
+  (0xea5, 0xea5), (0xea7, 0xebd), (0xec0, 0xec4), (0xec6, 0xec6), (0xec8, 0xecd), (0xed0, 0xed9),
+  (0xedc, 0xedf), (0xf00, 0xf00), (0xf18, 0xf19), (0xf20, 0xf29), (0xf35, 0xf35), (0xf37, 0xf37),
+  (0xf39, 0xf39), (0xf3e, 0xf47), (0xf49, 0xf6c), (0xf71, 0xf84), (0xf86, 0xf97), (0xf99, 0xfbc),
+  (0xfc6, 0xfc6), (0x1000, 0x1049), (0x1050, 0x109d), (0x10a0, 0x10c5), (0x10c7, 0x10c7),
+  (0x10cd, 0x10cd), (0x10d0, 0x10fa), (0x10fc, 0x1248), (0x124a, 0x124d), (0x1250, 0x1256),
+  (0x1258, 0x1258), (0x125a, 0x125d), (0x1260, 0x1288), (0x128a, 0x128d), (0x1290, 0x12b0),
+  (0x12b2, 0x12b5), (0x12b8, 0x12be), (0x12c0, 0x12c0), (0x12c2, 0x12c5), (0x12c8, 0x12d6),
+  (0x12d8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135a), (0x135d, 0x135f), (0x1369, 0x1371),
+  (0x1380, 0x138f), (0x13a0, 0x13f5), (0x13f8, 0x13fd), (0x1401, 0x166c), (0x166f, 0x167f),
+  (0x1681, 0x169a), (0x16a0, 0x16ea), (0x16ee, 0x16f8), (0x1700, 0x1715), (0x171f, 0x1734),
+  (0x1740, 0x1753), (0x1760, 0x176c), (0x176e, 0x1770), (0x1772, 0x1773), (0x1780, 0x17d3),
+  (0x17d7, 0x17d7), (0x17dc, 0x17dd), (0x17e0, 0x17e9), (0x180b, 0x180d), (0x180f, 0x1819),
+  (0x1820, 0x1878), (0x1880, 0x18aa), (0x18b0, 0x18f5), (0x1900, 0x191e), (0x1920, 0x192b),
+  (0x1930, 0x193b), (0x1946, 0x196d), (0x1970, 0x1974), (0x1980, 0x19ab), (0x19b0, 0x19c9),
+  (0x19d0, 0x19da), (0x1a00, 0x1a1b), (0x1a20, 0x1a5e), (0x1a60, 0x1a7c), (0x1a7f, 0x1a89),
+  (0x1a90, 0x1a99), (0x1aa7, 0x1aa7), (0x1ab0, 0x1abd), (0x1abf, 0x1ace), (0x1b00, 0x1b4c),
+  (0x1b50, 0x1b59), (0x1b6b, 0x1b73), (0x1b80, 0x1bf3), (0x1c00, 0x1c37), (0x1c40, 0x1c49),
+  (0x1c4d, 0x1c7d), (0x1c80, 0x1c88), (0x1c90, 0x1cba), (0x1cbd, 0x1cbf), (0x1cd0, 0x1cd2),
+  (0x1cd4, 0x1cfa), (0x1d00, 0x1f15), (0x1f18, 0x1f1d), (0x1f20, 0x1f45), (0x1f48, 0x1f4d),
+  (0x1f50, 0x1f57), (0x1f59, 0x1f59), (0x1f5b, 0x1f5b), (0x1f5d, 0x1f5d), (0x1f5f, 0x1f7d),
+  (0x1f80, 0x1fb4), (0x1fb6, 0x1fbc), (0x1fbe, 0x1fbe), (0x1fc2, 0x1fc4), (0x1fc6, 0x1fcc),
+  (0x1fd0, 0x1fd3), (0x1fd6, 0x1fdb), (0x1fe0, 0x1fec), (0x1ff2, 0x1ff4), (0x1ff6, 0x1ffc),
+  (0x203f, 0x2040), (0x2054, 0x2054), (0x2071, 0x2071), (0x207f, 0x207f), (0x2090, 0x209c),
+  (0x20d0, 0x20dc), (0x20e1, 0x20e1), (0x20e5, 0x20f0), (0x2102, 0x2102), (0x2107, 0x2107),
+  (0x210a, 0x2113), (0x2115, 0x2115), (0x2118, 0x211d), (0x2124, 0x2124), (0x2126, 0x2126),
+  (0x2128, 0x2128), (0x212a, 0x2139), (0x213c, 0x213f), (0x2145, 0x2149), (0x214e, 0x214e),
+  (0x2160, 0x2188), (0x2c00, 0x2ce4), (0x2ceb, 0x2cf3), (0x2d00, 0x2d25), (0x2d27, 0x2d27),
+  (0x2d2d, 0x2d2d), (0x2d30, 0x2d67), (0x2d6f, 0x2d6f), (0x2d7f, 0x2d96), (0x2da0, 0x2da6),
+  (0x2da8, 0x2dae), (0x2db0, 0x2db6), (0x2db8, 0x2dbe), (0x2dc0, 0x2dc6), (0x2dc8, 0x2dce),
+  (0x2dd0, 0x2dd6), (0x2dd8, 0x2dde), (0x2de0, 0x2dff), (0x3005, 0x3007), (0x3021, 0x302f),
+  (0x3031, 0x3035), (0x3038, 0x303c), (0x3041, 0x3096), (0x3099, 0x309a), (0x309d, 0x309f),
+  (0x30a1, 0x30fa), (0x30fc, 0x30ff), (0x3105, 0x312f), (0x3131, 0x318e), (0x31a0, 0x31bf),
+  (0x31f0, 0x31ff), (0x3400, 0x4dbf), (0x4e00, 0xa48c), (0xa4d0, 0xa4fd), (0xa500, 0xa60c),
+  (0xa610, 0xa62b), (0xa640, 0xa66f), (0xa674, 0xa67d), (0xa67f, 0xa6f1), (0xa717, 0xa71f),
+  (0xa722, 0xa788), (0xa78b, 0xa7ca), (0xa7d0, 0xa7d1), (0xa7d3, 0xa7d3), (0xa7d5, 0xa7d9),
+  (0xa7f2, 0xa827), (0xa82c, 0xa82c), (0xa840, 0xa873), (0xa880, 0xa8c5), (0xa8d0, 0xa8d9),
+  (0xa8e0, 0xa8f7), (0xa8fb, 0xa8fb), (0xa8fd, 0xa92d), (0xa930, 0xa953), (0xa960, 0xa97c),
+  (0xa980, 0xa9c0), (0xa9cf, 0xa9d9), (0xa9e0, 0xa9fe), (0xaa00, 0xaa36), (0xaa40, 0xaa4d),
+  (0xaa50, 0xaa59), (0xaa60, 0xaa76), (0xaa7a, 0xaac2), (0xaadb, 0xaadd), (0xaae0, 0xaaef),
+  (0xaaf2, 0xaaf6), (0xab01, 0xab06), (0xab09, 0xab0e), (0xab11, 0xab16), (0xab20, 0xab26),
+  (0xab28, 0xab2e), (0xab30, 0xab5a), (0xab5c, 0xab69), (0xab70, 0xabea), (0xabec, 0xabed),
+  (0xabf0, 0xabf9), (0xac00, 0xd7a3), (0xd7b0, 0xd7c6), (0xd7cb, 0xd7fb), (0xf900, 0xfa6d),
+  (0xfa70, 0xfad9), (0xfb00, 0xfb06), (0xfb13, 0xfb17), (0xfb1d, 0xfb28), (0xfb2a, 0xfb36),
+  (0xfb38, 0xfb3c), (0xfb3e, 0xfb3e), (0xfb40, 0xfb41), (0xfb43, 0xfb44), (0xfb46, 0xfbb1),
+  (0xfbd3, 0xfc5d), (0xfc64, 0xfd3d), (0xfd50, 0xfd8f), (0xfd92, 0xfdc7), (0xfdf0, 0xfdf9),
+  (0xfe00, 0xfe0f), (0xfe20, 0xfe2f), (0xfe33, 0xfe34), (0xfe4d, 0xfe4f), (0xfe71, 0xfe71),
+  (0xfe73, 0xfe73), (0xfe77, 0xfe77), (0xfe79, 0xfe79), (0xfe7b, 0xfe7b), (0xfe7d, 0xfe7d),
+  (0xfe7f, 0xfefc), (0xff10, 0xff19), (0xff21, 0xff3a), (0xff3f, 0xff3f), (0xff41, 0xff5a),
+  (0xff66, 0xffbe), (0xffc2, 0xffc7), (0xffca, 0xffcf), (0xffd2, 0xffd7), (0xffda, 0xffdc),
+  (0x10000, 0x1000b), (0x1000d, 0x10026), (0x10028, 0x1003a), (0x1003c, 0x1003d),
+  (0x1003f, 0x1004d), (0x10050, 0x1005d), (0x10080, 0x100fa), (0x10140, 0x10174),
+  (0x101fd, 0x101fd), (0x10280, 0x1029c), (0x102a0, 0x102d0), (0x102e0, 0x102e0),
+  (0x10300, 0x1031f), (0x1032d, 0x1034a), (0x10350, 0x1037a), (0x10380, 0x1039d),
+  (0x103a0, 0x103c3), (0x103c8, 0x103cf), (0x103d1, 0x103d5), (0x10400, 0x1049d),
+  (0x104a0, 0x104a9), (0x104b0, 0x104d3), (0x104d8, 0x104fb), (0x10500, 0x10527),
+  (0x10530, 0x10563), (0x10570, 0x1057a), (0x1057c, 0x1058a), (0x1058c, 0x10592),
+  (0x10594, 0x10595), (0x10597, 0x105a1), (0x105a3, 0x105b1), (0x105b3, 0x105b9),
+  (0x105bb, 0x105bc), (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767),
+  (0x10780, 0x10785), (0x10787, 0x107b0), (0x107b2, 0x107ba), (0x10800, 0x10805),
+  (0x10808, 0x10808), (0x1080a, 0x10835), (0x10837, 0x10838), (0x1083c, 0x1083c),
+  (0x1083f, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089e), (0x108e0, 0x108f2),
+  (0x108f4, 0x108f5), (0x10900, 0x10915), (0x10920, 0x10939), (0x10980, 0x109b7),
+  (0x109be, 0x109bf), (0x10a00, 0x10a03), (0x10a05, 0x10a06), (0x10a0c, 0x10a13),
+  (0x10a15, 0x10a17), (0x10a19, 0x10a35), (0x10a38, 0x10a3a), (0x10a3f, 0x10a3f),
+  (0x10a60, 0x10a7c), (0x10a80, 0x10a9c), (0x10ac0, 0x10ac7), (0x10ac9, 0x10ae6),
+  (0x10b00, 0x10b35), (0x10b40, 0x10b55), (0x10b60, 0x10b72), (0x10b80, 0x10b91),
+  (0x10c00, 0x10c48), (0x10c80, 0x10cb2), (0x10cc0, 0x10cf2), (0x10d00, 0x10d27),
+  (0x10d30, 0x10d39), (0x10e80, 0x10ea9), (0x10eab, 0x10eac), (0x10eb0, 0x10eb1),
+  (0x10f00, 0x10f1c), (0x10f27, 0x10f27), (0x10f30, 0x10f50), (0x10f70, 0x10f85),
+  (0x10fb0, 0x10fc4), (0x10fe0, 0x10ff6), (0x11000, 0x11046), (0x11066, 0x11075),
+  (0x1107f, 0x110ba), (0x110c2, 0x110c2), (0x110d0, 0x110e8), (0x110f0, 0x110f9),
+  (0x11100, 0x11134), (0x11136, 0x1113f), (0x11144, 0x11147), (0x11150, 0x11173),
+  (0x11176, 0x11176), (0x11180, 0x111c4), (0x111c9, 0x111cc), (0x111ce, 0x111da),
+  (0x111dc, 0x111dc), (0x11200, 0x11211), (0x11213, 0x11237), (0x1123e, 0x1123e),
+  (0x11280, 0x11286), (0x11288, 0x11288), (0x1128a, 0x1128d), (0x1128f, 0x1129d),
+  (0x1129f, 0x112a8), (0x112b0, 0x112ea), (0x112f0, 0x112f9), (0x11300, 0x11303),
+  (0x11305, 0x1130c), (0x1130f, 0x11310), (0x11313, 0x11328), (0x1132a, 0x11330),
+  (0x11332, 0x11333), (0x11335, 0x11339), (0x1133b, 0x11344), (0x11347, 0x11348),
+  (0x1134b, 0x1134d), (0x11350, 0x11350), (0x11357, 0x11357), (0x1135d, 0x11363),
+  (0x11366, 0x1136c), (0x11370, 0x11374), (0x11400, 0x1144a), (0x11450, 0x11459),
+  (0x1145e, 0x11461), (0x11480, 0x114c5), (0x114c7, 0x114c7), (0x114d0, 0x114d9),
+  (0x11580, 0x115b5), (0x115b8, 0x115c0), (0x115d8, 0x115dd), (0x11600, 0x11640),
+  (0x11644, 0x11644), (0x11650, 0x11659), (0x11680, 0x116b8), (0x116c0, 0x116c9),
+  (0x11700, 0x1171a), (0x1171d, 0x1172b), (0x11730, 0x11739), (0x11740, 0x11746),
+  (0x11800, 0x1183a), (0x118a0, 0x118e9), (0x118ff, 0x11906), (0x11909, 0x11909),
+  (0x1190c, 0x11913), (0x11915, 0x11916), (0x11918, 0x11935), (0x11937, 0x11938),
+  (0x1193b, 0x11943), (0x11950, 0x11959), (0x119a0, 0x119a7), (0x119aa, 0x119d7),
+  (0x119da, 0x119e1), (0x119e3, 0x119e4), (0x11a00, 0x11a3e), (0x11a47, 0x11a47),
+  (0x11a50, 0x11a99), (0x11a9d, 0x11a9d), (0x11ab0, 0x11af8), (0x11c00, 0x11c08),
+  (0x11c0a, 0x11c36), (0x11c38, 0x11c40), (0x11c50, 0x11c59), (0x11c72, 0x11c8f),
+  (0x11c92, 0x11ca7), (0x11ca9, 0x11cb6), (0x11d00, 0x11d06), (0x11d08, 0x11d09),
+  (0x11d0b, 0x11d36), (0x11d3a, 0x11d3a), (0x11d3c, 0x11d3d), (0x11d3f, 0x11d47),
+  (0x11d50, 0x11d59), (0x11d60, 0x11d65), (0x11d67, 0x11d68), (0x11d6a, 0x11d8e),
+  (0x11d90, 0x11d91), (0x11d93, 0x11d98), (0x11da0, 0x11da9), (0x11ee0, 0x11ef6),
+  (0x11fb0, 0x11fb0), (0x12000, 0x12399), (0x12400, 0x1246e), (0x12480, 0x12543),
+  (0x12f90, 0x12ff0), (0x13000, 0x1342e), (0x14400, 0x14646), (0x16800, 0x16a38),
+  (0x16a40, 0x16a5e), (0x16a60, 0x16a69), (0x16a70, 0x16abe), (0x16ac0, 0x16ac9),
+  (0x16ad0, 0x16aed), (0x16af0, 0x16af4), (0x16b00, 0x16b36), (0x16b40, 0x16b43),
+  (0x16b50, 0x16b59), (0x16b63, 0x16b77), (0x16b7d, 0x16b8f), (0x16e40, 0x16e7f),
+  (0x16f00, 0x16f4a), (0x16f4f, 0x16f87), (0x16f8f, 0x16f9f), (0x16fe0, 0x16fe1),
+  (0x16fe3, 0x16fe4), (0x16ff0, 0x16ff1), (0x17000, 0x187f7), (0x18800, 0x18cd5),
+  (0x18d00, 0x18d08), (0x1aff0, 0x1aff3), (0x1aff5, 0x1affb), (0x1affd, 0x1affe),
+  (0x1b000, 0x1b122), (0x1b150, 0x1b152), (0x1b164, 0x1b167), (0x1b170, 0x1b2fb),
+  (0x1bc00, 0x1bc6a), (0x1bc70, 0x1bc7c), (0x1bc80, 0x1bc88), (0x1bc90, 0x1bc99),
+  (0x1bc9d, 0x1bc9e), (0x1cf00, 0x1cf2d), (0x1cf30, 0x1cf46), (0x1d165, 0x1d169),
+  (0x1d16d, 0x1d172), (0x1d17b, 0x1d182), (0x1d185, 0x1d18b), (0x1d1aa, 0x1d1ad),
+  (0x1d242, 0x1d244), (0x1d400, 0x1d454), (0x1d456, 0x1d49c), (0x1d49e, 0x1d49f),
+  (0x1d4a2, 0x1d4a2), (0x1d4a5, 0x1d4a6), (0x1d4a9, 0x1d4ac), (0x1d4ae, 0x1d4b9),
+  (0x1d4bb, 0x1d4bb), (0x1d4bd, 0x1d4c3), (0x1d4c5, 0x1d505), (0x1d507, 0x1d50a),
+  (0x1d50d, 0x1d514), (0x1d516, 0x1d51c), (0x1d51e, 0x1d539), (0x1d53b, 0x1d53e),
+  (0x1d540, 0x1d544), (0x1d546, 0x1d546), (0x1d54a, 0x1d550), (0x1d552, 0x1d6a5),
+  (0x1d6a8, 0x1d6c0), (0x1d6c2, 0x1d6da), (0x1d6dc, 0x1d6fa), (0x1d6fc, 0x1d714),
+  (0x1d716, 0x1d734), (0x1d736, 0x1d74e), (0x1d750, 0x1d76e), (0x1d770, 0x1d788),
+  (0x1d78a, 0x1d7a8), (0x1d7aa, 0x1d7c2), (0x1d7c4, 0x1d7cb), (0x1d7ce, 0x1d7ff),
+  (0x1da00, 0x1da36), (0x1da3b, 0x1da6c), (0x1da75, 0x1da75), (0x1da84, 0x1da84),
+  (0x1da9b, 0x1da9f), (0x1daa1, 0x1daaf), (0x1df00, 0x1df1e), (0x1e000, 0x1e006),
+  (0x1e008, 0x1e018), (0x1e01b, 0x1e021), (0x1e023, 0x1e024), (0x1e026, 0x1e02a),
+  (0x1e100, 0x1e12c), (0x1e130, 0x1e13d), (0x1e140, 0x1e149), (0x1e14e, 0x1e14e),
+  (0x1e290, 0x1e2ae), (0x1e2c0, 0x1e2f9), (0x1e7e0, 0x1e7e6), (0x1e7e8, 0x1e7eb),
+  (0x1e7ed, 0x1e7ee), (0x1e7f0, 0x1e7fe), (0x1e800, 0x1e8c4), (0x1e8d0, 0x1e8d6),
+  (0x1e900, 0x1e94b), (0x1e950, 0x1e959), (0x1ee00, 0x1ee03), (0x1ee05, 0x1ee1f),
+  (0x1ee21, 0x1ee22), (0x1ee24, 0x1ee24), (0x1ee27, 0x1ee27), (0x1ee29, 0x1ee32),
+  (0x1ee34, 0x1ee37), (0x1ee39, 0x1ee39), (0x1ee3b, 0x1ee3b), (0x1ee42, 0x1ee42),
+  (0x1ee47, 0x1ee47), (0x1ee49, 0x1ee49), (0x1ee4b, 0x1ee4b), (0x1ee4d, 0x1ee4f),
+  (0x1ee51, 0x1ee52), (0x1ee54, 0x1ee54), (0x1ee57, 0x1ee57), (0x1ee59, 0x1ee59),
+  (0x1ee5b, 0x1ee5b), (0x1ee5d, 0x1ee5d), (0x1ee5f, 0x1ee5f), (0x1ee61, 0x1ee62),
+  (0x1ee64, 0x1ee64), (0x1ee67, 0x1ee6a), (0x1ee6c, 0x1ee72), (0x1ee74, 0x1ee77),
+  (0x1ee79, 0x1ee7c), (0x1ee7e, 0x1ee7e), (0x1ee80, 0x1ee89), (0x1ee8b, 0x1ee9b),
+  (0x1eea1, 0x1eea3), (0x1eea5, 0x1eea9), (0x1eeab, 0x1eebb), (0x1fbf0, 0x1fbf9),
+  (0x20000, 0x2a6df), (0x2a700, 0x2b738), (0x2b740, 0x2b81d), (0x2b820, 0x2cea1),
+  (0x2ceb0, 0x2ebe0), (0x2f800, 0x2fa1d), (0x30000, 0x3134a), (0xe0100, 0xe01ef),
+];
+
+pub static CATEGORIES: &'static [(u32, u32, UnicodeCategory)] = &[
+  (0x20, 0x20, Separator), (0x21, 0x23, Punctuation), (0x24, 0x24, Symbol),
+  (0x25, 0x2a, Punctuation), (0x2b, 0x2b, Symbol), (0x2c, 0x2f, Punctuation), (0x30, 0x39, Number),
+  (0x3a, 0x3b, Punctuation), (0x3c, 0x3e, Symbol), (0x3f, 0x40, Punctuation), (0x41, 0x5a, Letter),
+  (0x5b, 0x5d, Punctuation), (0x5e, 0x5e, Symbol), (0x5f, 0x5f, Punctuation), (0x60, 0x60, Symbol),
+  (0x61, 0x7a, Letter), (0x7b, 0x7b, Punctuation), (0x7c, 0x7c, Symbol), (0x7d, 0x7d, Punctuation),
+  (0x7e, 0x7e, Symbol), (0xa0, 0xa0, Separator), (0xa1, 0xa1, Punctuation), (0xa2, 0xa6, Symbol),
+  (0xa7, 0xa7, Punctuation), (0xa8, 0xa9, Symbol), (0xaa, 0xaa, Letter), (0xab, 0xab, Punctuation),
+  (0xac, 0xac, Symbol), (0xae, 0xb1, Symbol), (0xb2, 0xb3, Number), (0xb4, 0xb4, Symbol),
+  (0xb5, 0xb5, Letter), (0xb6, 0xb7, Punctuation), (0xb8, 0xb8, Symbol), (0xb9, 0xb9, Number),
+  (0xba, 0xba, Letter), (0xbb, 0xbb, Punctuation), (0xbc, 0xbe, Number), (0xbf, 0xbf, Punctuation),
+  (0xc0, 0xd6, Letter), (0xd7, 0xd7, Symbol), (0xd8, 0xf6, Letter), (0xf7, 0xf7, Symbol),
+  (0xf8, 0x2c1, Letter), (0x2c2, 0x2c5, Symbol), (0x2c6, 0x2d1, Letter), (0x2d2, 0x2df, Symbol),
+  (0x2e0, 0x2e4, Letter), (0x2e5, 0x2eb, Symbol), (0x2ec, 0x2ec, Letter), (0x2ed, 0x2ed, Symbol),
+  (0x2ee, 0x2ee, Letter), (0x2ef, 0x2ff, Symbol), (0x300, 0x36f, Mark), (0x370, 0x374, Letter),
+  (0x375, 0x375, Symbol), (0x376, 0x377, Letter), (0x37a, 0x37d, Letter),
+  (0x37e, 0x37e, Punctuation), (0x37f, 0x37f, Letter), (0x384, 0x385, Symbol),
+  (0x386, 0x386, Letter), (0x387, 0x387, Punctuation), (0x388, 0x38a, Letter),
+  (0x38c, 0x38c, Letter), (0x38e, 0x3a1, Letter), (0x3a3, 0x3f5, Letter), (0x3f6, 0x3f6, Symbol),
+  (0x3f7, 0x481, Letter), (0x482, 0x482, Symbol), (0x483, 0x489, Mark), (0x48a, 0x52f, Letter),
+  (0x531, 0x556, Letter), (0x559, 0x559, Letter), (0x55a, 0x55f, Punctuation),
+  (0x560, 0x588, Letter), (0x589, 0x58a, Punctuation), (0x58d, 0x58f, Symbol),
+  (0x591, 0x5bd, Mark), (0x5be, 0x5be, Punctuation), (0x5bf, 0x5bf, Mark),
+  (0x5c0, 0x5c0, Punctuation), (0x5c1, 0x5c2, Mark), (0x5c3, 0x5c3, Punctuation),
+  (0x5c4, 0x5c5, Mark), (0x5c6, 0x5c6, Punctuation), (0x5c7, 0x5c7, Mark), (0x5d0, 0x5ea, Letter),
+  (0x5ef, 0x5f2, Letter), (0x5f3, 0x5f4, Punctuation), (0x606, 0x608, Symbol),
+  (0x609, 0x60a, Punctuation), (0x60b, 0x60b, Symbol), (0x60c, 0x60d, Punctuation),
+  (0x60e, 0x60f, Symbol), (0x610, 0x61a, Mark), (0x61b, 0x61b, Punctuation),
+  (0x61d, 0x61f, Punctuation), (0x620, 0x64a, Letter), (0x64b, 0x65f, Mark),
+  (0x660, 0x669, Number), (0x66a, 0x66d, Punctuation), (0x66e, 0x66f, Letter),
+  (0x670, 0x670, Mark), (0x671, 0x6d3, Letter), (0x6d4, 0x6d4, Punctuation),
+  (0x6d5, 0x6d5, Letter), (0x6d6, 0x6dc, Mark), (0x6de, 0x6de, Symbol), (0x6df, 0x6e4, Mark),
+  (0x6e5, 0x6e6, Letter), (0x6e7, 0x6e8, Mark), (0x6e9, 0x6e9, Symbol), (0x6ea, 0x6ed, Mark),
+  (0x6ee, 0x6ef, Letter), (0x6f0, 0x6f9, Number), (0x6fa, 0x6fc, Letter), (0x6fd, 0x6fe, Symbol),
+  (0x6ff, 0x6ff, Letter), (0x700, 0x70d, Punctuation), (0x710, 0x710, Letter),
+  (0x711, 0x711, Mark), (0x712, 0x72f, Letter), (0x730, 0x74a, Mark), (0x74d, 0x7a5, Letter),
+  (0x7a6, 0x7b0, Mark), (0x7b1, 0x7b1, Letter), (0x7c0, 0x7c9, Number), (0x7ca, 0x7ea, Letter),
+  (0x7eb, 0x7f3, Mark), (0x7f4, 0x7f5, Letter), (0x7f6, 0x7f6, Symbol),
+  (0x7f7, 0x7f9, Punctuation), (0x7fa, 0x7fa, Letter), (0x7fd, 0x7fd, Mark),
+  (0x7fe, 0x7ff, Symbol), (0x800, 0x815, Letter), (0x816, 0x819, Mark), (0x81a, 0x81a, Letter),
+  (0x81b, 0x823, Mark), (0x824, 0x824, Letter), (0x825, 0x827, Mark), (0x828, 0x828, Letter),
+  (0x829, 0x82d, Mark), (0x830, 0x83e, Punctuation), (0x840, 0x858, Letter), (0x859, 0x85b, Mark),
+  (0x85e, 0x85e, Punctuation), (0x860, 0x86a, Letter), (0x870, 0x887, Letter),
+  (0x888, 0x888, Symbol), (0x889, 0x88e, Letter), (0x898, 0x89f, Mark), (0x8a0, 0x8c9, Letter),
+  (0x8ca, 0x8e1, Mark), (0x8e3, 0x903, Mark), (0x904, 0x939, Letter), (0x93a, 0x93c, Mark),
+  (0x93d, 0x93d, Letter), (0x93e, 0x94f, Mark), (0x950, 0x950, Letter), (0x951, 0x957, Mark),
+  (0x958, 0x961, Letter), (0x962, 0x963, Mark), (0x964, 0x965, Punctuation),
+  (0x966, 0x96f, Number), (0x970, 0x970, Punctuation), (0x971, 0x980, Letter),
+  (0x981, 0x983, Mark), (0x985, 0x98c, Letter), (0x98f, 0x990, Letter), (0x993, 0x9a8, Letter),
+  (0x9aa, 0x9b0, Letter), (0x9b2, 0x9b2, Letter), (0x9b6, 0x9b9, Letter), (0x9bc, 0x9bc, Mark),
+  (0x9bd, 0x9bd, Letter), (0x9be, 0x9c4, Mark), (0x9c7, 0x9c8, Mark), (0x9cb, 0x9cd, Mark),
+  (0x9ce, 0x9ce, Letter), (0x9d7, 0x9d7, Mark), (0x9dc, 0x9dd, Letter), (0x9df, 0x9e1, Letter),
+  (0x9e2, 0x9e3, Mark), (0x9e6, 0x9ef, Number), (0x9f0, 0x9f1, Letter), (0x9f2, 0x9f3, Symbol),
+  (0x9f4, 0x9f9, Number), (0x9fa, 0x9fb, Symbol), (0x9fc, 0x9fc, Letter),
+  (0x9fd, 0x9fd, Punctuation), (0x9fe, 0x9fe, Mark), (0xa01, 0xa03, Mark), (0xa05, 0xa0a, Letter),
+  (0xa0f, 0xa10, Letter), (0xa13, 0xa28, Letter), (0xa2a, 0xa30, Letter), (0xa32, 0xa33, Letter),
+  (0xa35, 0xa36, Letter), (0xa38, 0xa39, Letter), (0xa3c, 0xa3c, Mark), (0xa3e, 0xa42, Mark),
+  (0xa47, 0xa48, Mark), (0xa4b, 0xa4d, Mark), (0xa51, 0xa51, Mark), (0xa59, 0xa5c, Letter),
+  (0xa5e, 0xa5e, Letter), (0xa66, 0xa6f, Number), (0xa70, 0xa71, Mark), (0xa72, 0xa74, Letter),
+  (0xa75, 0xa75, Mark), (0xa76, 0xa76, Punctuation), (0xa81, 0xa83, Mark), (0xa85, 0xa8d, Letter),
+  (0xa8f, 0xa91, Letter), (0xa93, 0xaa8, Letter), (0xaaa, 0xab0, Letter), (0xab2, 0xab3, Letter),
+  (0xab5, 0xab9, Letter), (0xabc, 0xabc, Mark), (0xabd, 0xabd, Letter), (0xabe, 0xac5, Mark),
+  (0xac7, 0xac9, Mark), (0xacb, 0xacd, Mark), (0xad0, 0xad0, Letter), (0xae0, 0xae1, Letter),
+  (0xae2, 0xae3, Mark), (0xae6, 0xaef, Number), (0xaf0, 0xaf0, Punctuation),
+  (0xaf1, 0xaf1, Symbol), (0xaf9, 0xaf9, Letter), (0xafa, 0xaff, Mark), (0xb01, 0xb03, Mark),
+  (0xb05, 0xb0c, Letter), (0xb0f, 0xb10, Letter), (0xb13, 0xb28, Letter), (0xb2a, 0xb30, Letter),
+  (0xb32, 0xb33, Letter), (0xb35, 0xb39, Letter), (0xb3c, 0xb3c, Mark), (0xb3d, 0xb3d, Letter),
+  (0xb3e, 0xb44, Mark), (0xb47, 0xb48, Mark), (0xb4b, 0xb4d, Mark), (0xb55, 0xb57, Mark),
+  (0xb5c, 0xb5d, Letter), (0xb5f, 0xb61, Letter), (0xb62, 0xb63, Mark), (0xb66, 0xb6f, Number),
+  (0xb70, 0xb70, Symbol), (0xb71, 0xb71, Letter), (0xb72, 0xb77, Number), (0xb82, 0xb82, Mark),
+  (0xb83, 0xb83, Letter), (0xb85, 0xb8a, Letter), (0xb8e, 0xb90, Letter), (0xb92, 0xb95, Letter),
+  (0xb99, 0xb9a, Letter), (0xb9c, 0xb9c, Letter), (0xb9e, 0xb9f, Letter), (0xba3, 0xba4, Letter),
+  (0xba8, 0xbaa, Letter), (0xbae, 0xbb9, Letter), (0xbbe, 0xbc2, Mark), (0xbc6, 0xbc8, Mark),
+  (0xbca, 0xbcd, Mark), (0xbd0, 0xbd0, Letter), (0xbd7, 0xbd7, Mark), (0xbe6, 0xbf2, Number),
+  (0xbf3, 0xbfa, Symbol), (0xc00, 0xc04, Mark), (0xc05, 0xc0c, Letter), (0xc0e, 0xc10, Letter),
+  (0xc12, 0xc28, Letter), (0xc2a, 0xc39, Letter), (0xc3c, 0xc3c, Mark), (0xc3d, 0xc3d, Letter),
+  (0xc3e, 0xc44, Mark), (0xc46, 0xc48, Mark), (0xc4a, 0xc4d, Mark), (0xc55, 0xc56, Mark),
+  (0xc58, 0xc5a, Letter), (0xc5d, 0xc5d, Letter), (0xc60, 0xc61, Letter), (0xc62, 0xc63, Mark),
+  (0xc66, 0xc6f, Number), (0xc77, 0xc77, Punctuation), (0xc78, 0xc7e, Number),
+  (0xc7f, 0xc7f, Symbol), (0xc80, 0xc80, Letter), (0xc81, 0xc83, Mark),
+  (0xc84, 0xc84, Punctuation), (0xc85, 0xc8c, Letter), (0xc8e, 0xc90, Letter),
+  (0xc92, 0xca8, Letter), (0xcaa, 0xcb3, Letter), (0xcb5, 0xcb9, Letter), (0xcbc, 0xcbc, Mark),
+  (0xcbd, 0xcbd, Letter), (0xcbe, 0xcc4, Mark), (0xcc6, 0xcc8, Mark), (0xcca, 0xccd, Mark),
+  (0xcd5, 0xcd6, Mark), (0xcdd, 0xcde, Letter), (0xce0, 0xce1, Letter), (0xce2, 0xce3, Mark),
+  (0xce6, 0xcef, Number), (0xcf1, 0xcf2, Letter), (0xd00, 0xd03, Mark), (0xd04, 0xd0c, Letter),
+  (0xd0e, 0xd10, Letter), (0xd12, 0xd3a, Letter), (0xd3b, 0xd3c, Mark), (0xd3d, 0xd3d, Letter),
+  (0xd3e, 0xd44, Mark), (0xd46, 0xd48, Mark), (0xd4a, 0xd4d, Mark), (0xd4e, 0xd4e, Letter),
+  (0xd4f, 0xd4f, Symbol), (0xd54, 0xd56, Letter), (0xd57, 0xd57, Mark), (0xd58, 0xd5e, Number),
+  (0xd5f, 0xd61, Letter), (0xd62, 0xd63, Mark), (0xd66, 0xd78, Number), (0xd79, 0xd79, Symbol),
+  (0xd7a, 0xd7f, Letter), (0xd81, 0xd83, Mark), (0xd85, 0xd96, Letter), (0xd9a, 0xdb1, Letter),
+  (0xdb3, 0xdbb, Letter), (0xdbd, 0xdbd, Letter), (0xdc0, 0xdc6, Letter), (0xdca, 0xdca, Mark),
+  (0xdcf, 0xdd4, Mark), (0xdd6, 0xdd6, Mark), (0xdd8, 0xddf, Mark), (0xde6, 0xdef, Number),
+  (0xdf2, 0xdf3, Mark), (0xdf4, 0xdf4, Punctuation), (0xe01, 0xe30, Letter), (0xe31, 0xe31, Mark),
+  (0xe32, 0xe33, Letter), (0xe34, 0xe3a, Mark), (0xe3f, 0xe3f, Symbol), (0xe40, 0xe46, Letter),
+  (0xe47, 0xe4e, Mark), (0xe4f, 0xe4f, Punctuation), (0xe50, 0xe59, Number),
+  (0xe5a, 0xe5b, Punctuation), (0xe81, 0xe82, Letter), (0xe84, 0xe84, Letter),
+  (0xe86, 0xe8a, Letter), (0xe8c, 0xea3, Letter), (0xea5, 0xea5, Letter), (0xea7, 0xeb0, Letter),
+  (0xeb1, 0xeb1, Mark), (0xeb2, 0xeb3, Letter), (0xeb4, 0xebc, Mark), (0xebd, 0xebd, Letter),
+  (0xec0, 0xec4, Letter), (0xec6, 0xec6, Letter), (0xec8, 0xecd, Mark), (0xed0, 0xed9, Number),
+  (0xedc, 0xedf, Letter), (0xf00, 0xf00, Letter), (0xf01, 0xf03, Symbol),
+  (0xf04, 0xf12, Punctuation), (0xf13, 0xf13, Symbol), (0xf14, 0xf14, Punctuation),
+  (0xf15, 0xf17, Symbol), (0xf18, 0xf19, Mark), (0xf1a, 0xf1f, Symbol), (0xf20, 0xf33, Number),
+  (0xf34, 0xf34, Symbol), (0xf35, 0xf35, Mark), (0xf36, 0xf36, Symbol), (0xf37, 0xf37, Mark),
+  (0xf38, 0xf38, Symbol), (0xf39, 0xf39, Mark), (0xf3a, 0xf3d, Punctuation), (0xf3e, 0xf3f, Mark),
+  (0xf40, 0xf47, Letter), (0xf49, 0xf6c, Letter), (0xf71, 0xf84, Mark),
+  (0xf85, 0xf85, Punctuation), (0xf86, 0xf87, Mark), (0xf88, 0xf8c, Letter), (0xf8d, 0xf97, Mark),
+  (0xf99, 0xfbc, Mark), (0xfbe, 0xfc5, Symbol), (0xfc6, 0xfc6, Mark), (0xfc7, 0xfcc, Symbol),
+  (0xfce, 0xfcf, Symbol), (0xfd0, 0xfd4, Punctuation), (0xfd5, 0xfd8, Symbol),
+  (0xfd9, 0xfda, Punctuation), (0x1000, 0x102a, Letter), (0x102b, 0x103e, Mark),
+  (0x103f, 0x103f, Letter), (0x1040, 0x1049, Number), (0x104a, 0x104f, Punctuation),
+  (0x1050, 0x1055, Letter), (0x1056, 0x1059, Mark), (0x105a, 0x105d, Letter),
+  (0x105e, 0x1060, Mark), (0x1061, 0x1061, Letter), (0x1062, 0x1064, Mark),
+  (0x1065, 0x1066, Letter), (0x1067, 0x106d, Mark), (0x106e, 0x1070, Letter),
+  (0x1071, 0x1074, Mark), (0x1075, 0x1081, Letter), (0x1082, 0x108d, Mark),
+  (0x108e, 0x108e, Letter), (0x108f, 0x108f, Mark), (0x1090, 0x1099, Number),
+  (0x109a, 0x109d, Mark), (0x109e, 0x109f, Symbol), (0x10a0, 0x10c5, Letter),
+  (0x10c7, 0x10c7, Letter), (0x10cd, 0x10cd, Letter), (0x10d0, 0x10fa, Letter),
+  (0x10fb, 0x10fb, Punctuation), (0x10fc, 0x1248, Letter), (0x124a, 0x124d, Letter),
+  (0x1250, 0x1256, Letter), (0x1258, 0x1258, Letter), (0x125a, 0x125d, Letter),
+  (0x1260, 0x1288, Letter), (0x128a, 0x128d, Letter), (0x1290, 0x12b0, Letter),
+  (0x12b2, 0x12b5, Letter), (0x12b8, 0x12be, Letter), (0x12c0, 0x12c0, Letter),
+  (0x12c2, 0x12c5, Letter), (0x12c8, 0x12d6, Letter), (0x12d8, 0x1310, Letter),
+  (0x1312, 0x1315, Letter), (0x1318, 0x135a, Letter), (0x135d, 0x135f, Mark),
+  (0x1360, 0x1368, Punctuation), (0x1369, 0x137c, Number), (0x1380, 0x138f, Letter),
+  (0x1390, 0x1399, Symbol), (0x13a0, 0x13f5, Letter), (0x13f8, 0x13fd, Letter),
+  (0x1400, 0x1400, Punctuation), (0x1401, 0x166c, Letter), (0x166d, 0x166d, Symbol),
+  (0x166e, 0x166e, Punctuation), (0x166f, 0x167f, Letter), (0x1680, 0x1680, Separator),
+  (0x1681, 0x169a, Letter), (0x169b, 0x169c, Punctuation), (0x16a0, 0x16ea, Letter),
+  (0x16eb, 0x16ed, Punctuation), (0x16ee, 0x16f0, Number), (0x16f1, 0x16f8, Letter),
+  (0x1700, 0x1711, Letter), (0x1712, 0x1715, Mark), (0x171f, 0x1731, Letter),
+  (0x1732, 0x1734, Mark), (0x1735, 0x1736, Punctuation), (0x1740, 0x1751, Letter),
+  (0x1752, 0x1753, Mark), (0x1760, 0x176c, Letter), (0x176e, 0x1770, Letter),
+  (0x1772, 0x1773, Mark), (0x1780, 0x17b3, Letter), (0x17b4, 0x17d3, Mark),
+  (0x17d4, 0x17d6, Punctuation), (0x17d7, 0x17d7, Letter), (0x17d8, 0x17da, Punctuation),
+  (0x17db, 0x17db, Symbol), (0x17dc, 0x17dc, Letter), (0x17dd, 0x17dd, Mark),
+  (0x17e0, 0x17e9, Number), (0x17f0, 0x17f9, Number), (0x1800, 0x180a, Punctuation),
+  (0x180b, 0x180d, Mark), (0x180f, 0x180f, Mark), (0x1810, 0x1819, Number),
+  (0x1820, 0x1878, Letter), (0x1880, 0x1884, Letter), (0x1885, 0x1886, Mark),
+  (0x1887, 0x18a8, Letter), (0x18a9, 0x18a9, Mark), (0x18aa, 0x18aa, Letter),
+  (0x18b0, 0x18f5, Letter), (0x1900, 0x191e, Letter), (0x1920, 0x192b, Mark),
+  (0x1930, 0x193b, Mark), (0x1940, 0x1940, Symbol), (0x1944, 0x1945, Punctuation),
+  (0x1946, 0x194f, Number), (0x1950, 0x196d, Letter), (0x1970, 0x1974, Letter),
+  (0x1980, 0x19ab, Letter), (0x19b0, 0x19c9, Letter), (0x19d0, 0x19da, Number),
+  (0x19de, 0x19ff, Symbol), (0x1a00, 0x1a16, Letter), (0x1a17, 0x1a1b, Mark),
+  (0x1a1e, 0x1a1f, Punctuation), (0x1a20, 0x1a54, Letter), (0x1a55, 0x1a5e, Mark),
+  (0x1a60, 0x1a7c, Mark), (0x1a7f, 0x1a7f, Mark), (0x1a80, 0x1a89, Number),
+  (0x1a90, 0x1a99, Number), (0x1aa0, 0x1aa6, Punctuation), (0x1aa7, 0x1aa7, Letter),
+  (0x1aa8, 0x1aad, Punctuation), (0x1ab0, 0x1ace, Mark), (0x1b00, 0x1b04, Mark),
+  (0x1b05, 0x1b33, Letter), (0x1b34, 0x1b44, Mark), (0x1b45, 0x1b4c, Letter),
+  (0x1b50, 0x1b59, Number), (0x1b5a, 0x1b60, Punctuation), (0x1b61, 0x1b6a, Symbol),
+  (0x1b6b, 0x1b73, Mark), (0x1b74, 0x1b7c, Symbol), (0x1b7d, 0x1b7e, Punctuation),
+  (0x1b80, 0x1b82, Mark), (0x1b83, 0x1ba0, Letter), (0x1ba1, 0x1bad, Mark),
+  (0x1bae, 0x1baf, Letter), (0x1bb0, 0x1bb9, Number), (0x1bba, 0x1be5, Letter),
+  (0x1be6, 0x1bf3, Mark), (0x1bfc, 0x1bff, Punctuation), (0x1c00, 0x1c23, Letter),
+  (0x1c24, 0x1c37, Mark), (0x1c3b, 0x1c3f, Punctuation), (0x1c40, 0x1c49, Number),
+  (0x1c4d, 0x1c4f, Letter), (0x1c50, 0x1c59, Number), (0x1c5a, 0x1c7d, Letter),
+  (0x1c7e, 0x1c7f, Punctuation), (0x1c80, 0x1c88, Letter), (0x1c90, 0x1cba, Letter),
+  (0x1cbd, 0x1cbf, Letter), (0x1cc0, 0x1cc7, Punctuation), (0x1cd0, 0x1cd2, Mark),
+  (0x1cd3, 0x1cd3, Punctuation), (0x1cd4, 0x1ce8, Mark), (0x1ce9, 0x1cec, Letter),
+  (0x1ced, 0x1ced, Mark), (0x1cee, 0x1cf3, Letter), (0x1cf4, 0x1cf4, Mark),
+  (0x1cf5, 0x1cf6, Letter), (0x1cf7, 0x1cf9, Mark), (0x1cfa, 0x1cfa, Letter),
+  (0x1d00, 0x1dbf, Letter), (0x1dc0, 0x1dff, Mark), (0x1e00, 0x1f15, Letter),
+  (0x1f18, 0x1f1d, Letter), (0x1f20, 0x1f45, Letter), (0x1f48, 0x1f4d, Letter),
+  (0x1f50, 0x1f57, Letter), (0x1f59, 0x1f59, Letter), (0x1f5b, 0x1f5b, Letter),
+  (0x1f5d, 0x1f5d, Letter), (0x1f5f, 0x1f7d, Letter), (0x1f80, 0x1fb4, Letter),
+  (0x1fb6, 0x1fbc, Letter), (0x1fbd, 0x1fbd, Symbol), (0x1fbe, 0x1fbe, Letter),
+  (0x1fbf, 0x1fc1, Symbol), (0x1fc2, 0x1fc4, Letter), (0x1fc6, 0x1fcc, Letter),
+  (0x1fcd, 0x1fcf, Symbol), (0x1fd0, 0x1fd3, Letter), (0x1fd6, 0x1fdb, Letter),
+  (0x1fdd, 0x1fdf, Symbol), (0x1fe0, 0x1fec, Letter), (0x1fed, 0x1fef, Symbol),
+  (0x1ff2, 0x1ff4, Letter), (0x1ff6, 0x1ffc, Letter), (0x1ffd, 0x1ffe, Symbol),
+  (0x2000, 0x200a, Separator), (0x2010, 0x2027, Punctuation), (0x2028, 0x2029, Separator),
+  (0x202f, 0x202f, Separator), (0x2030, 0x2043, Punctuation), (0x2044, 0x2044, Symbol),
+  (0x2045, 0x2051, Punctuation), (0x2052, 0x2052, Symbol), (0x2053, 0x205e, Punctuation),
+  (0x205f, 0x205f, Separator), (0x2070, 0x2070, Number), (0x2071, 0x2071, Letter),
+  (0x2074, 0x2079, Number), (0x207a, 0x207c, Symbol), (0x207d, 0x207e, Punctuation),
+  (0x207f, 0x207f, Letter), (0x2080, 0x2089, Number), (0x208a, 0x208c, Symbol),
+  (0x208d, 0x208e, Punctuation), (0x2090, 0x209c, Letter), (0x20a0, 0x20c0, Symbol),
+  (0x20d0, 0x20f0, Mark), (0x2100, 0x2101, Symbol), (0x2102, 0x2102, Letter),
+  (0x2103, 0x2106, Symbol), (0x2107, 0x2107, Letter), (0x2108, 0x2109, Symbol),
+  (0x210a, 0x2113, Letter), (0x2114, 0x2114, Symbol), (0x2115, 0x2115, Letter),
+  (0x2116, 0x2118, Symbol), (0x2119, 0x211d, Letter), (0x211e, 0x2123, Symbol),
+  (0x2124, 0x2124, Letter), (0x2125, 0x2125, Symbol), (0x2126, 0x2126, Letter),
+  (0x2127, 0x2127, Symbol), (0x2128, 0x2128, Letter), (0x2129, 0x2129, Symbol),
+  (0x212a, 0x212d, Letter), (0x212e, 0x212e, Symbol), (0x212f, 0x2139, Letter),
+  (0x213a, 0x213b, Symbol), (0x213c, 0x213f, Letter), (0x2140, 0x2144, Symbol),
+  (0x2145, 0x2149, Letter), (0x214a, 0x214d, Symbol), (0x214e, 0x214e, Letter),
+  (0x214f, 0x214f, Symbol), (0x2150, 0x2182, Number), (0x2183, 0x2184, Letter),
+  (0x2185, 0x2189, Number), (0x218a, 0x218b, Symbol), (0x2190, 0x2307, Symbol),
+  (0x2308, 0x230b, Punctuation), (0x230c, 0x2328, Symbol), (0x2329, 0x232a, Punctuation),
+  (0x232b, 0x2426, Symbol), (0x2440, 0x244a, Symbol), (0x2460, 0x249b, Number),
+  (0x249c, 0x24e9, Symbol), (0x24ea, 0x24ff, Number), (0x2500, 0x2767, Symbol),
+  (0x2768, 0x2775, Punctuation), (0x2776, 0x2793, Number), (0x2794, 0x27c4, Symbol),
+  (0x27c5, 0x27c6, Punctuation), (0x27c7, 0x27e5, Symbol), (0x27e6, 0x27ef, Punctuation),
+  (0x27f0, 0x2982, Symbol), (0x2983, 0x2998, Punctuation), (0x2999, 0x29d7, Symbol),
+  (0x29d8, 0x29db, Punctuation), (0x29dc, 0x29fb, Symbol), (0x29fc, 0x29fd, Punctuation),
+  (0x29fe, 0x2b73, Symbol), (0x2b76, 0x2b95, Symbol), (0x2b97, 0x2bff, Symbol),
+  (0x2c00, 0x2ce4, Letter), (0x2ce5, 0x2cea, Symbol), (0x2ceb, 0x2cee, Letter),
+  (0x2cef, 0x2cf1, Mark), (0x2cf2, 0x2cf3, Letter), (0x2cf9, 0x2cfc, Punctuation),
+  (0x2cfd, 0x2cfd, Number), (0x2cfe, 0x2cff, Punctuation), (0x2d00, 0x2d25, Letter),
+  (0x2d27, 0x2d27, Letter), (0x2d2d, 0x2d2d, Letter), (0x2d30, 0x2d67, Letter),
+  (0x2d6f, 0x2d6f, Letter), (0x2d70, 0x2d70, Punctuation), (0x2d7f, 0x2d7f, Mark),
+  (0x2d80, 0x2d96, Letter), (0x2da0, 0x2da6, Letter), (0x2da8, 0x2dae, Letter),
+  (0x2db0, 0x2db6, Letter), (0x2db8, 0x2dbe, Letter), (0x2dc0, 0x2dc6, Letter),
+  (0x2dc8, 0x2dce, Letter), (0x2dd0, 0x2dd6, Letter), (0x2dd8, 0x2dde, Letter),
+  (0x2de0, 0x2dff, Mark), (0x2e00, 0x2e2e, Punctuation), (0x2e2f, 0x2e2f, Letter),
+  (0x2e30, 0x2e4f, Punctuation), (0x2e50, 0x2e51, Symbol), (0x2e52, 0x2e5d, Punctuation),
+  (0x2e80, 0x2e99, Symbol), (0x2e9b, 0x2ef3, Symbol), (0x2f00, 0x2fd5, Symbol),
+  (0x2ff0, 0x2ffb, Symbol), (0x3000, 0x3000, Separator), (0x3001, 0x3003, Punctuation),
+  (0x3004, 0x3004, Symbol), (0x3005, 0x3006, Letter), (0x3007, 0x3007, Number),
+  (0x3008, 0x3011, Punctuation), (0x3012, 0x3013, Symbol), (0x3014, 0x301f, Punctuation),
+  (0x3020, 0x3020, Symbol), (0x3021, 0x3029, Number), (0x302a, 0x302f, Mark),
+  (0x3030, 0x3030, Punctuation), (0x3031, 0x3035, Letter), (0x3036, 0x3037, Symbol),
+  (0x3038, 0x303a, Number), (0x303b, 0x303c, Letter), (0x303d, 0x303d, Punctuation),
+  (0x303e, 0x303f, Symbol), (0x3041, 0x3096, Letter), (0x3099, 0x309a, Mark),
+  (0x309b, 0x309c, Symbol), (0x309d, 0x309f, Letter), (0x30a0, 0x30a0, Punctuation),
+  (0x30a1, 0x30fa, Letter), (0x30fb, 0x30fb, Punctuation), (0x30fc, 0x30ff, Letter),
+  (0x3105, 0x312f, Letter), (0x3131, 0x318e, Letter), (0x3190, 0x3191, Symbol),
+  (0x3192, 0x3195, Number), (0x3196, 0x319f, Symbol), (0x31a0, 0x31bf, Letter),
+  (0x31c0, 0x31e3, Symbol), (0x31f0, 0x31ff, Letter), (0x3200, 0x321e, Symbol),
+  (0x3220, 0x3229, Number), (0x322a, 0x3247, Symbol), (0x3248, 0x324f, Number),
+  (0x3250, 0x3250, Symbol), (0x3251, 0x325f, Number), (0x3260, 0x327f, Symbol),
+  (0x3280, 0x3289, Number), (0x328a, 0x32b0, Symbol), (0x32b1, 0x32bf, Number),
+  (0x32c0, 0x33ff, Symbol), (0x3400, 0x4dbf, Letter), (0x4dc0, 0x4dff, Symbol),
+  (0x4e00, 0xa48c, Letter), (0xa490, 0xa4c6, Symbol), (0xa4d0, 0xa4fd, Letter),
+  (0xa4fe, 0xa4ff, Punctuation), (0xa500, 0xa60c, Letter), (0xa60d, 0xa60f, Punctuation),
+  (0xa610, 0xa61f, Letter), (0xa620, 0xa629, Number), (0xa62a, 0xa62b, Letter),
+  (0xa640, 0xa66e, Letter), (0xa66f, 0xa672, Mark), (0xa673, 0xa673, Punctuation),
+  (0xa674, 0xa67d, Mark), (0xa67e, 0xa67e, Punctuation), (0xa67f, 0xa69d, Letter),
+  (0xa69e, 0xa69f, Mark), (0xa6a0, 0xa6e5, Letter), (0xa6e6, 0xa6ef, Number),
+  (0xa6f0, 0xa6f1, Mark), (0xa6f2, 0xa6f7, Punctuation), (0xa700, 0xa716, Symbol),
+  (0xa717, 0xa71f, Letter), (0xa720, 0xa721, Symbol), (0xa722, 0xa788, Letter),
+  (0xa789, 0xa78a, Symbol), (0xa78b, 0xa7ca, Letter), (0xa7d0, 0xa7d1, Letter),
+  (0xa7d3, 0xa7d3, Letter), (0xa7d5, 0xa7d9, Letter), (0xa7f2, 0xa801, Letter),
+  (0xa802, 0xa802, Mark), (0xa803, 0xa805, Letter), (0xa806, 0xa806, Mark),
+  (0xa807, 0xa80a, Letter), (0xa80b, 0xa80b, Mark), (0xa80c, 0xa822, Letter),
+  (0xa823, 0xa827, Mark), (0xa828, 0xa82b, Symbol), (0xa82c, 0xa82c, Mark),
+  (0xa830, 0xa835, Number), (0xa836, 0xa839, Symbol), (0xa840, 0xa873, Letter),
+  (0xa874, 0xa877, Punctuation), (0xa880, 0xa881, Mark), (0xa882, 0xa8b3, Letter),
+  (0xa8b4, 0xa8c5, Mark), (0xa8ce, 0xa8cf, Punctuation), (0xa8d0, 0xa8d9, Number),
+  (0xa8e0, 0xa8f1, Mark), (0xa8f2, 0xa8f7, Letter), (0xa8f8, 0xa8fa, Punctuation),
+  (0xa8fb, 0xa8fb, Letter), (0xa8fc, 0xa8fc, Punctuation), (0xa8fd, 0xa8fe, Letter),
+  (0xa8ff, 0xa8ff, Mark), (0xa900, 0xa909, Number), (0xa90a, 0xa925, Letter),
+  (0xa926, 0xa92d, Mark), (0xa92e, 0xa92f, Punctuation), (0xa930, 0xa946, Letter),
+  (0xa947, 0xa953, Mark), (0xa95f, 0xa95f, Punctuation), (0xa960, 0xa97c, Letter),
+  (0xa980, 0xa983, Mark), (0xa984, 0xa9b2, Letter), (0xa9b3, 0xa9c0, Mark),
+  (0xa9c1, 0xa9cd, Punctuation), (0xa9cf, 0xa9cf, Letter), (0xa9d0, 0xa9d9, Number),
+  (0xa9de, 0xa9df, Punctuation), (0xa9e0, 0xa9e4, Letter), (0xa9e5, 0xa9e5, Mark),
+  (0xa9e6, 0xa9ef, Letter), (0xa9f0, 0xa9f9, Number), (0xa9fa, 0xa9fe, Letter),
+  (0xaa00, 0xaa28, Letter), (0xaa29, 0xaa36, Mark), (0xaa40, 0xaa42, Letter),
+  (0xaa43, 0xaa43, Mark), (0xaa44, 0xaa4b, Letter), (0xaa4c, 0xaa4d, Mark),
+  (0xaa50, 0xaa59, Number), (0xaa5c, 0xaa5f, Punctuation), (0xaa60, 0xaa76, Letter),
+  (0xaa77, 0xaa79, Symbol), (0xaa7a, 0xaa7a, Letter), (0xaa7b, 0xaa7d, Mark),
+  (0xaa7e, 0xaaaf, Letter), (0xaab0, 0xaab0, Mark), (0xaab1, 0xaab1, Letter),
+  (0xaab2, 0xaab4, Mark), (0xaab5, 0xaab6, Letter), (0xaab7, 0xaab8, Mark),
+  (0xaab9, 0xaabd, Letter), (0xaabe, 0xaabf, Mark), (0xaac0, 0xaac0, Letter),
+  (0xaac1, 0xaac1, Mark), (0xaac2, 0xaac2, Letter), (0xaadb, 0xaadd, Letter),
+  (0xaade, 0xaadf, Punctuation), (0xaae0, 0xaaea, Letter), (0xaaeb, 0xaaef, Mark),
+  (0xaaf0, 0xaaf1, Punctuation), (0xaaf2, 0xaaf4, Letter), (0xaaf5, 0xaaf6, Mark),
+  (0xab01, 0xab06, Letter), (0xab09, 0xab0e, Letter), (0xab11, 0xab16, Letter),
+  (0xab20, 0xab26, Letter), (0xab28, 0xab2e, Letter), (0xab30, 0xab5a, Letter),
+  (0xab5b, 0xab5b, Symbol), (0xab5c, 0xab69, Letter), (0xab6a, 0xab6b, Symbol),
+  (0xab70, 0xabe2, Letter), (0xabe3, 0xabea, Mark), (0xabeb, 0xabeb, Punctuation),
+  (0xabec, 0xabed, Mark), (0xabf0, 0xabf9, Number), (0xac00, 0xd7a3, Letter),
+  (0xd7b0, 0xd7c6, Letter), (0xd7cb, 0xd7fb, Letter), (0xf900, 0xfa6d, Letter),
+  (0xfa70, 0xfad9, Letter), (0xfb00, 0xfb06, Letter), (0xfb13, 0xfb17, Letter),
+  (0xfb1d, 0xfb1d, Letter), (0xfb1e, 0xfb1e, Mark), (0xfb1f, 0xfb28, Letter),
+  (0xfb29, 0xfb29, Symbol), (0xfb2a, 0xfb36, Letter), (0xfb38, 0xfb3c, Letter),
+  (0xfb3e, 0xfb3e, Letter), (0xfb40, 0xfb41, Letter), (0xfb43, 0xfb44, Letter),
+  (0xfb46, 0xfbb1, Letter), (0xfbb2, 0xfbc2, Symbol), (0xfbd3, 0xfd3d, Letter),
+  (0xfd3e, 0xfd3f, Punctuation), (0xfd40, 0xfd4f, Symbol), (0xfd50, 0xfd8f, Letter),
+  (0xfd92, 0xfdc7, Letter), (0xfdcf, 0xfdcf, Symbol), (0xfdf0, 0xfdfb, Letter),
+  (0xfdfc, 0xfdff, Symbol), (0xfe00, 0xfe0f, Mark), (0xfe10, 0xfe19, Punctuation),
+  (0xfe20, 0xfe2f, Mark), (0xfe30, 0xfe52, Punctuation), (0xfe54, 0xfe61, Punctuation),
+  (0xfe62, 0xfe62, Symbol), (0xfe63, 0xfe63, Punctuation), (0xfe64, 0xfe66, Symbol),
+  (0xfe68, 0xfe68, Punctuation), (0xfe69, 0xfe69, Symbol), (0xfe6a, 0xfe6b, Punctuation),
+  (0xfe70, 0xfe74, Letter), (0xfe76, 0xfefc, Letter), (0xff01, 0xff03, Punctuation),
+  (0xff04, 0xff04, Symbol), (0xff05, 0xff0a, Punctuation), (0xff0b, 0xff0b, Symbol),
+  (0xff0c, 0xff0f, Punctuation), (0xff10, 0xff19, Number), (0xff1a, 0xff1b, Punctuation),
+  (0xff1c, 0xff1e, Symbol), (0xff1f, 0xff20, Punctuation), (0xff21, 0xff3a, Letter),
+  (0xff3b, 0xff3d, Punctuation), (0xff3e, 0xff3e, Symbol), (0xff3f, 0xff3f, Punctuation),
+  (0xff40, 0xff40, Symbol), (0xff41, 0xff5a, Letter), (0xff5b, 0xff5b, Punctuation),
+  (0xff5c, 0xff5c, Symbol), (0xff5d, 0xff5d, Punctuation), (0xff5e, 0xff5e, Symbol),
+  (0xff5f, 0xff65, Punctuation), (0xff66, 0xffbe, Letter), (0xffc2, 0xffc7, Letter),
+  (0xffca, 0xffcf, Letter), (0xffd2, 0xffd7, Letter), (0xffda, 0xffdc, Letter),
+  (0xffe0, 0xffe6, Symbol), (0xffe8, 0xffee, Symbol), (0xfffc, 0xfffd, Symbol),
+  (0x10000, 0x1000b, Letter), (0x1000d, 0x10026, Letter), (0x10028, 0x1003a, Letter),
+  (0x1003c, 0x1003d, Letter), (0x1003f, 0x1004d, Letter), (0x10050, 0x1005d, Letter),
+  (0x10080, 0x100fa, Letter), (0x10100, 0x10102, Punctuation), (0x10107, 0x10133, Number),
+  (0x10137, 0x1013f, Symbol), (0x10140, 0x10178, Number), (0x10179, 0x10189, Symbol),
+  (0x1018a, 0x1018b, Number), (0x1018c, 0x1018e, Symbol), (0x10190, 0x1019c, Symbol),
+  (0x101a0, 0x101a0, Symbol), (0x101d0, 0x101fc, Symbol), (0x101fd, 0x101fd, Mark),
+  (0x10280, 0x1029c, Letter), (0x102a0, 0x102d0, Letter), (0x102e0, 0x102e0, Mark),
+  (0x102e1, 0x102fb, Number), (0x10300, 0x1031f, Letter), (0x10320, 0x10323, Number),
+  (0x1032d, 0x10340, Letter), (0x10341, 0x10341, Number), (0x10342, 0x10349, Letter),
+  (0x1034a, 0x1034a, Number), (0x10350, 0x10375, Letter), (0x10376, 0x1037a, Mark),
+  (0x10380, 0x1039d, Letter), (0x1039f, 0x1039f, Punctuation), (0x103a0, 0x103c3, Letter),
+  (0x103c8, 0x103cf, Letter), (0x103d0, 0x103d0, Punctuation), (0x103d1, 0x103d5, Number),
+  (0x10400, 0x1049d, Letter), (0x104a0, 0x104a9, Number), (0x104b0, 0x104d3, Letter),
+  (0x104d8, 0x104fb, Letter), (0x10500, 0x10527, Letter), (0x10530, 0x10563, Letter),
+  (0x1056f, 0x1056f, Punctuation), (0x10570, 0x1057a, Letter), (0x1057c, 0x1058a, Letter),
+  (0x1058c, 0x10592, Letter), (0x10594, 0x10595, Letter), (0x10597, 0x105a1, Letter),
+  (0x105a3, 0x105b1, Letter), (0x105b3, 0x105b9, Letter), (0x105bb, 0x105bc, Letter),
+  (0x10600, 0x10736, Letter), (0x10740, 0x10755, Letter), (0x10760, 0x10767, Letter),
+  (0x10780, 0x10785, Letter), (0x10787, 0x107b0, Letter), (0x107b2, 0x107ba, Letter),
+  (0x10800, 0x10805, Letter), (0x10808, 0x10808, Letter), (0x1080a, 0x10835, Letter),
+  (0x10837, 0x10838, Letter), (0x1083c, 0x1083c, Letter), (0x1083f, 0x10855, Letter),
+  (0x10857, 0x10857, Punctuation), (0x10858, 0x1085f, Number), (0x10860, 0x10876, Letter),
+  (0x10877, 0x10878, Symbol), (0x10879, 0x1087f, Number), (0x10880, 0x1089e, Letter),
+  (0x108a7, 0x108af, Number), (0x108e0, 0x108f2, Letter), (0x108f4, 0x108f5, Letter),
+  (0x108fb, 0x108ff, Number), (0x10900, 0x10915, Letter), (0x10916, 0x1091b, Number),
+  (0x1091f, 0x1091f, Punctuation), (0x10920, 0x10939, Letter), (0x1093f, 0x1093f, Punctuation),
+  (0x10980, 0x109b7, Letter), (0x109bc, 0x109bd, Number), (0x109be, 0x109bf, Letter),
+  (0x109c0, 0x109cf, Number), (0x109d2, 0x109ff, Number), (0x10a00, 0x10a00, Letter),
+  (0x10a01, 0x10a03, Mark), (0x10a05, 0x10a06, Mark), (0x10a0c, 0x10a0f, Mark),
+  (0x10a10, 0x10a13, Letter), (0x10a15, 0x10a17, Letter), (0x10a19, 0x10a35, Letter),
+  (0x10a38, 0x10a3a, Mark), (0x10a3f, 0x10a3f, Mark), (0x10a40, 0x10a48, Number),
+  (0x10a50, 0x10a58, Punctuation), (0x10a60, 0x10a7c, Letter), (0x10a7d, 0x10a7e, Number),
+  (0x10a7f, 0x10a7f, Punctuation), (0x10a80, 0x10a9c, Letter), (0x10a9d, 0x10a9f, Number),
+  (0x10ac0, 0x10ac7, Letter), (0x10ac8, 0x10ac8, Symbol), (0x10ac9, 0x10ae4, Letter),
+  (0x10ae5, 0x10ae6, Mark), (0x10aeb, 0x10aef, Number), (0x10af0, 0x10af6, Punctuation),
+  (0x10b00, 0x10b35, Letter), (0x10b39, 0x10b3f, Punctuation), (0x10b40, 0x10b55, Letter),
+  (0x10b58, 0x10b5f, Number), (0x10b60, 0x10b72, Letter), (0x10b78, 0x10b7f, Number),
+  (0x10b80, 0x10b91, Letter), (0x10b99, 0x10b9c, Punctuation), (0x10ba9, 0x10baf, Number),
+  (0x10c00, 0x10c48, Letter), (0x10c80, 0x10cb2, Letter), (0x10cc0, 0x10cf2, Letter),
+  (0x10cfa, 0x10cff, Number), (0x10d00, 0x10d23, Letter), (0x10d24, 0x10d27, Mark),
+  (0x10d30, 0x10d39, Number), (0x10e60, 0x10e7e, Number), (0x10e80, 0x10ea9, Letter),
+  (0x10eab, 0x10eac, Mark), (0x10ead, 0x10ead, Punctuation), (0x10eb0, 0x10eb1, Letter),
+  (0x10f00, 0x10f1c, Letter), (0x10f1d, 0x10f26, Number), (0x10f27, 0x10f27, Letter),
+  (0x10f30, 0x10f45, Letter), (0x10f46, 0x10f50, Mark), (0x10f51, 0x10f54, Number),
+  (0x10f55, 0x10f59, Punctuation), (0x10f70, 0x10f81, Letter), (0x10f82, 0x10f85, Mark),
+  (0x10f86, 0x10f89, Punctuation), (0x10fb0, 0x10fc4, Letter), (0x10fc5, 0x10fcb, Number),
+  (0x10fe0, 0x10ff6, Letter), (0x11000, 0x11002, Mark), (0x11003, 0x11037, Letter),
+  (0x11038, 0x11046, Mark), (0x11047, 0x1104d, Punctuation), (0x11052, 0x1106f, Number),
+  (0x11070, 0x11070, Mark), (0x11071, 0x11072, Letter), (0x11073, 0x11074, Mark),
+  (0x11075, 0x11075, Letter), (0x1107f, 0x11082, Mark), (0x11083, 0x110af, Letter),
+  (0x110b0, 0x110ba, Mark), (0x110bb, 0x110bc, Punctuation), (0x110be, 0x110c1, Punctuation),
+  (0x110c2, 0x110c2, Mark), (0x110d0, 0x110e8, Letter), (0x110f0, 0x110f9, Number),
+  (0x11100, 0x11102, Mark), (0x11103, 0x11126, Letter), (0x11127, 0x11134, Mark),
+  (0x11136, 0x1113f, Number), (0x11140, 0x11143, Punctuation), (0x11144, 0x11144, Letter),
+  (0x11145, 0x11146, Mark), (0x11147, 0x11147, Letter), (0x11150, 0x11172, Letter),
+  (0x11173, 0x11173, Mark), (0x11174, 0x11175, Punctuation), (0x11176, 0x11176, Letter),
+  (0x11180, 0x11182, Mark), (0x11183, 0x111b2, Letter), (0x111b3, 0x111c0, Mark),
+  (0x111c1, 0x111c4, Letter), (0x111c5, 0x111c8, Punctuation), (0x111c9, 0x111cc, Mark),
+  (0x111cd, 0x111cd, Punctuation), (0x111ce, 0x111cf, Mark), (0x111d0, 0x111d9, Number),
+  (0x111da, 0x111da, Letter), (0x111db, 0x111db, Punctuation), (0x111dc, 0x111dc, Letter),
+  (0x111dd, 0x111df, Punctuation), (0x111e1, 0x111f4, Number), (0x11200, 0x11211, Letter),
+  (0x11213, 0x1122b, Letter), (0x1122c, 0x11237, Mark), (0x11238, 0x1123d, Punctuation),
+  (0x1123e, 0x1123e, Mark), (0x11280, 0x11286, Letter), (0x11288, 0x11288, Letter),
+  (0x1128a, 0x1128d, Letter), (0x1128f, 0x1129d, Letter), (0x1129f, 0x112a8, Letter),
+  (0x112a9, 0x112a9, Punctuation), (0x112b0, 0x112de, Letter), (0x112df, 0x112ea, Mark),
+  (0x112f0, 0x112f9, Number), (0x11300, 0x11303, Mark), (0x11305, 0x1130c, Letter),
+  (0x1130f, 0x11310, Letter), (0x11313, 0x11328, Letter), (0x1132a, 0x11330, Letter),
+  (0x11332, 0x11333, Letter), (0x11335, 0x11339, Letter), (0x1133b, 0x1133c, Mark),
+  (0x1133d, 0x1133d, Letter), (0x1133e, 0x11344, Mark), (0x11347, 0x11348, Mark),
+  (0x1134b, 0x1134d, Mark), (0x11350, 0x11350, Letter), (0x11357, 0x11357, Mark),
+  (0x1135d, 0x11361, Letter), (0x11362, 0x11363, Mark), (0x11366, 0x1136c, Mark),
+  (0x11370, 0x11374, Mark), (0x11400, 0x11434, Letter), (0x11435, 0x11446, Mark),
+  (0x11447, 0x1144a, Letter), (0x1144b, 0x1144f, Punctuation), (0x11450, 0x11459, Number),
+  (0x1145a, 0x1145b, Punctuation), (0x1145d, 0x1145d, Punctuation), (0x1145e, 0x1145e, Mark),
+  (0x1145f, 0x11461, Letter), (0x11480, 0x114af, Letter), (0x114b0, 0x114c3, Mark),
+  (0x114c4, 0x114c5, Letter), (0x114c6, 0x114c6, Punctuation), (0x114c7, 0x114c7, Letter),
+  (0x114d0, 0x114d9, Number), (0x11580, 0x115ae, Letter), (0x115af, 0x115b5, Mark),
+  (0x115b8, 0x115c0, Mark), (0x115c1, 0x115d7, Punctuation), (0x115d8, 0x115db, Letter),
+  (0x115dc, 0x115dd, Mark), (0x11600, 0x1162f, Letter), (0x11630, 0x11640, Mark),
+  (0x11641, 0x11643, Punctuation), (0x11644, 0x11644, Letter), (0x11650, 0x11659, Number),
+  (0x11660, 0x1166c, Punctuation), (0x11680, 0x116aa, Letter), (0x116ab, 0x116b7, Mark),
+  (0x116b8, 0x116b8, Letter), (0x116b9, 0x116b9, Punctuation), (0x116c0, 0x116c9, Number),
+  (0x11700, 0x1171a, Letter), (0x1171d, 0x1172b, Mark), (0x11730, 0x1173b, Number),
+  (0x1173c, 0x1173e, Punctuation), (0x1173f, 0x1173f, Symbol), (0x11740, 0x11746, Letter),
+  (0x11800, 0x1182b, Letter), (0x1182c, 0x1183a, Mark), (0x1183b, 0x1183b, Punctuation),
+  (0x118a0, 0x118df, Letter), (0x118e0, 0x118f2, Number), (0x118ff, 0x11906, Letter),
+  (0x11909, 0x11909, Letter), (0x1190c, 0x11913, Letter), (0x11915, 0x11916, Letter),
+  (0x11918, 0x1192f, Letter), (0x11930, 0x11935, Mark), (0x11937, 0x11938, Mark),
+  (0x1193b, 0x1193e, Mark), (0x1193f, 0x1193f, Letter), (0x11940, 0x11940, Mark),
+  (0x11941, 0x11941, Letter), (0x11942, 0x11943, Mark), (0x11944, 0x11946, Punctuation),
+  (0x11950, 0x11959, Number), (0x119a0, 0x119a7, Letter), (0x119aa, 0x119d0, Letter),
+  (0x119d1, 0x119d7, Mark), (0x119da, 0x119e0, Mark), (0x119e1, 0x119e1, Letter),
+  (0x119e2, 0x119e2, Punctuation), (0x119e3, 0x119e3, Letter), (0x119e4, 0x119e4, Mark),
+  (0x11a00, 0x11a00, Letter), (0x11a01, 0x11a0a, Mark), (0x11a0b, 0x11a32, Letter),
+  (0x11a33, 0x11a39, Mark), (0x11a3a, 0x11a3a, Letter), (0x11a3b, 0x11a3e, Mark),
+  (0x11a3f, 0x11a46, Punctuation), (0x11a47, 0x11a47, Mark), (0x11a50, 0x11a50, Letter),
+  (0x11a51, 0x11a5b, Mark), (0x11a5c, 0x11a89, Letter), (0x11a8a, 0x11a99, Mark),
+  (0x11a9a, 0x11a9c, Punctuation), (0x11a9d, 0x11a9d, Letter), (0x11a9e, 0x11aa2, Punctuation),
+  (0x11ab0, 0x11af8, Letter), (0x11c00, 0x11c08, Letter), (0x11c0a, 0x11c2e, Letter),
+  (0x11c2f, 0x11c36, Mark), (0x11c38, 0x11c3f, Mark), (0x11c40, 0x11c40, Letter),
+  (0x11c41, 0x11c45, Punctuation), (0x11c50, 0x11c6c, Number), (0x11c70, 0x11c71, Punctuation),
+  (0x11c72, 0x11c8f, Letter), (0x11c92, 0x11ca7, Mark), (0x11ca9, 0x11cb6, Mark),
+  (0x11d00, 0x11d06, Letter), (0x11d08, 0x11d09, Letter), (0x11d0b, 0x11d30, Letter),
+  (0x11d31, 0x11d36, Mark), (0x11d3a, 0x11d3a, Mark), (0x11d3c, 0x11d3d, Mark),
+  (0x11d3f, 0x11d45, Mark), (0x11d46, 0x11d46, Letter), (0x11d47, 0x11d47, Mark),
+  (0x11d50, 0x11d59, Number), (0x11d60, 0x11d65, Letter), (0x11d67, 0x11d68, Letter),
+  (0x11d6a, 0x11d89, Letter), (0x11d8a, 0x11d8e, Mark), (0x11d90, 0x11d91, Mark),
+  (0x11d93, 0x11d97, Mark), (0x11d98, 0x11d98, Letter), (0x11da0, 0x11da9, Number),
+  (0x11ee0, 0x11ef2, Letter), (0x11ef3, 0x11ef6, Mark), (0x11ef7, 0x11ef8, Punctuation),
+  (0x11fb0, 0x11fb0, Letter), (0x11fc0, 0x11fd4, Number), (0x11fd5, 0x11ff1, Symbol),
+  (0x11fff, 0x11fff, Punctuation), (0x12000, 0x12399, Letter), (0x12400, 0x1246e, Number),
+  (0x12470, 0x12474, Punctuation), (0x12480, 0x12543, Letter), (0x12f90, 0x12ff0, Letter),
+  (0x12ff1, 0x12ff2, Punctuation), (0x13000, 0x1342e, Letter), (0x14400, 0x14646, Letter),
+  (0x16800, 0x16a38, Letter), (0x16a40, 0x16a5e, Letter), (0x16a60, 0x16a69, Number),
+  (0x16a6e, 0x16a6f, Punctuation), (0x16a70, 0x16abe, Letter), (0x16ac0, 0x16ac9, Number),
+  (0x16ad0, 0x16aed, Letter), (0x16af0, 0x16af4, Mark), (0x16af5, 0x16af5, Punctuation),
+  (0x16b00, 0x16b2f, Letter), (0x16b30, 0x16b36, Mark), (0x16b37, 0x16b3b, Punctuation),
+  (0x16b3c, 0x16b3f, Symbol), (0x16b40, 0x16b43, Letter), (0x16b44, 0x16b44, Punctuation),
+  (0x16b45, 0x16b45, Symbol), (0x16b50, 0x16b59, Number), (0x16b5b, 0x16b61, Number),
+  (0x16b63, 0x16b77, Letter), (0x16b7d, 0x16b8f, Letter), (0x16e40, 0x16e7f, Letter),
+  (0x16e80, 0x16e96, Number), (0x16e97, 0x16e9a, Punctuation), (0x16f00, 0x16f4a, Letter),
+  (0x16f4f, 0x16f4f, Mark), (0x16f50, 0x16f50, Letter), (0x16f51, 0x16f87, Mark),
+  (0x16f8f, 0x16f92, Mark), (0x16f93, 0x16f9f, Letter), (0x16fe0, 0x16fe1, Letter),
+  (0x16fe2, 0x16fe2, Punctuation), (0x16fe3, 0x16fe3, Letter), (0x16fe4, 0x16fe4, Mark),
+  (0x16ff0, 0x16ff1, Mark), (0x17000, 0x187f7, Letter), (0x18800, 0x18cd5, Letter),
+  (0x18d00, 0x18d08, Letter), (0x1aff0, 0x1aff3, Letter), (0x1aff5, 0x1affb, Letter),
+  (0x1affd, 0x1affe, Letter), (0x1b000, 0x1b122, Letter), (0x1b150, 0x1b152, Letter),
+  (0x1b164, 0x1b167, Letter), (0x1b170, 0x1b2fb, Letter), (0x1bc00, 0x1bc6a, Letter),
+  (0x1bc70, 0x1bc7c, Letter), (0x1bc80, 0x1bc88, Letter), (0x1bc90, 0x1bc99, Letter),
+  (0x1bc9c, 0x1bc9c, Symbol), (0x1bc9d, 0x1bc9e, Mark), (0x1bc9f, 0x1bc9f, Punctuation),
+  (0x1cf00, 0x1cf2d, Mark), (0x1cf30, 0x1cf46, Mark), (0x1cf50, 0x1cfc3, Symbol),
+  (0x1d000, 0x1d0f5, Symbol), (0x1d100, 0x1d126, Symbol), (0x1d129, 0x1d164, Symbol),
+  (0x1d165, 0x1d169, Mark), (0x1d16a, 0x1d16c, Symbol), (0x1d16d, 0x1d172, Mark),
+  (0x1d17b, 0x1d182, Mark), (0x1d183, 0x1d184, Symbol), (0x1d185, 0x1d18b, Mark),
+  (0x1d18c, 0x1d1a9, Symbol), (0x1d1aa, 0x1d1ad, Mark), (0x1d1ae, 0x1d1ea, Symbol),
+  (0x1d200, 0x1d241, Symbol), (0x1d242, 0x1d244, Mark), (0x1d245, 0x1d245, Symbol),
+  (0x1d2e0, 0x1d2f3, Number), (0x1d300, 0x1d356, Symbol), (0x1d360, 0x1d378, Number),
+  (0x1d400, 0x1d454, Letter), (0x1d456, 0x1d49c, Letter), (0x1d49e, 0x1d49f, Letter),
+  (0x1d4a2, 0x1d4a2, Letter), (0x1d4a5, 0x1d4a6, Letter), (0x1d4a9, 0x1d4ac, Letter),
+  (0x1d4ae, 0x1d4b9, Letter), (0x1d4bb, 0x1d4bb, Letter), (0x1d4bd, 0x1d4c3, Letter),
+  (0x1d4c5, 0x1d505, Letter), (0x1d507, 0x1d50a, Letter), (0x1d50d, 0x1d514, Letter),
+  (0x1d516, 0x1d51c, Letter), (0x1d51e, 0x1d539, Letter), (0x1d53b, 0x1d53e, Letter),
+  (0x1d540, 0x1d544, Letter), (0x1d546, 0x1d546, Letter), (0x1d54a, 0x1d550, Letter),
+  (0x1d552, 0x1d6a5, Letter), (0x1d6a8, 0x1d6c0, Letter), (0x1d6c1, 0x1d6c1, Symbol),
+  (0x1d6c2, 0x1d6da, Letter), (0x1d6db, 0x1d6db, Symbol), (0x1d6dc, 0x1d6fa, Letter),
+  (0x1d6fb, 0x1d6fb, Symbol), (0x1d6fc, 0x1d714, Letter), (0x1d715, 0x1d715, Symbol),
+  (0x1d716, 0x1d734, Letter), (0x1d735, 0x1d735, Symbol), (0x1d736, 0x1d74e, Letter),
+  (0x1d74f, 0x1d74f, Symbol), (0x1d750, 0x1d76e, Letter), (0x1d76f, 0x1d76f, Symbol),
+  (0x1d770, 0x1d788, Letter), (0x1d789, 0x1d789, Symbol), (0x1d78a, 0x1d7a8, Letter),
+  (0x1d7a9, 0x1d7a9, Symbol), (0x1d7aa, 0x1d7c2, Letter), (0x1d7c3, 0x1d7c3, Symbol),
+  (0x1d7c4, 0x1d7cb, Letter), (0x1d7ce, 0x1d7ff, Number), (0x1d800, 0x1d9ff, Symbol),
+  (0x1da00, 0x1da36, Mark), (0x1da37, 0x1da3a, Symbol), (0x1da3b, 0x1da6c, Mark),
+  (0x1da6d, 0x1da74, Symbol), (0x1da75, 0x1da75, Mark), (0x1da76, 0x1da83, Symbol),
+  (0x1da84, 0x1da84, Mark), (0x1da85, 0x1da86, Symbol), (0x1da87, 0x1da8b, Punctuation),
+  (0x1da9b, 0x1da9f, Mark), (0x1daa1, 0x1daaf, Mark), (0x1df00, 0x1df1e, Letter),
+  (0x1e000, 0x1e006, Mark), (0x1e008, 0x1e018, Mark), (0x1e01b, 0x1e021, Mark),
+  (0x1e023, 0x1e024, Mark), (0x1e026, 0x1e02a, Mark), (0x1e100, 0x1e12c, Letter),
+  (0x1e130, 0x1e136, Mark), (0x1e137, 0x1e13d, Letter), (0x1e140, 0x1e149, Number),
+  (0x1e14e, 0x1e14e, Letter), (0x1e14f, 0x1e14f, Symbol), (0x1e290, 0x1e2ad, Letter),
+  (0x1e2ae, 0x1e2ae, Mark), (0x1e2c0, 0x1e2eb, Letter), (0x1e2ec, 0x1e2ef, Mark),
+  (0x1e2f0, 0x1e2f9, Number), (0x1e2ff, 0x1e2ff, Symbol), (0x1e7e0, 0x1e7e6, Letter),
+  (0x1e7e8, 0x1e7eb, Letter), (0x1e7ed, 0x1e7ee, Letter), (0x1e7f0, 0x1e7fe, Letter),
+  (0x1e800, 0x1e8c4, Letter), (0x1e8c7, 0x1e8cf, Number), (0x1e8d0, 0x1e8d6, Mark),
+  (0x1e900, 0x1e943, Letter), (0x1e944, 0x1e94a, Mark), (0x1e94b, 0x1e94b, Letter),
+  (0x1e950, 0x1e959, Number), (0x1e95e, 0x1e95f, Punctuation), (0x1ec71, 0x1ecab, Number),
+  (0x1ecac, 0x1ecac, Symbol), (0x1ecad, 0x1ecaf, Number), (0x1ecb0, 0x1ecb0, Symbol),
+  (0x1ecb1, 0x1ecb4, Number), (0x1ed01, 0x1ed2d, Number), (0x1ed2e, 0x1ed2e, Symbol),
+  (0x1ed2f, 0x1ed3d, Number), (0x1ee00, 0x1ee03, Letter), (0x1ee05, 0x1ee1f, Letter),
+  (0x1ee21, 0x1ee22, Letter), (0x1ee24, 0x1ee24, Letter), (0x1ee27, 0x1ee27, Letter),
+  (0x1ee29, 0x1ee32, Letter), (0x1ee34, 0x1ee37, Letter), (0x1ee39, 0x1ee39, Letter),
+  (0x1ee3b, 0x1ee3b, Letter), (0x1ee42, 0x1ee42, Letter), (0x1ee47, 0x1ee47, Letter),
+  (0x1ee49, 0x1ee49, Letter), (0x1ee4b, 0x1ee4b, Letter), (0x1ee4d, 0x1ee4f, Letter),
+  (0x1ee51, 0x1ee52, Letter), (0x1ee54, 0x1ee54, Letter), (0x1ee57, 0x1ee57, Letter),
+  (0x1ee59, 0x1ee59, Letter), (0x1ee5b, 0x1ee5b, Letter), (0x1ee5d, 0x1ee5d, Letter),
+  (0x1ee5f, 0x1ee5f, Letter), (0x1ee61, 0x1ee62, Letter), (0x1ee64, 0x1ee64, Letter),
+  (0x1ee67, 0x1ee6a, Letter), (0x1ee6c, 0x1ee72, Letter), (0x1ee74, 0x1ee77, Letter),
+  (0x1ee79, 0x1ee7c, Letter), (0x1ee7e, 0x1ee7e, Letter), (0x1ee80, 0x1ee89, Letter),
+  (0x1ee8b, 0x1ee9b, Letter), (0x1eea1, 0x1eea3, Letter), (0x1eea5, 0x1eea9, Letter),
+  (0x1eeab, 0x1eebb, Letter), (0x1eef0, 0x1eef1, Symbol), (0x1f000, 0x1f02b, Symbol),
+  (0x1f030, 0x1f093, Symbol), (0x1f0a0, 0x1f0ae, Symbol), (0x1f0b1, 0x1f0bf, Symbol),
+  (0x1f0c1, 0x1f0cf, Symbol), (0x1f0d1, 0x1f0f5, Symbol), (0x1f100, 0x1f10c, Number),
+  (0x1f10d, 0x1f1ad, Symbol), (0x1f1e6, 0x1f202, Symbol), (0x1f210, 0x1f23b, Symbol),
+  (0x1f240, 0x1f248, Symbol), (0x1f250, 0x1f251, Symbol), (0x1f260, 0x1f265, Symbol),
+  (0x1f300, 0x1f6d7, Symbol), (0x1f6dd, 0x1f6ec, Symbol), (0x1f6f0, 0x1f6fc, Symbol),
+  (0x1f700, 0x1f773, Symbol), (0x1f780, 0x1f7d8, Symbol), (0x1f7e0, 0x1f7eb, Symbol),
+  (0x1f7f0, 0x1f7f0, Symbol), (0x1f800, 0x1f80b, Symbol), (0x1f810, 0x1f847, Symbol),
+  (0x1f850, 0x1f859, Symbol), (0x1f860, 0x1f887, Symbol), (0x1f890, 0x1f8ad, Symbol),
+  (0x1f8b0, 0x1f8b1, Symbol), (0x1f900, 0x1fa53, Symbol), (0x1fa60, 0x1fa6d, Symbol),
+  (0x1fa70, 0x1fa74, Symbol), (0x1fa78, 0x1fa7c, Symbol), (0x1fa80, 0x1fa86, Symbol),
+  (0x1fa90, 0x1faac, Symbol), (0x1fab0, 0x1faba, Symbol), (0x1fac0, 0x1fac5, Symbol),
+  (0x1fad0, 0x1fad9, Symbol), (0x1fae0, 0x1fae7, Symbol), (0x1faf0, 0x1faf6, Symbol),
+  (0x1fb00, 0x1fb92, Symbol), (0x1fb94, 0x1fbca, Symbol), (0x1fbf0, 0x1fbf9, Number),
+  (0x20000, 0x2a6df, Letter), (0x2a700, 0x2b738, Letter), (0x2b740, 0x2b81d, Letter),
+  (0x2b820, 0x2cea1, Letter), (0x2ceb0, 0x2ebe0, Letter), (0x2f800, 0x2fa1d, Letter),
+  (0x30000, 0x3134a, Letter), (0xe0100, 0xe01ef, Mark),
+];
+
+pub static CASE_FOLDING: &'static [(char, &'static str)] = &[
+  ('\u{b5}', "\u{3bc}"), ('\u{df}', "ss"), ('\u{149}', "\u{2bc}n"),
+  ('\u{17f}', "s"), ('\u{1f0}', "j\u{30c}"), ('\u{345}', "\u{3b9}"),
+  ('\u{390}', "\u{3b9}\u{308}\u{301}"), ('\u{3b0}', "\u{3c5}\u{308}\u{301}"),
+  ('\u{3c2}', "\u{3c3}"), ('\u{3d0}', "\u{3b2}"), ('\u{3d1}', "\u{3b8}"), ('\u{3d5}', "\u{3c6}"),
+  ('\u{3d6}', "\u{3c0}"), ('\u{3f0}', "\u{3ba}"), ('\u{3f1}', "\u{3c1}"), ('\u{3f5}', "\u{3b5}"),
+  ('\u{587}', "\u{565}\u{582}"), ('\u{13a0}', "\u{13a0}"), ('\u{13a1}', "\u{13a1}"),
+  ('\u{13a2}', "\u{13a2}"), ('\u{13a3}', "\u{13a3}"), ('\u{13a4}', "\u{13a4}"),
+  ('\u{13a5}', "\u{13a5}"), ('\u{13a6}', "\u{13a6}"), ('\u{13a7}', "\u{13a7}"),
+  ('\u{13a8}', "\u{13a8}"), ('\u{13a9}', "\u{13a9}"), ('\u{13aa}', "\u{13aa}"),
+  ('\u{13ab}', "\u{13ab}"), ('\u{13ac}', "\u{13ac}"), ('\u{13ad}', "\u{13ad}"),
+  ('\u{13ae}', "\u{13ae}"), ('\u{13af}', "\u{13af}"), ('\u{13b0}', "\u{13b0}"),
+  ('\u{13b1}', "\u{13b1}"), ('\u{13b2}', "\u{13b2}"), ('\u{13b3}', "\u{13b3}"),
+  ('\u{13b4}', "\u{13b4}"), ('\u{13b5}', "\u{13b5}"), ('\u{13b6}', "\u{13b6}"),
+  ('\u{13b7}', "\u{13b7}"), ('\u{13b8}', "\u{13b8}"), ('\u{13b9}', "\u{13b9}"),
+  ('\u{13ba}', "\u{13ba}"), ('\u{13bb}', "\u{13bb}"), ('\u{13bc}', "\u{13bc}"),
+  ('\u{13bd}', "\u{13bd}"), ('\u{13be}', "\u{13be}"), ('\u{13bf}', "\u{13bf}"),
+  ('\u{13c0}', "\u{13c0}"), ('\u{13c1}', "\u{13c1}"), ('\u{13c2}', "\u{13c2}"),
+  ('\u{13c3}', "\u{13c3}"), ('\u{13c4}', "\u{13c4}"), ('\u{13c5}', "\u{13c5}"),
+  ('\u{13c6}', "\u{13c6}"), ('\u{13c7}', "\u{13c7}"), ('\u{13c8}', "\u{13c8}"),
+  ('\u{13c9}', "\u{13c9}"), ('\u{13ca}', "\u{13ca}"), ('\u{13cb}', "\u{13cb}"),
+  ('\u{13cc}', "\u{13cc}"), ('\u{13cd}', "\u{13cd}"), ('\u{13ce}', "\u{13ce}"),
+  ('\u{13cf}', "\u{13cf}"), ('\u{13d0}', "\u{13d0}"), ('\u{13d1}', "\u{13d1}"),
+  ('\u{13d2}', "\u{13d2}"), ('\u{13d3}', "\u{13d3}"), ('\u{13d4}', "\u{13d4}"),
+  ('\u{13d5}', "\u{13d5}"), ('\u{13d6}', "\u{13d6}"), ('\u{13d7}', "\u{13d7}"),
+  ('\u{13d8}', "\u{13d8}"), ('\u{13d9}', "\u{13d9}"), ('\u{13da}', "\u{13da}"),
+  ('\u{13db}', "\u{13db}"), ('\u{13dc}', "\u{13dc}"), ('\u{13dd}', "\u{13dd}"),
+  ('\u{13de}', "\u{13de}"), ('\u{13df}', "\u{13df}"), ('\u{13e0}', "\u{13e0}"),
+  ('\u{13e1}', "\u{13e1}"), ('\u{13e2}', "\u{13e2}"), ('\u{13e3}', "\u{13e3}"),
+  ('\u{13e4}', "\u{13e4}"), ('\u{13e5}', "\u{13e5}"), ('\u{13e6}', "\u{13e6}"),
+  ('\u{13e7}', "\u{13e7}"), ('\u{13e8}', "\u{13e8}"), ('\u{13e9}', "\u{13e9}"),
+  ('\u{13ea}', "\u{13ea}"), ('\u{13eb}', "\u{13eb}"), ('\u{13ec}', "\u{13ec}"),
+  ('\u{13ed}', "\u{13ed}"), ('\u{13ee}', "\u{13ee}"), ('\u{13ef}', "\u{13ef}"),
+  ('\u{13f0}', "\u{13f0}"), ('\u{13f1}', "\u{13f1}"), ('\u{13f2}', "\u{13f2}"),
+  ('\u{13f3}', "\u{13f3}"), ('\u{13f4}', "\u{13f4}"), ('\u{13f5}', "\u{13f5}"),
+  ('\u{13f8}', "\u{13f0}"), ('\u{13f9}', "\u{13f1}"), ('\u{13fa}', "\u{13f2}"),
+  ('\u{13fb}', "\u{13f3}"), ('\u{13fc}', "\u{13f4}"), ('\u{13fd}', "\u{13f5}"),
+  ('\u{1c80}', "\u{432}"), ('\u{1c81}', "\u{434}"), ('\u{1c82}', "\u{43e}"),
+  ('\u{1c83}', "\u{441}"), ('\u{1c84}', "\u{442}"), ('\u{1c85}', "\u{442}"),
+  ('\u{1c86}', "\u{44a}"), ('\u{1c87}', "\u{463}"), ('\u{1c88}', "\u{a64b}"),
+  ('\u{1e96}', "h\u{331}"), ('\u{1e97}', "t\u{308}"), ('\u{1e98}', "w\u{30a}"),
+  ('\u{1e99}', "y\u{30a}"), ('\u{1e9a}', "a\u{2be}"), ('\u{1e9b}', "\u{1e61}"),
+  ('\u{1e9e}', "ss"), ('\u{1f50}', "\u{3c5}\u{313}"),
+  ('\u{1f52}', "\u{3c5}\u{313}\u{300}"), ('\u{1f54}', "\u{3c5}\u{313}\u{301}"),
+  ('\u{1f56}', "\u{3c5}\u{313}\u{342}"), ('\u{1f80}', "\u{1f00}\u{3b9}"),
+  ('\u{1f81}', "\u{1f01}\u{3b9}"), ('\u{1f82}', "\u{1f02}\u{3b9}"), ('\u{1f83}', "\u{1f03}\u{3b9}"),
+  ('\u{1f84}', "\u{1f04}\u{3b9}"), ('\u{1f85}', "\u{1f05}\u{3b9}"), ('\u{1f86}', "\u{1f06}\u{3b9}"),
+  ('\u{1f87}', "\u{1f07}\u{3b9}"), ('\u{1f88}', "\u{1f00}\u{3b9}"), ('\u{1f89}', "\u{1f01}\u{3b9}"),
+  ('\u{1f8a}', "\u{1f02}\u{3b9}"), ('\u{1f8b}', "\u{1f03}\u{3b9}"), ('\u{1f8c}', "\u{1f04}\u{3b9}"),
+  ('\u{1f8d}', "\u{1f05}\u{3b9}"), ('\u{1f8e}', "\u{1f06}\u{3b9}"), ('\u{1f8f}', "\u{1f07}\u{3b9}"),
+  ('\u{1f90}', "\u{1f20}\u{3b9}"), ('\u{1f91}', "\u{1f21}\u{3b9}"), ('\u{1f92}', "\u{1f22}\u{3b9}"),
+  ('\u{1f93}', "\u{1f23}\u{3b9}"), ('\u{1f94}', "\u{1f24}\u{3b9}"), ('\u{1f95}', "\u{1f25}\u{3b9}"),
+  ('\u{1f96}', "\u{1f26}\u{3b9}"), ('\u{1f97}', "\u{1f27}\u{3b9}"), ('\u{1f98}', "\u{1f20}\u{3b9}"),
+  ('\u{1f99}', "\u{1f21}\u{3b9}"), ('\u{1f9a}', "\u{1f22}\u{3b9}"), ('\u{1f9b}', "\u{1f23}\u{3b9}"),
+  ('\u{1f9c}', "\u{1f24}\u{3b9}"), ('\u{1f9d}', "\u{1f25}\u{3b9}"), ('\u{1f9e}', "\u{1f26}\u{3b9}"),
+  ('\u{1f9f}', "\u{1f27}\u{3b9}"), ('\u{1fa0}', "\u{1f60}\u{3b9}"), ('\u{1fa1}', "\u{1f61}\u{3b9}"),
+  ('\u{1fa2}', "\u{1f62}\u{3b9}"), ('\u{1fa3}', "\u{1f63}\u{3b9}"), ('\u{1fa4}', "\u{1f64}\u{3b9}"),
+  ('\u{1fa5}', "\u{1f65}\u{3b9}"), ('\u{1fa6}', "\u{1f66}\u{3b9}"), ('\u{1fa7}', "\u{1f67}\u{3b9}"),
+  ('\u{1fa8}', "\u{1f60}\u{3b9}"), ('\u{1fa9}', "\u{1f61}\u{3b9}"), ('\u{1faa}', "\u{1f62}\u{3b9}"),
+  ('\u{1fab}', "\u{1f63}\u{3b9}"), ('\u{1fac}', "\u{1f64}\u{3b9}"), ('\u{1fad}', "\u{1f65}\u{3b9}"),
+  ('\u{1fae}', "\u{1f66}\u{3b9}"), ('\u{1faf}', "\u{1f67}\u{3b9}"), ('\u{1fb2}', "\u{1f70}\u{3b9}"),
+  ('\u{1fb3}', "\u{3b1}\u{3b9}"), ('\u{1fb4}', "\u{3ac}\u{3b9}"), ('\u{1fb6}', "\u{3b1}\u{342}"),
+  ('\u{1fb7}', "\u{3b1}\u{342}\u{3b9}"), ('\u{1fbc}', "\u{3b1}\u{3b9}"), ('\u{1fbe}', "\u{3b9}"),
+  ('\u{1fc2}', "\u{1f74}\u{3b9}"), ('\u{1fc3}', "\u{3b7}\u{3b9}"), ('\u{1fc4}', "\u{3ae}\u{3b9}"),
+  ('\u{1fc6}', "\u{3b7}\u{342}"), ('\u{1fc7}', "\u{3b7}\u{342}\u{3b9}"),
+  ('\u{1fcc}', "\u{3b7}\u{3b9}"), ('\u{1fd2}', "\u{3b9}\u{308}\u{300}"),
+  ('\u{1fd3}', "\u{3b9}\u{308}\u{301}"), ('\u{1fd6}', "\u{3b9}\u{342}"),
+  ('\u{1fd7}', "\u{3b9}\u{308}\u{342}"), ('\u{1fe2}', "\u{3c5}\u{308}\u{300}"),
+  ('\u{1fe3}', "\u{3c5}\u{308}\u{301}"), ('\u{1fe4}', "\u{3c1}\u{313}"),
+  ('\u{1fe6}', "\u{3c5}\u{342}"), ('\u{1fe7}', "\u{3c5}\u{308}\u{342}"),
+  ('\u{1ff2}', "\u{1f7c}\u{3b9}"), ('\u{1ff3}', "\u{3c9}\u{3b9}"), ('\u{1ff4}', "\u{3ce}\u{3b9}"),
+  ('\u{1ff6}', "\u{3c9}\u{342}"), ('\u{1ff7}', "\u{3c9}\u{342}\u{3b9}"),
+  ('\u{1ffc}', "\u{3c9}\u{3b9}"), ('\u{ab70}', "\u{13a0}"), ('\u{ab71}', "\u{13a1}"),
+  ('\u{ab72}', "\u{13a2}"), ('\u{ab73}', "\u{13a3}"), ('\u{ab74}', "\u{13a4}"),
+  ('\u{ab75}', "\u{13a5}"), ('\u{ab76}', "\u{13a6}"), ('\u{ab77}', "\u{13a7}"),
+  ('\u{ab78}', "\u{13a8}"), ('\u{ab79}', "\u{13a9}"), ('\u{ab7a}', "\u{13aa}"),
+  ('\u{ab7b}', "\u{13ab}"), ('\u{ab7c}', "\u{13ac}"), ('\u{ab7d}', "\u{13ad}"),
+  ('\u{ab7e}', "\u{13ae}"), ('\u{ab7f}', "\u{13af}"), ('\u{ab80}', "\u{13b0}"),
+  ('\u{ab81}', "\u{13b1}"), ('\u{ab82}', "\u{13b2}"), ('\u{ab83}', "\u{13b3}"),
+  ('\u{ab84}', "\u{13b4}"), ('\u{ab85}', "\u{13b5}"), ('\u{ab86}', "\u{13b6}"),
+  ('\u{ab87}', "\u{13b7}"), ('\u{ab88}', "\u{13b8}"), ('\u{ab89}', "\u{13b9}"),
+  ('\u{ab8a}', "\u{13ba}"), ('\u{ab8b}', "\u{13bb}"), ('\u{ab8c}', "\u{13bc}"),
+  ('\u{ab8d}', "\u{13bd}"), ('\u{ab8e}', "\u{13be}"), ('\u{ab8f}', "\u{13bf}"),
+  ('\u{ab90}', "\u{13c0}"), ('\u{ab91}', "\u{13c1}"), ('\u{ab92}', "\u{13c2}"),
+  ('\u{ab93}', "\u{13c3}"), ('\u{ab94}', "\u{13c4}"), ('\u{ab95}', "\u{13c5}"),
+  ('\u{ab96}', "\u{13c6}"), ('\u{ab97}', "\u{13c7}"), ('\u{ab98}', "\u{13c8}"),
+  ('\u{ab99}', "\u{13c9}"), ('\u{ab9a}', "\u{13ca}"), ('\u{ab9b}', "\u{13cb}"),
+  ('\u{ab9c}', "\u{13cc}"), ('\u{ab9d}', "\u{13cd}"), ('\u{ab9e}', "\u{13ce}"),
+  ('\u{ab9f}', "\u{13cf}"), ('\u{aba0}', "\u{13d0}"), ('\u{aba1}', "\u{13d1}"),
+  ('\u{aba2}', "\u{13d2}"), ('\u{aba3}', "\u{13d3}"), ('\u{aba4}', "\u{13d4}"),
+  ('\u{aba5}', "\u{13d5}"), ('\u{aba6}', "\u{13d6}"), ('\u{aba7}', "\u{13d7}"),
+  ('\u{aba8}', "\u{13d8}"), ('\u{aba9}', "\u{13d9}"), ('\u{abaa}', "\u{13da}"),
+  ('\u{abab}', "\u{13db}"), ('\u{abac}', "\u{13dc}"), ('\u{abad}', "\u{13dd}"),
+  ('\u{abae}', "\u{13de}"), ('\u{abaf}', "\u{13df}"), ('\u{abb0}', "\u{13e0}"),
+  ('\u{abb1}', "\u{13e1}"), ('\u{abb2}', "\u{13e2}"), ('\u{abb3}', "\u{13e3}"),
+  ('\u{abb4}', "\u{13e4}"), ('\u{abb5}', "\u{13e5}"), ('\u{abb6}', "\u{13e6}"),
+  ('\u{abb7}', "\u{13e7}"), ('\u{abb8}', "\u{13e8}"), ('\u{abb9}', "\u{13e9}"),
+  ('\u{abba}', "\u{13ea}"), ('\u{abbb}', "\u{13eb}"), ('\u{abbc}', "\u{13ec}"),
+  ('\u{abbd}', "\u{13ed}"), ('\u{abbe}', "\u{13ee}"), ('\u{abbf}', "\u{13ef}"),
+  ('\u{fb00}', "ff"), ('\u{fb01}', "fi"), ('\u{fb02}', "fl"),
+  ('\u{fb03}', "ffi"), ('\u{fb04}', "ffl"),
+  ('\u{fb05}', "st"), ('\u{fb06}', "st"), ('\u{fb13}', "\u{574}\u{576}"),
+  ('\u{fb14}', "\u{574}\u{565}"), ('\u{fb15}', "\u{574}\u{56b}"), ('\u{fb16}', "\u{57e}\u{576}"),
+  ('\u{fb17}', "\u{574}\u{56d}"),
+];
@@ -7,11 +7,14 @@ use verbose_errors::Err;
 
 #[cfg(not(feature = "core"))]
 use std::collections::HashMap;
+#[cfg(not(feature = "core"))]
+use unicode_tables::CASE_FOLDING;
 
 #[cfg(feature = "core")]
 use std::prelude::v1::*;
 use std::vec::Vec;
 use std::string::ToString;
+use internal::Needed;
 
 /// useful functions to calculate the offset between slices and show a hexdump of a slice
 #[cfg(not(feature = "core"))]
@@ -50,6 +53,26 @@ impl<'a> InputLength for &'a str {
   }
 }
 
+/// checks that an input can be split at an index, for `sized_value!`
+#[doc(hidden)]
+pub trait InputBoundary {
+  fn is_boundary(&self, index: usize) -> bool;
+}
+
+impl<'a, T> InputBoundary for &'a[T] {
+  #[inline]
+  fn is_boundary(&self, index: usize) -> bool {
+    index <= self.len()
+  }
+}
+
+impl<'a> InputBoundary for &'a str {
+  #[inline]
+  fn is_boundary(&self, index: usize) -> bool {
+    self.is_char_boundary(index)
+  }
+}
+
 impl<'a> InputLength for (&'a [u8], usize) {
   #[inline]
   fn input_len(&self) -> usize {
@@ -144,6 +167,127 @@ impl<'a> IterIndices for &'a str {
     }
 }
 
+/// result of a case-insensitive comparison of the beginning of an input with a tag
+#[doc(hidden)]
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum NocaseMatch {
+  /// the tag matches the given length of input
+  Match(usize),
+  /// the input matches the beginning of the tag
+  Incomplete,
+  Mismatch,
+}
+
+#[cfg(not(feature = "core"))]
+/// full Unicode case folding of a character, see `fold_case`
+#[doc(hidden)]
+#[derive(Debug,Clone)]
+pub enum FoldCase {
+  Folded(::std::str::Chars<'static>),
+  Lowercase(::std::char::ToLowercase),
+}
+
+#[cfg(not(feature = "core"))]
+impl Iterator for FoldCase {
+  type Item = char;
+
+  #[inline]
+  fn next(&mut self) -> Option<char> {
+    match *self {
+      FoldCase::Folded(ref mut chars)   => chars.next(),
+      FoldCase::Lowercase(ref mut chars) => chars.next(),
+    }
+  }
+}
+
+#[cfg(not(feature = "core"))]
+/// full Unicode case folding, used to compare characters regardless of case
+///
+/// It is the lowercase mapping, except for the characters whose full case folding differs:
+/// `ß` folds to `ss`, and `ς` to `σ`, so that `Σ`, `σ` and `ς` are equal.
+#[doc(hidden)]
+#[inline]
+pub fn fold_case(c: char) -> FoldCase {
+  if !c.is_ascii() {
+    if let Ok(idx) = CASE_FOLDING.binary_search_by_key(&c, |&(k, _)| k) {
+      return FoldCase::Folded(CASE_FOLDING[idx].1.chars());
+    }
+  }
+  FoldCase::Lowercase(c.to_lowercase())
+}
+
+#[cfg(not(feature = "core"))]
+/// compares the beginning of a sequence of decoded characters with a tag, ignoring case
+fn tag_nocase_chars<I: Iterator<Item = DecodedChar>>(input: I, tag: &str) -> NocaseMatch {
+  let mut expected = tag.chars().flat_map(fold_case);
+  let mut pending  = expected.next();
+  if pending.is_none() {
+    return NocaseMatch::Match(0);
+  }
+
+  let mut idx = 0;
+  for decoded in input {
+    match decoded {
+      DecodedChar::Char(c, len)  => {
+        for l in fold_case(c) {
+          match pending {
+            Some(e) if e == l => pending = expected.next(),
+            _                 => return NocaseMatch::Mismatch,
+          }
+        }
+        idx += len;
+        if pending.is_none() {
+          return NocaseMatch::Match(idx);
+        }
+      },
+      DecodedChar::Incomplete(_) => return NocaseMatch::Incomplete,
+      DecodedChar::Invalid       => return NocaseMatch::Mismatch,
+    }
+  }
+  NocaseMatch::Incomplete
+}
+
+#[cfg(not(feature = "core"))]
+/// compares the beginning of the input with a tag, ignoring case
+///
+/// The matched input can have a different length than the tag, as some
+/// characters have a case folding of a different size
+#[doc(hidden)]
+pub fn tag_nocase_len(input: &str, tag: &str) -> NocaseMatch {
+  tag_nocase_chars(input.chars().map(|c| DecodedChar::Char(c, c.len_utf8())), tag)
+}
+
+/// decodes the characters of a UTF-8 byte slice, stopping after an invalid or truncated sequence
+#[cfg(not(feature = "core"))]
+struct Utf8Chars<'a>(&'a [u8]);
+
+#[cfg(not(feature = "core"))]
+impl<'a> Iterator for Utf8Chars<'a> {
+  type Item = DecodedChar;
+
+  fn next(&mut self) -> Option<DecodedChar> {
+    if self.0.is_empty() {
+      return None;
+    }
+    let decoded = decode_utf8(self.0);
+    self.0 = match decoded {
+      DecodedChar::Char(_, len) => &self.0[len..],
+      _                         => &self.0[..0],
+    };
+    Some(decoded)
+  }
+}
+
+#[cfg(not(feature = "core"))]
+/// compares the beginning of an UTF-8 byte slice with a tag, ignoring case
+///
+/// Invalid UTF-8 does not match, but a sequence truncated at the end of the input may.
+/// Only the characters compared with the tag are decoded
+#[doc(hidden)]
+pub fn tag_nocase_len_bytes(input: &[u8], tag: &str) -> NocaseMatch {
+  tag_nocase_chars(Utf8Chars(input), tag)
+}
+
 static CHARS: &'static[u8] = b"0123456789abcdef";
 
 #[cfg(not(feature = "core"))]
@@ -156,6 +300,16 @@ impl Offset for [u8] {
   }
 }
 
+#[cfg(not(feature = "core"))]
+impl Offset for str {
+  fn offset(&self, second:&str) -> usize {
+    let fst = self.as_ptr();
+    let snd = second.as_ptr();
+
+    snd as usize - fst as usize
+  }
+}
+
 #[cfg(not(feature = "core"))]
 impl HexDisplay for [u8] {
   #[allow(unused_variables)]
@@ -592,6 +746,40 @@ macro_rules! array_impls {
           self
         }
       }
+
+      impl<'a> CharSet for &'a [u8; $N] {
+        #[inline]
+        fn contains_char(&self, c: char) -> bool {
+          self.iter().any(|&b| b as char == c)
+        }
+
+        #[inline]
+        fn contains_char_nocase(&self, c: char) -> bool {
+          self.iter().any(|&b| (b as char).eq_ignore_ascii_case(&c))
+        }
+
+        #[inline]
+        fn is_byte_set(&self) -> bool {
+          true
+        }
+      }
+
+      impl CharSet for [u8; $N] {
+        #[inline]
+        fn contains_char(&self, c: char) -> bool {
+          self.iter().any(|&b| b as char == c)
+        }
+
+        #[inline]
+        fn contains_char_nocase(&self, c: char) -> bool {
+          self.iter().any(|&b| (b as char).eq_ignore_ascii_case(&c))
+        }
+
+        #[inline]
+        fn is_byte_set(&self) -> bool {
+          true
+        }
+      }
     )+
   };
 }
@@ -604,6 +792,252 @@ array_impls! {
     30 31 32
 }
 
+/// result of decoding the first character of an input
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum DecodedChar {
+  /// the character and its length in bytes
+  Char(char, usize),
+  /// the input is empty or ends inside a character
+  Incomplete(Needed),
+  /// the input does not start with a valid UTF-8 sequence
+  Invalid,
+}
+
+/// decodes the first character of a UTF-8 byte slice
+///
+/// Overlong encodings, surrogates and code points above `U+10FFFF` are invalid.
+/// If the input ends inside a valid sequence, it returns the total length of that sequence
+/// in `Incomplete`.
+///
+/// ```
+/// # use nom::{decode_utf8,DecodedChar,Needed};
+/// assert_eq!(decode_utf8(&b"\xc3\xa9t\xc3\xa9"[..]), DecodedChar::Char('é', 2));
+/// assert_eq!(decode_utf8(&b"\xe2\x82"[..]), DecodedChar::Incomplete(Needed::Size(3)));
+/// assert_eq!(decode_utf8(&b"\xc0\xaf"[..]), DecodedChar::Invalid);
+/// ```
+pub fn decode_utf8(input: &[u8]) -> DecodedChar {
+  if input.is_empty() {
+    return DecodedChar::Incomplete(Needed::Size(1));
+  }
+
+  let first = input[0];
+  // the range of the second byte excludes overlong encodings, surrogates and values above U+10FFFF
+  let (width, mut value, second) = match first {
+    0x00...0x7F => return DecodedChar::Char(first as char, 1),
+    0xC2...0xDF => (2, (first & 0x1F) as u32, (0x80, 0xBF)),
+    0xE0        => (3, (first & 0x0F) as u32, (0xA0, 0xBF)),
+    0xED        => (3, (first & 0x0F) as u32, (0x80, 0x9F)),
+    0xE1...0xEF => (3, (first & 0x0F) as u32, (0x80, 0xBF)),
+    0xF0        => (4, (first & 0x07) as u32, (0x90, 0xBF)),
+    0xF4        => (4, (first & 0x07) as u32, (0x80, 0x8F)),
+    0xF1...0xF3 => (4, (first & 0x07) as u32, (0x80, 0xBF)),
+    _           => return DecodedChar::Invalid,
+  };
+
+  for i in 1..width {
+    if i == input.len() {
+      return DecodedChar::Incomplete(Needed::Size(width));
+    }
+    let (low, high) = if i == 1 { second } else { (0x80, 0xBF) };
+    let b = input[i];
+    if b < low || b > high {
+      return DecodedChar::Invalid;
+    }
+    value = (value << 6) | (b & 0x3F) as u32;
+  }
+
+  match ::std::char::from_u32(value) {
+    Some(c) => DecodedChar::Char(c, width),
+    None    => DecodedChar::Invalid,
+  }
+}
+
+/// inputs from which a character can be decoded: UTF-8 byte slices and strings
+pub trait FirstChar {
+  /// decodes the first character of the input
+  fn first_char(&self) -> DecodedChar;
+  /// reads the first byte of a byte slice as the character with the same code point,
+  /// and decodes the first character of a string
+  fn first_byte_char(&self) -> DecodedChar;
+}
+
+impl<'a> FirstChar for &'a [u8] {
+  #[inline]
+  fn first_char(&self) -> DecodedChar {
+    decode_utf8(self)
+  }
+
+  #[inline]
+  fn first_byte_char(&self) -> DecodedChar {
+    match self.first() {
+      Some(&b) => DecodedChar::Char(b as char, 1),
+      None     => DecodedChar::Incomplete(Needed::Size(1)),
+    }
+  }
+}
+
+impl<'a> FirstChar for &'a str {
+  #[inline]
+  fn first_char(&self) -> DecodedChar {
+    match self.chars().next() {
+      Some(c) => DecodedChar::Char(c, c.len_utf8()),
+      None    => DecodedChar::Incomplete(Needed::Size(1)),
+    }
+  }
+
+  #[inline]
+  fn first_byte_char(&self) -> DecodedChar {
+    self.first_char()
+  }
+}
+
+/// sets of characters used by `one_of!` and `none_of!`
+///
+/// Strings and slices of `char` are sets of characters, matched against the decoded
+/// input. Byte slices are sets of bytes, matched against the first byte of a `&[u8]` input.
+pub trait CharSet {
+  /// returns true if the set contains the character
+  fn contains_char(&self, c: char) -> bool;
+
+  /// returns true if the set contains the character, ignoring case: the case folding
+  /// of characters is compared in sets of characters, and ASCII case in sets of bytes
+  fn contains_char_nocase(&self, c: char) -> bool;
+
+  /// returns true if the set is matched byte by byte on `&[u8]` input
+  #[inline]
+  fn is_byte_set(&self) -> bool {
+    false
+  }
+}
+
+impl CharSet for str {
+  #[inline]
+  fn contains_char(&self, c: char) -> bool {
+    self.chars().any(|x| x == c)
+  }
+
+  #[inline]
+  fn contains_char_nocase(&self, c: char) -> bool {
+    self.chars().any(|x| char_eq_nocase(x, c))
+  }
+}
+
+impl<'a> CharSet for &'a str {
+  #[inline]
+  fn contains_char(&self, c: char) -> bool {
+    self.chars().any(|x| x == c)
+  }
+
+  #[inline]
+  fn contains_char_nocase(&self, c: char) -> bool {
+    self.chars().any(|x| char_eq_nocase(x, c))
+  }
+}
+
+impl CharSet for [u8] {
+  #[inline]
+  fn contains_char(&self, c: char) -> bool {
+    self.iter().any(|&b| b as char == c)
+  }
+
+  #[inline]
+  fn contains_char_nocase(&self, c: char) -> bool {
+    self.iter().any(|&b| (b as char).eq_ignore_ascii_case(&c))
+  }
+
+  #[inline]
+  fn is_byte_set(&self) -> bool {
+    true
+  }
+}
+
+impl<'a> CharSet for &'a [u8] {
+  #[inline]
+  fn contains_char(&self, c: char) -> bool {
+    self.iter().any(|&b| b as char == c)
+  }
+
+  #[inline]
+  fn contains_char_nocase(&self, c: char) -> bool {
+    self.iter().any(|&b| (b as char).eq_ignore_ascii_case(&c))
+  }
+
+  #[inline]
+  fn is_byte_set(&self) -> bool {
+    true
+  }
+}
+
+impl CharSet for [char] {
+  #[inline]
+  fn contains_char(&self, c: char) -> bool {
+    self.contains(&c)
+  }
+
+  #[inline]
+  fn contains_char_nocase(&self, c: char) -> bool {
+    self.iter().any(|&x| char_eq_nocase(x, c))
+  }
+}
+
+impl<'a> CharSet for &'a [char] {
+  #[inline]
+  fn contains_char(&self, c: char) -> bool {
+    self.contains(&c)
+  }
+
+  #[inline]
+  fn contains_char_nocase(&self, c: char) -> bool {
+    self.iter().any(|&x| char_eq_nocase(x, c))
+  }
+}
+
+/// compares two characters by their case folding
+#[cfg(not(feature = "core"))]
+#[inline]
+fn char_eq_nocase(a: char, b: char) -> bool {
+  a == b || fold_case(a).eq(fold_case(b))
+}
+
+/// compares two characters ignoring ASCII case
+#[cfg(feature = "core")]
+#[inline]
+fn char_eq_nocase(a: char, b: char) -> bool {
+  a.eq_ignore_ascii_case(&b)
+}
+
+/// characters expected by `char!`: a `char` is matched against the decoded input,
+/// and a `u8` against the first byte of a `&[u8]` input
+pub trait ExpectedChar {
+  /// the expected character
+  fn as_char(&self) -> char;
+
+  /// returns true if it is matched byte by byte on `&[u8]` input
+  #[inline]
+  fn is_byte(&self) -> bool {
+    false
+  }
+}
+
+impl ExpectedChar for char {
+  #[inline]
+  fn as_char(&self) -> char {
+    *self
+  }
+}
+
+impl ExpectedChar for u8 {
+  #[inline]
+  fn as_char(&self) -> char {
+    *self as char
+  }
+
+  #[inline]
+  fn is_byte(&self) -> bool {
+    true
+  }
+}
+
 /// indicates which parser returned an error
 #[derive(Debug,PartialEq,Eq,Hash,Clone)]
 pub enum ErrorKind<E=u32> {
@@ -663,6 +1097,26 @@ pub enum ErrorKind<E=u32> {
   TakeUntilStr,
   Not,
   Permutation,
+  LengthValueShort,
+  LengthValueLeftover,
+  Keyword,
+  Verify,
+  StringLiteral,
+  InvalidEscape,
+  InvalidUnicodeEscape,
+  Float,
+  Overflow,
+  Identifier,
+  Satisfy,
+  CharRange,
+  Utf8,
+  OddLength,
+  UnpairedSurrogate,
+  Endianness,
+  Alignment,
+  PrefixCode,
+  Radix,
+  BitOffset(usize),
 }
 
 pub fn error_to_u32<E>(e: &ErrorKind<E>) -> u32 {
@@ -723,6 +1177,26 @@ pub fn error_to_u32<E>(e: &ErrorKind<E>) -> u32 {
     ErrorKind::Many0                     => 62,
     ErrorKind::Not                       => 63,
     ErrorKind::Permutation               => 64,
+    ErrorKind::LengthValueShort          => 65,
+    ErrorKind::LengthValueLeftover       => 66,
+    ErrorKind::Keyword                   => 67,
+    ErrorKind::Verify                    => 68,
+    ErrorKind::StringLiteral             => 69,
+    ErrorKind::InvalidEscape             => 70,
+    ErrorKind::InvalidUnicodeEscape      => 71,
+    ErrorKind::Float                     => 72,
+    ErrorKind::Overflow                  => 73,
+    ErrorKind::Identifier                => 74,
+    ErrorKind::Satisfy                   => 75,
+    ErrorKind::CharRange                 => 76,
+    ErrorKind::Utf8                      => 77,
+    ErrorKind::OddLength                 => 78,
+    ErrorKind::UnpairedSurrogate         => 79,
+    ErrorKind::Endianness                => 80,
+    ErrorKind::Alignment                 => 81,
+    ErrorKind::PrefixCode                => 82,
+    ErrorKind::Radix                     => 83,
+    ErrorKind::BitOffset(_)              => 84,
   }
 }
 
@@ -785,6 +1259,26 @@ pub fn error_to_u32<E>(e: &ErrorKind<E>) -> u32 {
         ErrorKind::OctDigit                  => "Octal digit",
         ErrorKind::Not                       => "Negation",
         ErrorKind::Permutation               => "Permutation",
+        ErrorKind::LengthValueShort          => "Length delimited value too short",
+        ErrorKind::LengthValueLeftover       => "Length delimited value not fully consumed",
+        ErrorKind::Keyword                   => "Keyword",
+        ErrorKind::Verify                    => "Predicate verification",
+        ErrorKind::StringLiteral             => "String literal",
+        ErrorKind::InvalidEscape             => "Invalid escape sequence in string literal",
+        ErrorKind::InvalidUnicodeEscape      => "Invalid unicode escape sequence in string literal",
+        ErrorKind::Float                     => "Floating point number",
+        ErrorKind::Overflow                  => "Integer overflow",
+        ErrorKind::Identifier                => "Identifier",
+        ErrorKind::Satisfy                   => "Predicate on a character",
+        ErrorKind::CharRange                 => "Character range",
+        ErrorKind::Utf8                      => "Invalid UTF-8",
+        ErrorKind::OddLength                 => "Odd length of UTF-16 text",
+        ErrorKind::UnpairedSurrogate         => "Unpaired UTF-16 surrogate",
+        ErrorKind::Endianness                => "Unknown byte order magic",
+        ErrorKind::Alignment                 => "Bit stream not on a byte boundary",
+        ErrorKind::PrefixCode                => "Unknown prefix code",
+        ErrorKind::Radix                     => "Radix out of the 2 to 36 range",
+        ErrorKind::BitOffset(_)              => "Offset of the bit in the byte",
       }
 
     }
@@ -1,5 +1,6 @@
 use util::ErrorKind;
-use internal::{IResult, IError};
+use internal::{IResult, IError, ParseError};
+use util::InputLength;
 use internal::IResult::*;
 use std::boxed::Box;
 
@@ -19,6 +20,29 @@ pub enum Err<P,E=u32>{
   NodePosition(ErrorKind<E>, P, Box<Err<P,E>>)
 }
 
+impl<P,E> Err<P,E> {
+  /// Converts the positions of the whole error chain with a function, keeping the error codes
+  ///
+  /// ```
+  /// # use nom::Err::{Position,NodePosition};
+  /// # use nom::ErrorKind;
+  /// let err: nom::Err<&[u8]> = NodePosition(ErrorKind::Tag, &b"ab"[..], Box::new(Position(ErrorKind::Digit, &b"b"[..])));
+  /// assert_eq!(err.map_position(|p| p.len()), NodePosition(ErrorKind::Tag, 2, Box::new(Position(ErrorKind::Digit, 1))));
+  /// ```
+  pub fn map_position<Q, F>(self, f: F) -> Err<Q,E>
+   where F: Fn(P) -> Q {
+    fn map<P, Q, E, F: Fn(P) -> Q>(e: Err<P,E>, f: &F) -> Err<Q,E> {
+      match e {
+        Err::Code(k)                  => Err::Code(k),
+        Err::Node(k, next)            => Err::Node(k, Box::new(map(*next, f))),
+        Err::Position(k, p)           => Err::Position(k, f(p)),
+        Err::NodePosition(k, p, next) => Err::NodePosition(k, f(p), Box::new(map(*next, f))),
+      }
+    }
+    map(self, &f)
+  }
+}
+
 impl<I,O,E> IResult<I,O,E> {
   /// Maps a `IResult<I, O, E>` to `IResult<I, O, N>` by appling a function
   /// to a contained `Error` value, leaving `Done` and `Incomplete` value
@@ -62,6 +86,39 @@ impl<I,O,E> IResult<I,O,E> {
   }
 }
 
+/// Applies a parser on the whole input and converts its result to a std::result::Result
+///
+/// The parser must consume all the input: if some of it remains after
+/// a successful parse, `ParseError::TrailingData` contains the offset
+/// of the first unconsumed element
+///
+/// ```
+/// # #[macro_use] extern crate nom;
+/// # use nom::{parse_all, ParseError};
+/// # fn main() {
+///  named!(abcd, tag!("abcd"));
+///
+///  assert_eq!(parse_all(&b"abcd"[..], abcd), Ok(&b"abcd"[..]));
+///  assert_eq!(parse_all(&b"abcdefgh"[..], abcd), Err(ParseError::TrailingData(4)));
+/// # }
+/// ```
+pub fn parse_all<I,O,E,F>(input: I, parser: F) -> Result<O, ParseError<I,E>>
+  where I: InputLength,
+        F: FnOnce(I) -> IResult<I,O,E> {
+  let len = input.input_len();
+  match parser(input) {
+    Done(i, o)    => {
+      if i.input_len() == 0 {
+        Ok(o)
+      } else {
+        Err(ParseError::TrailingData(len - i.input_len()))
+      }
+    },
+    Incomplete(n) => Err(ParseError::Incomplete(n)),
+    Error(e)      => Err(ParseError::Error(e))
+  }
+}
+
 /// translate parser result from IResult<I,O,u32> to IResult<I,O,E> with a custom type
 ///
 /// ```
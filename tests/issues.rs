@@ -102,8 +102,6 @@ fn usize_length_bytes_issue(){
   length_bytes!(b"012346", be_u16);
 }
 
-/*
- DOES NOT COMPILE
 #[test]
 fn issue_152() {
   named!(take4, take!(4));
@@ -117,8 +115,10 @@ fn issue_152() {
       b"efgh" => abc
     )
   );
+
+  assert_eq!(sw(&b"abcdXYZ"[..]), IResult::Done(&b""[..], &b"XYZ"[..]));
+  assert_eq!(sw(&b"efghabc"[..]), IResult::Done(&b""[..], &b"abc"[..]));
 }
-*/
 
 #[test]
 fn take_till_issue() {
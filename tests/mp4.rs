@@ -253,6 +253,16 @@ named!(filetype_parser<&[u8], FileType>,
   )
 );
 
+named!(ftyp_box<&[u8], FileType>,
+  chain!(
+    size: be_u32                              ~
+          tag!("ftyp")                        ~
+    len:  expr_opt!(size.checked_sub(8))      ~
+    ft:   sized_value!(len, filetype_parser)  ,
+    || { ft }
+  )
+);
+
 fn mvhd_box(input:&[u8]) -> IResult<&[u8],MvhdBox> {
   let res = if input.len() < 100 {
     Incomplete(Needed::Size(100))
@@ -526,5 +536,16 @@ fn big_bunny_test() {
   explore_mp4_file("assets/bigbuckbunny.mp4");
 }
 
-
-
+#[test]
+fn ftyp_box_test() {
+  let ftyp = b"\x00\x00\x00\x18ftypisom\x00\x00\x02\x00isomiso2free";
+  assert_eq!(ftyp_box(&ftyp[..]), Done(&b"free"[..], FileType {
+    major_brand:         "isom",
+    major_brand_version: &b"\x00\x00\x02\x00"[..],
+    compatible_brands:   vec!["isom", "iso2"]
+  }));
+
+  // the declared size cuts the last brand in half
+  let short = b"\x00\x00\x00\x16ftypisom\x00\x00\x02\x00isomiso2";
+  assert_eq!(ftyp_box(&short[..]), Error(error_position!(ErrorKind::LengthValueShort, &short[8..22])));
+}